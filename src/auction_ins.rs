@@ -0,0 +1,267 @@
+use cosmwasm_std::{Deps, DepsMut, Env, Response, MessageInfo, Addr, Coin, BankMsg, Timestamp, Uint128, Order, StdResult,
+Storage};
+use crate::state::{Collection, DutchAuction, AuctionState, AuctionPurchase};
+use crate::indexes::{AUCTION_STATES_STORE, AUCTION_PURCHASES_STORE};
+use crate::error::ContractError;
+use crate::ins::{collection_key, common_response, STATUS_OK};
+use crate::query::{internal_get_collection, internal_get_items_count};
+
+
+fn refundable_auction(collection : &Option<Collection>) -> Result<(Collection, DutchAuction), ContractError> {
+
+    if collection.is_none() {
+        return Err(ContractError::CollectionNotFound { text: "Collection is NOT found!".to_string()});
+    }
+
+    let collection = collection.clone().unwrap();
+
+    let auction = collection.dutch_auction.clone();
+
+    if auction.is_none() || !auction.clone().unwrap().is_refundable() {
+        return Err(ContractError::NothingToClaim { text: 
+            "Collection has no refundable auction!".to_string()});
+    }
+
+    Ok((collection, auction.unwrap()))
+}
+
+
+/*
+Records the mint of an auction and returns the price to be paid out 
+to the collection treasuries. With refund to clearing price, only 
+up to the floor price is paid out and the rest is held by the contract
+ */
+pub (crate) fn record_auction_purchase(deps : DepsMut, time : Timestamp, collection : &Collection, 
+    buyer : &Addr, price : Coin) -> Result<Coin, ContractError> {
+
+    if collection.dutch_auction.is_none() {
+        return Ok(price);
+    }
+
+    let auction = collection.dutch_auction.clone().unwrap();
+
+    let _key = collection_key(collection)?;
+
+    let mut state = AUCTION_STATES_STORE.may_load(deps.storage, _key.clone())?
+    .unwrap_or(AuctionState { minted : 0, last_price : None, proceeds_claimed : false, ended_at : None });
+
+    if state.ended_at.is_some() {
+        return Err(ContractError::AuctionNotActive { text: "Auction has ended!".to_string()});
+    }
+
+    state.minted += 1;
+
+    state.last_price = Some(auction.price_at(time));
+
+    AUCTION_STATES_STORE.save(deps.storage, _key.clone(), &state)?;
+
+    if !auction.is_refundable() {
+        return Ok(price);
+    }
+
+    let payout = Coin { amount : price.amount.min(auction.floor_price.amount), denom : price.denom.clone() };
+
+    let mut purchase = AUCTION_PURCHASES_STORE.may_load(deps.storage, 
+    (_key.0.clone(), _key.1.clone(), buyer.clone()))?
+    .unwrap_or(AuctionPurchase { buyer : buyer.clone(), count : 0, paid : Uint128::zero(), 
+        escrowed : Uint128::zero(), refund_claimed : false });
+
+    purchase.count += 1;
+    purchase.paid += price.amount;
+    purchase.escrowed += price.amount - payout.amount;
+
+    AUCTION_PURCHASES_STORE.save(deps.storage, (_key.0, _key.1, buyer.clone()), &purchase)?;
+
+    Ok(payout)
+}
+
+
+/*
+The auction ends at its end time or when the collection is sold out,
+once its end is recorded it stays ended
+ */
+pub (crate) fn is_auction_ended(deps : Deps, _env : &Env, _key : &(Addr, String), 
+    collection : &Collection, auction : &DutchAuction) -> bool {
+
+    let ended_at = AUCTION_STATES_STORE.may_load(deps.storage, _key.clone())
+    .unwrap_or(None)
+    .and_then(|s| s.ended_at);
+
+    ended_at.is_some() || _env.block.time >= auction.end_time || 
+    internal_get_items_count(deps, _key.0.clone(), 
+    collection.name.clone(), collection.symbol.clone()) == 0
+}
+
+
+// records the end of the auction the first time it is found ended
+pub (crate) fn mark_auction_ended(storage : &mut dyn Storage, _key : &(Addr, String), time : Timestamp) 
+-> StdResult<()> {
+
+    let state = AUCTION_STATES_STORE.may_load(storage, _key.clone())?;
+
+    if state.is_some() && state.clone().unwrap().ended_at.is_none() {
+
+        let mut state = state.unwrap();
+
+        state.ended_at = Some(time);
+
+        AUCTION_STATES_STORE.save(storage, _key.clone(), &state)?;
+    }
+
+    Ok(())
+}
+
+
+/*
+A collection with a refundable auction is only removed once
+the proceeds and every refund above zero have been claimed
+ */
+pub (crate) fn check_if_auction_settled(deps : Deps, collection : &Collection) -> Result<(), ContractError> {
+
+    let auction = collection.dutch_auction.clone();
+
+    if auction.is_none() || !auction.clone().unwrap().is_refundable() {
+        return Ok(());
+    }
+
+    let _key = collection_key(collection)?;
+
+    let state = AUCTION_STATES_STORE.may_load(deps.storage, _key.clone())?;
+
+    if state.is_none() {
+        return Ok(());
+    }
+
+    let clearing = clearing_price(&state, &auction.unwrap());
+
+    let unclaimed_refund = AUCTION_PURCHASES_STORE
+    .prefix(_key)
+    .range(deps.storage, None, None, Order::Ascending)
+    .filter_map(|p| p.ok())
+    .any(|(_, p)| !p.refund_claimed && !refund_of(&p, &clearing).is_zero());
+
+    if !state.unwrap().proceeds_claimed || unclaimed_refund {
+        return Err(ContractError::InvalidCollectionStatus { text: 
+            "Collection with unclaimed auction proceeds or refunds cannot be removed!".to_string()});
+    }
+
+    Ok(())
+}
+
+
+// the clearing price is the auction price of the last mint 
+pub (crate) fn clearing_price(state : &Option<AuctionState>, auction : &DutchAuction) -> Coin {
+
+    state.clone()
+    .and_then(|s| s.last_price)
+    .unwrap_or(auction.price_at(auction.end_time))
+}
+
+
+pub (crate) fn refund_of(purchase : &AuctionPurchase, clearing : &Coin) -> Uint128 {
+
+    let at_clearing = clearing.amount.checked_mul(Uint128::from(purchase.count))
+    .unwrap_or(Uint128::MAX);
+
+    purchase.paid.saturating_sub(at_clearing).min(purchase.escrowed)
+}
+
+
+pub fn claim_auction_refund(deps : DepsMut, _env : Env, info : MessageInfo, 
+    owner : Addr, collection_name : String, collection_symbol : String) 
+-> Result<Response, ContractError> {
+
+    let (collection, auction) = refundable_auction(&internal_get_collection(deps.as_ref(), 
+    owner, collection_name, collection_symbol))?;
+
+    let _key = collection_key(&collection)?;
+
+    if !is_auction_ended(deps.as_ref(), &_env, &_key, &collection, &auction) {
+        return Err(ContractError::AuctionNotEnded { text: "Auction has NOT ended!".to_string()});
+    }
+
+    mark_auction_ended(deps.storage, &_key, _env.block.time)?;
+
+    let purchase = AUCTION_PURCHASES_STORE.may_load(deps.storage, 
+    (_key.0.clone(), _key.1.clone(), info.sender.clone()))?;
+
+    if purchase.is_none() || purchase.clone().unwrap().refund_claimed {
+        return Err(ContractError::NothingToClaim { text: "No refund to claim!".to_string()});
+    }
+
+    let mut purchase = purchase.unwrap();
+
+    let state = AUCTION_STATES_STORE.may_load(deps.storage, _key.clone())?;
+
+    let refund = refund_of(&purchase, &clearing_price(&state, &auction));
+
+    purchase.refund_claimed = true;
+
+    AUCTION_PURCHASES_STORE.save(deps.storage, (_key.0.clone(), _key.1.clone(), info.sender.clone()), &purchase)?;
+
+    let mut bank_msgs : Vec<BankMsg> = Vec::new();
+
+    if !refund.is_zero() {
+        bank_msgs.push(BankMsg::Send { to_address : info.sender.to_string(), 
+            amount : vec![Coin { amount : refund, denom : auction.floor_price.denom.clone() }] });
+    }
+
+    common_response(format!("{}-{}",_key.0, _key.1).as_str(), "claim_auction_refund", STATUS_OK, 
+    Some(format!("{}{}", refund, auction.floor_price.denom)), Some(bank_msgs))
+}
+
+
+/*
+Pays the collection treasuries what is held by the contract 
+after the refunds to the buyers
+ */
+pub fn claim_auction_proceeds(deps : DepsMut, _env : Env, info : MessageInfo, 
+    collection_name : String, collection_symbol : String) 
+-> Result<Response, ContractError> {
+
+    let (collection, auction) = refundable_auction(&internal_get_collection(deps.as_ref(), 
+    info.sender.clone(), collection_name, collection_symbol))?;
+
+    let _key = collection_key(&collection)?;
+
+    if !is_auction_ended(deps.as_ref(), &_env, &_key, &collection, &auction) {
+        return Err(ContractError::AuctionNotEnded { text: "Auction has NOT ended!".to_string()});
+    }
+
+    mark_auction_ended(deps.storage, &_key, _env.block.time)?;
+
+    let state = AUCTION_STATES_STORE.may_load(deps.storage, _key.clone())?;
+
+    if state.is_none() || state.clone().unwrap().proceeds_claimed {
+        return Err(ContractError::NothingToClaim { text: "No proceeds to claim!".to_string()});
+    }
+
+    let clearing = clearing_price(&state, &auction);
+
+    let purchases : StdResult<Vec<AuctionPurchase>> = AUCTION_PURCHASES_STORE
+    .prefix(_key.clone())
+    .range(deps.storage, None, None, Order::Ascending)
+    .map(|p| {
+        let (_k, p) = p?;
+        Ok(p)
+    }).collect();
+
+    let proceeds = purchases?.iter()
+    .fold(Uint128::zero(), |acc, p| acc + (p.escrowed - refund_of(p, &clearing)));
+
+    let mut state = state.unwrap();
+
+    state.proceeds_claimed = true;
+
+    AUCTION_STATES_STORE.save(deps.storage, _key.clone(), &state)?;
+
+    let bank_msgs : Vec<BankMsg> = collection.treasuries_to_payments(Coin { amount : proceeds, 
+        denom : auction.floor_price.denom.clone() })?
+    .into_iter()
+    .filter(|(_, amt)| !amt.amount.is_zero())
+    .map(|(wallet, amt)| BankMsg::Send { to_address : wallet.to_string(), amount : vec![amt] })
+    .collect();
+
+    common_response(format!("{}-{}",_key.0, _key.1).as_str(), "claim_auction_proceeds", STATUS_OK, 
+    Some(format!("{}{}", proceeds, auction.floor_price.denom)), Some(bank_msgs))
+}
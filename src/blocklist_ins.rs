@@ -0,0 +1,86 @@
+use cosmwasm_std::{DepsMut, Env, Response, MessageInfo, Addr};
+use crate::state::{BlockedAddress, CollectionRole};
+use crate::indexes::{BLOCKLIST_STORE, COLLECTION_BLOCKLISTS_STORE};
+use crate::error::ContractError;
+use crate::ins::{collection_id, common_response, STATUS_OK};
+use crate::query::internal_get_collection;
+use crate::checks::{check_if_contract_admin, check_if_collection_role};
+
+
+pub fn block_addresses(deps : DepsMut, _env : Env, info : MessageInfo, addresses : Vec<Addr>, 
+    reason : Option<String>) -> Result<Response, ContractError> {
+
+    check_if_contract_admin(deps.as_ref(), &info.sender)?;
+
+    for address in addresses.iter() {
+
+        BLOCKLIST_STORE.save(deps.storage, address.clone(), &BlockedAddress {
+            address : address.clone(),
+            reason : reason.clone(),
+            blocked_by : info.sender.clone(),
+            date_blocked : _env.block.time,
+        })?;
+    }
+
+    common_response(format!("{}", addresses.len()).as_str(), "block_addresses", STATUS_OK, None, None)
+}
+
+
+pub fn unblock_addresses(deps : DepsMut, info : MessageInfo, addresses : Vec<Addr>) 
+-> Result<Response, ContractError> {
+
+    check_if_contract_admin(deps.as_ref(), &info.sender)?;
+
+    for a in addresses.iter() {
+        BLOCKLIST_STORE.remove(deps.storage, a.clone());
+    }
+
+    common_response(format!("{}", addresses.len()).as_str(), "unblock_addresses", STATUS_OK, None, None)
+}
+
+
+/*
+The owner or a manager of a collection blocks the addresses 
+from minting the collection
+ */
+pub fn block_collection_addresses(deps : DepsMut, _env : Env, info : MessageInfo, owner : Addr,
+    collection_name : String, collection_symbol : String, addresses : Vec<Addr>, 
+    reason : Option<String>) -> Result<Response, ContractError> {
+
+    let _key = (owner.clone(), collection_id(collection_name.clone(), collection_symbol.clone()));
+
+    if internal_get_collection(deps.as_ref(), owner, collection_name, collection_symbol).is_none() {
+        return Err(ContractError::CollectionNotFound { text: "Collection is NOT found!".to_string()});
+    }
+
+    check_if_collection_role(deps.as_ref(), &_key, &info.sender, CollectionRole::Manager)?;
+
+    for address in addresses.iter() {
+
+        COLLECTION_BLOCKLISTS_STORE.save(deps.storage, (_key.0.clone(), _key.1.clone(), address.clone()), 
+        &BlockedAddress {
+            address : address.clone(),
+            reason : reason.clone(),
+            blocked_by : info.sender.clone(),
+            date_blocked : _env.block.time,
+        })?;
+    }
+
+    common_response(format!("{}-{}",_key.0, _key.1).as_str(), "block_collection_addresses", STATUS_OK, None, None)
+}
+
+
+pub fn unblock_collection_addresses(deps : DepsMut, info : MessageInfo, owner : Addr,
+    collection_name : String, collection_symbol : String, addresses : Vec<Addr>) 
+-> Result<Response, ContractError> {
+
+    let _key = (owner, collection_id(collection_name, collection_symbol));
+
+    check_if_collection_role(deps.as_ref(), &_key, &info.sender, CollectionRole::Manager)?;
+
+    for a in addresses.iter() {
+        COLLECTION_BLOCKLISTS_STORE.remove(deps.storage, (_key.0.clone(), _key.1.clone(), a.clone()));
+    }
+
+    common_response(format!("{}-{}",_key.0, _key.1).as_str(), "unblock_collection_addresses", STATUS_OK, None, None)
+}
@@ -1,9 +1,22 @@
-use cosmwasm_std::{DepsMut, MessageInfo, Addr, Coin, Uint128};
+use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Addr, Coin, Uint128, Timestamp, QueryRequest, WasmQuery,
+ContractInfoResponse, Api, StdResult};
 use crate::indexes::{collections_store, COLLECTION_ITEMS_STORE};
-use crate::ins::collection_id;
-use crate::query::internal_get_collection;
+use crate::ins::{collection_id, collection_key};
+use crate::query::{internal_get_collection, internal_get_contract_admins, internal_get_name_availability,
+internal_get_contract_fee};
 use crate::error::ContractError;
-use crate::state::{COLLECTION_STATUS_ACTIVATED, COLLECTION_STATUS_DEACTIVATED, COLLECTION_STATUS_DRAFT, Treasury};
+use crate::state::{COLLECTION_STATUS_ACTIVATED, COLLECTION_STATUS_DRAFT, COLLECTION_STATUS_PAUSED,
+COLLECTION_STATUS_PENDING_REVIEW, COLLECTION_STATUS_SOLD_OUT, COLLECTION_STATUS_CLOSED, COLLECTION_STATUS_ARCHIVED,
+INITIAL_COLLECTION_STATUSES,
+collection_status_name, allowed_status_transitions, CollectionReview, REVIEW_STATUS_APPROVED, CollectionRole, 
+Treasury, TREASURY_PERCENTAGE_TOTAL, Collection, PRICE_TYPE_STANDARD, FeeType, FeeTier, FeeOverride, FEE_DISCOUNT_MAX,
+PromoCode, PromoDiscount, DutchAuction, BondingCurve, CURVE_TYPE_LINEAR, CURVE_TYPE_EXPONENTIAL,
+MintPhase, CollectionSigners, CollectionAction, PauseGroup, MintThrottle, MINT_THROTTLE_MAX_BLOCKS, Item};
+use crate::msg::{ProposedAction, CollectionMsg, ItemMsg, CollectionSignersMsg};
+use crate::indexes::{PHASE_MINTS_STORE, PHASE_ALLOWLISTS_STORE, MODERATIONS_STORE, COLLECTION_MEMBERS_STORE, COLLECTION_SIGNERS_STORE,
+PAUSE_FLAGS_STORE, MINT_PAUSES_STORE, BLOCKLIST_STORE, COLLECTION_BLOCKLISTS_STORE,
+BLOCK_MINTS_STORE, LAST_MINT_HEIGHTS_STORE};
+use pix0_contract_common::state::Fee;
 use pix0_market_handlers::state::Royalty;
 
 fn collection_exists( deps: &DepsMut, info: MessageInfo, name : String, symbol : String ) -> 
@@ -67,7 +80,11 @@ fn is_status_valid ( status : u8) -> bool {
 
     status == COLLECTION_STATUS_DRAFT ||
     status == COLLECTION_STATUS_ACTIVATED ||
-    status == COLLECTION_STATUS_DEACTIVATED
+    status == COLLECTION_STATUS_PAUSED ||
+    status == COLLECTION_STATUS_PENDING_REVIEW ||
+    status == COLLECTION_STATUS_SOLD_OUT ||
+    status == COLLECTION_STATUS_CLOSED ||
+    status == COLLECTION_STATUS_ARCHIVED 
 
 }
 
@@ -89,20 +106,52 @@ Result<u8,ContractError>{
 }
 
 
+pub (crate) fn check_if_initial_status_valid(status : u8) -> Result<(), ContractError> {
+
+    if !INITIAL_COLLECTION_STATUSES.contains(&status) {
+        return Err(ContractError::InvalidCollectionStatus { text: 
+            format!("A collection can NOT be created as {}!", collection_status_name(status))});
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_status_transition_allowed(from : Option<u8>, to : u8) -> Result<(), ContractError> {
+
+    let from = from.unwrap_or(COLLECTION_STATUS_DRAFT);
+
+    if from != to && !allowed_status_transitions(from).contains(&to) {
+        return Err(ContractError::IllegalStatusTransition { text: 
+            format!("Illegal status transition from {} to {}!", collection_status_name(from), 
+            collection_status_name(to))});
+    }
+
+    Ok(())
+}
+
+
 pub (crate) fn are_treasuries_valid (treasuries : &Option<Vec<Treasury>>)  -> Result<bool, ContractError> {
 
     if treasuries.is_some () {
 
-        let mut total_percentage = 0;
+        let mut total_percentage : u32 = 0;
 
         let ts = treasuries.clone().unwrap();
 
-        ts.iter().for_each(|t| total_percentage += t.percentage);
+        ts.iter().for_each(|t| total_percentage += t.percentage as u32);
 
-        if total_percentage > 100 || total_percentage < 100 {
+        // the treasuries must add up to exactly 100 percent
+        if total_percentage != TREASURY_PERCENTAGE_TOTAL as u32 {
+
+            return Err(ContractError::InvalidAllocationsForTreasuries { message:  
+                format!("Invalid percentage {} for treasuries amount, the total must be {}", 
+                total_percentage, TREASURY_PERCENTAGE_TOTAL) } );
+        }
+        else if ts.iter().filter(|t| t.primary == Some(true)).count() > 1 {
 
             return Err(ContractError::InvalidAllocationsForTreasuries { message:  
-                format!("Invalid percentage {} for treasuries amount, the total must be 100", total_percentage) } );
+                "Only one treasury can be the primary treasury".to_string() } );
         }
         else {
             Ok(true)
@@ -144,14 +193,12 @@ pub (crate) fn are_royalties_valid (royalties : &Option<Vec<Royalty>>)  -> Resul
 }
 
 
-fn item_exists( info: MessageInfo, 
+fn item_exists( owner: Addr, 
     collection_name : String,
     collection_symbol : String,
     name : String, 
     deps: &DepsMut ) -> bool {
 
-    let owner = info.clone().sender;
-    
     let _key = (owner, collection_id(collection_name
         , collection_symbol), name );
 
@@ -161,12 +208,12 @@ fn item_exists( info: MessageInfo,
 }
 
 
-pub (crate) fn check_if_item_exists(deps: &DepsMut,info: MessageInfo, 
+pub (crate) fn check_if_item_exists(deps: &DepsMut,owner: Addr, 
     collection_name : String,
     collection_symbol : String,
     name : String) -> Result<(), ContractError> {
 
-    if item_exists(info.clone(), collection_name.clone() ,
+    if item_exists(owner, collection_name.clone() ,
     collection_symbol,
     name.clone(), &deps) {
         return Err(ContractError::CustomErrorMesg { message: format!("The item {} in collection '{}' already exists!", 
@@ -206,14 +253,17 @@ fn is_fund_sufficient (info : MessageInfo, required_fund : Coin) -> (bool, Coin)
         return (false, Coin { amount :Uint128::default(), denom :"uconst".to_string()});
     }
 
-    let first_fund = sent_funds.get(0).unwrap();
+    // the funds may come in several denoms, only the required denom counts
+    let sent_fund = sent_funds.iter()
+    .find(|f| f.denom == required_fund.denom)
+    .unwrap_or(sent_funds.get(0).unwrap());
 
-    if first_fund.amount < Uint128::from(required_fund.amount) ||
-    first_fund.denom != required_fund.denom {
-        (false,first_fund.clone()) 
+    if sent_fund.amount < Uint128::from(required_fund.amount) ||
+    sent_fund.denom != required_fund.denom {
+        (false,sent_fund.clone()) 
     }
     else {
-        (true,first_fund.clone())
+        (true,sent_fund.clone())
     }
 }
 
@@ -221,6 +271,11 @@ fn is_fund_sufficient (info : MessageInfo, required_fund : Coin) -> (bool, Coin)
 pub (crate) fn check_if_fund_sufficient(info : MessageInfo, required_fund : Coin) 
 -> Result<(),ContractError> {
 
+    // nothing is required for a free mint
+    if required_fund.amount.is_zero() {
+        return Ok(());
+    }
+
     let fund_checked = is_fund_sufficient(info.clone(), required_fund.clone());
     if !fund_checked.0 {
         return Err(ContractError::InsufficientFund {
@@ -230,4 +285,764 @@ pub (crate) fn check_if_fund_sufficient(info : MessageInfo, required_fund : Coin
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+
+/*
+Checks the collection is ready for minting and returns
+the price of the price type to be paid for each mint,
+minted is the number of items minted so far
+ */
+pub (crate) fn check_if_collection_mintable(collection : &Collection, price_type : Option<u8>, time : Timestamp,
+    minted : u64) -> Result<Coin,ContractError> {
+
+    if collection.effective_status(time) != Some(COLLECTION_STATUS_ACTIVATED) {
+        return Err(ContractError::NftStatusIsNotReadyForMinting { text: "Collection is NOT ready for minting!".to_string()});
+    }
+
+    if collection.dutch_auction.is_some() {
+
+        let auction = collection.dutch_auction.clone().unwrap();
+
+        if time < auction.start_time || time >= auction.end_time {
+            return Err(ContractError::AuctionNotActive { text: 
+                format!("Auction runs from {} to {}!", auction.start_time, auction.end_time)});
+        }
+
+        return Ok(auction.price_at(time));
+    }
+
+    if collection.mint_phases.is_some() {
+
+        let phase = collection.active_phase(time);
+
+        if phase.is_none() {
+            return Err(ContractError::NoActiveMintPhase { text: "No mint phase is active now!".to_string()});
+        }
+
+        // the phase sets the price, another price type would not be honored
+        if price_type.is_some() && price_type.unwrap() != PRICE_TYPE_STANDARD {
+            return Err(ContractError::PriceTypeNotFound { text: 
+                format!("Price type {} is NOT available in mint phase {}!", price_type.unwrap(), phase.unwrap().name)});
+        }
+
+        return Ok(phase.unwrap().price);
+    }
+
+    if collection.bonding_curve.is_some() {
+
+        let curve = collection.bonding_curve.clone().unwrap();
+
+        return curve.price_at(minted).map_err(|e| ContractError::PriceOverflow { text: 
+            format!("Bonding curve price overflows at supply {} : {}!", minted, e)});
+    }
+
+    let prc_typ = price_type.unwrap_or(PRICE_TYPE_STANDARD);
+
+    let price = collection.price_by_type(prc_typ);
+
+    if price.is_none() {
+        return Err(ContractError::PriceTypeNotFound { text: 
+            format!("Price type {} is NOT found in collection!", prc_typ)});
+    }
+
+    Ok(price.unwrap())
+}
+
+
+
+pub (crate) fn check_if_fees_valid(fees : &Option<Vec<Fee>>) -> Result<(), ContractError> {
+
+    if fees.is_some() {
+
+        let unknown : Vec<String> = fees.clone().unwrap()
+        .into_iter()
+        .filter(|f| FeeType::from_name(&f.name).is_none())
+        .map(|f| f.name)
+        .collect();
+
+        if unknown.len() > 0 {
+            return Err(ContractError::UnknownFeeName { text: 
+                format!("Unknown fee name(s) :{}!", unknown.join(", "))});
+        }
+    }
+
+    Ok(())
+}
+
+
+
+/*
+The name and symbol are unique across the creators, 
+so a name taken or reserved by another creator is refused
+ */
+pub (crate) fn check_if_name_available(deps : Deps, sender : &Addr, name : String, symbol : String, 
+    time : Timestamp) -> Result<(), ContractError> {
+
+    let availability = internal_get_name_availability(deps, name.clone(), symbol.clone(), 
+    Some(sender.clone()), time);
+
+    if availability.taken {
+        return Err(ContractError::NameNotAvailable { 
+            text : format!("Name {} with symbol {} is already used by another collection!", name, symbol)});
+    }
+
+    if !availability.available {
+        return Err(ContractError::NameNotAvailable { 
+            text : format!("Name {} with symbol {} is reserved until {}!", name, symbol, 
+            availability.reservation.unwrap().expires_at.seconds())});
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_contract_admin(deps : Deps, sender : &Addr) -> Result<(), ContractError> {
+
+    if !internal_get_contract_admins(deps).contains(sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_fee_tier_valid(tier : &FeeTier) -> Result<(), ContractError> {
+
+    let invalid = tier.discounts.iter().find(|d| d.percentage > FEE_DISCOUNT_MAX);
+
+    if invalid.is_some() {
+        return Err(ContractError::InvalidFeeDiscount { text: 
+            format!("Invalid discount {} for {}, the max is {}", invalid.unwrap().percentage, 
+            invalid.unwrap().fee_type.name(), FEE_DISCOUNT_MAX)});
+    }
+
+    Ok(())
+}
+
+
+/*
+An override must be in the denom of the contract fee it replaces,
+or at least have a denom when the contract has no such fee
+ */
+pub (crate) fn check_if_fee_overrides_valid(deps : Deps, overrides : &Option<Vec<FeeOverride>>) 
+-> Result<(), ContractError> {
+
+    for o in overrides.clone().unwrap_or(vec![]).iter() {
+
+        let fee = internal_get_contract_fee(deps, o.fee_type.name());
+
+        if o.value.denom.trim().is_empty() || 
+        (fee.is_some() && fee.clone().unwrap().denom != o.value.denom) {
+            return Err(ContractError::InvalidInput { text: 
+                format!("Invalid denom {} for the override of {}, expected :{}!", o.value.denom, 
+                o.fee_type.name(), fee.map(|f| f.denom).unwrap_or("any denom".to_string()))});
+        }
+
+        if overrides.clone().unwrap().iter().filter(|x| x.fee_type == o.fee_type).count() > 1 {
+            return Err(ContractError::InvalidInput { text: 
+                format!("Duplicate override of {}!", o.fee_type.name())});
+        }
+    }
+
+    Ok(())
+}
+
+
+
+pub (crate) fn check_if_referrer_valid(referrer : &Option<Addr>, sender : &Addr) -> Result<(), ContractError> {
+
+    if referrer.as_ref() == Some(sender) {
+        return Err(ContractError::InvalidInput { text: "A minter cannot be its own referrer!".to_string()});
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_referral_share_valid(referral_share : Option<u16>) -> Result<(), ContractError> {
+
+    if referral_share.is_some() && referral_share.unwrap() > TREASURY_PERCENTAGE_TOTAL {
+        return Err(ContractError::InvalidReferralShare { text: 
+            format!("Invalid referral share {}, the max is {}", referral_share.unwrap(), TREASURY_PERCENTAGE_TOTAL)});
+    }
+
+    Ok(())
+}
+
+
+
+pub (crate) fn check_if_promo_code_valid(code : &PromoCode) -> Result<(), ContractError> {
+
+    if code.code_hash.len() != 64 || !code.code_hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ContractError::InvalidPromoCode { text: 
+            format!("Invalid code hash {}, it must be a hex encoded sha256 hash", code.code_hash)});
+    }
+
+    if let PromoDiscount::Percentage(p) = code.discount {
+        if p > TREASURY_PERCENTAGE_TOTAL {
+            return Err(ContractError::InvalidPromoCode { text: 
+                format!("Invalid discount {}, the max is {}", p, TREASURY_PERCENTAGE_TOTAL)});
+        }
+    }
+
+    Ok(())
+}
+
+
+
+pub (crate) fn check_if_dutch_auction_valid(auction : &Option<DutchAuction>) -> Result<(), ContractError> {
+
+    if auction.is_some() {
+
+        let a = auction.clone().unwrap();
+
+        if a.start_price.denom != a.floor_price.denom {
+            return Err(ContractError::InvalidDutchAuction { text: 
+                "Start price and floor price must be of the same denom".to_string()});
+        }
+
+        if a.floor_price.amount > a.start_price.amount {
+            return Err(ContractError::InvalidDutchAuction { text: 
+                "Floor price must NOT be greater than start price".to_string()});
+        }
+
+        if a.decay_interval == 0 {
+            return Err(ContractError::InvalidDutchAuction { text: 
+                "Decay interval must be greater than zero".to_string()});
+        }
+
+        if a.end_time <= a.start_time {
+            return Err(ContractError::InvalidDutchAuction { text: 
+                "End time must be later than start time".to_string()});
+        }
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_bonding_curve_valid(curve : &Option<BondingCurve>, 
+    auction : &Option<DutchAuction>) -> Result<(), ContractError> {
+
+    if curve.is_some() {
+
+        let c = curve.clone().unwrap();
+
+        if auction.is_some() {
+            return Err(ContractError::InvalidBondingCurve { text: 
+                "A collection can NOT have both a bonding curve and a Dutch auction".to_string()});
+        }
+
+        if c.curve_type == CURVE_TYPE_LINEAR {
+
+            if c.increment.is_none() {
+                return Err(ContractError::InvalidBondingCurve { text: 
+                    "Linear bonding curve requires an increment".to_string()});
+            }
+        }
+        else if c.curve_type == CURVE_TYPE_EXPONENTIAL {
+
+            if c.growth_percentage.unwrap_or(0) == 0 {
+                return Err(ContractError::InvalidBondingCurve { text: 
+                    "Exponential bonding curve requires a growth percentage greater than zero".to_string()});
+            }
+        }
+        else {
+            return Err(ContractError::InvalidBondingCurve { text: 
+                format!("Unknown bonding curve type {}", c.curve_type)});
+        }
+    }
+
+    Ok(())
+}
+
+
+/*
+Phases must have unique names and must not overlap in time,
+only the last phase by start time can be left open-ended
+ */
+pub (crate) fn check_if_mint_phases_valid(phases : &Option<Vec<MintPhase>>, 
+    auction : &Option<DutchAuction>, curve : &Option<BondingCurve>) -> Result<(), ContractError> {
+
+    if phases.is_some() {
+
+        if auction.is_some() || curve.is_some() {
+            return Err(ContractError::InvalidMintPhases { text: 
+                "Mint phases can NOT be combined with a Dutch auction or a bonding curve".to_string()});
+        }
+
+        let mut phases = phases.clone().unwrap();
+
+        phases.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+
+        for (i, p) in phases.iter().enumerate() {
+
+            if p.name.trim().is_empty() {
+                return Err(ContractError::InvalidMintPhases { text: "Mint phase name is required".to_string()});
+            }
+
+            if phases.iter().filter(|o| o.name == p.name).count() > 1 {
+                return Err(ContractError::InvalidMintPhases { text: 
+                    format!("Mint phase name {} is duplicated", p.name)});
+            }
+
+            if p.end_time.is_some() && p.end_time.unwrap() <= p.start_time {
+                return Err(ContractError::InvalidMintPhases { text: 
+                    format!("Mint phase {} must end later than it starts", p.name)});
+            }
+
+            if i + 1 < phases.len() {
+
+                let next = &phases[i + 1];
+
+                if p.end_time.is_none() || p.end_time.unwrap() > next.start_time {
+                    return Err(ContractError::InvalidMintPhases { text: 
+                        format!("Mint phase {} overlaps with mint phase {}", p.name, next.name)});
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_eligible_for_phase(deps : Deps, collection : &Collection, phase : &MintPhase, 
+    wallet : &Addr, count : u32) -> Result<(), ContractError> {
+
+    let (owner, coll_id) = collection_key(collection)?;
+
+    if phase.allowlist_only == Some(true) && !PHASE_ALLOWLISTS_STORE.has(deps.storage, 
+        ((owner.clone(), coll_id.clone()), phase.name.clone(), wallet.clone())) {
+        return Err(ContractError::NotEligibleForMintPhase { text: 
+            format!("{} is NOT allowed to mint in phase {}!", wallet, phase.name)});
+    }
+
+    if phase.max_per_wallet.is_some() {
+
+        let _key = (owner, coll_id, wallet.clone());
+
+        let minted = PHASE_MINTS_STORE.may_load(deps.storage, _key)?
+        .unwrap_or(vec![])
+        .into_iter()
+        .find(|m| m.phase == phase.name)
+        .map(|m| m.count)
+        .unwrap_or(0);
+
+        let max = phase.max_per_wallet.unwrap();
+
+        if minted + count > max {
+            return Err(ContractError::MintPhaseWalletLimitReached { text: 
+                format!("{} has minted {} of {} allowed in phase {}!", wallet, minted, max, phase.name)});
+        }
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_launch_window_valid(launch_at : Option<Timestamp>, close_at : Option<Timestamp>) 
+-> Result<(), ContractError> {
+
+    if launch_at.is_some() && close_at.is_some() && close_at.unwrap() <= launch_at.unwrap() {
+        return Err(ContractError::InvalidLaunchWindow { text: 
+            "Collection must close later than it launches".to_string()});
+    }
+
+    Ok(())
+}
+
+
+/*
+Only approved collections can be activated from draft or pending review,
+a contract admin activating a collection approves it on the way, and the
+returned review is to be stored on the collection
+ */
+pub (crate) fn check_if_activation_approved(deps : Deps, time : Timestamp, sender : &Addr, 
+    collection : &Collection) -> Result<Option<CollectionReview>, ContractError> {
+
+    if collection.is_approved() {
+        return Ok(None);
+    }
+
+    if check_if_contract_admin(deps, sender).is_ok() {
+
+        return Ok(Some(CollectionReview { status : REVIEW_STATUS_APPROVED, reviewer : Some(sender.clone()), 
+            reason : None, date_submitted : collection.review.clone().and_then(|r| r.date_submitted), 
+            date_reviewed : Some(time) }));
+    }
+
+    Err(ContractError::CollectionNotApproved { text: 
+        format!("Collection {} has NOT been approved for activation!", collection.name)})
+}
+
+
+pub (crate) fn is_collection_suspended(deps : Deps, _key : &(Addr, String)) -> bool {
+
+    MODERATIONS_STORE.may_load(deps.storage, _key.clone())
+    .unwrap_or(None)
+    .map(|m| m.suspended)
+    .unwrap_or(false)
+}
+
+
+pub (crate) fn check_if_collection_not_suspended(deps : Deps, collection : &Collection) -> Result<(), ContractError> {
+
+    if is_collection_suspended(deps, &collection_key(collection)?) {
+        return Err(ContractError::CollectionSuspended { text: 
+            format!("Collection {} is suspended by the platform!", collection.name)});
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_minting_not_paused(deps : Deps, collection : &Collection, 
+    time : Timestamp) -> Result<(), ContractError> {
+
+    let pause = MINT_PAUSES_STORE.may_load(deps.storage, collection_key(collection)?)?;
+
+    if pause.is_some() && pause.clone().unwrap().is_active_at(time) {
+        return Err(ContractError::MintingPaused { text: format!("Minting of {} is paused{}", collection.name,
+        pause.unwrap().reason.map(|r| format!(" : {}", r)).unwrap_or("!".to_string()))});
+    }
+
+    Ok(())
+}
+
+
+/*
+The owner of a collection has all the roles, 
+the others need to be granted the role
+ */
+pub (crate) fn check_if_collection_role(deps : Deps, _key : &(Addr, String), sender : &Addr, 
+    role : CollectionRole) -> Result<(), ContractError> {
+
+    if sender == &_key.0 {
+        return Ok(());
+    }
+
+    let roles = COLLECTION_MEMBERS_STORE.may_load(deps.storage, 
+    (_key.0.clone(), _key.1.clone(), sender.clone()))?.unwrap_or(vec![]);
+
+    if !roles.contains(&role) {
+        return Err(ContractError::MissingCollectionRole { text: 
+            format!("{} does NOT have the {} role in the collection!", sender, role.name())});
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_signers_valid(signers : &CollectionSigners) -> Result<(), ContractError> {
+
+    // the signers are validated on entry
+    for (i, s) in signers.signers.iter().enumerate() {
+
+        if signers.signers[..i].contains(s) {
+            return Err(ContractError::InvalidSigners { text: format!("Signer {} is duplicated!", s)});
+        }
+    }
+
+    // no signers only turns off the approvals
+    if signers.signers.is_empty() {
+
+        if signers.threshold != 0 {
+            return Err(ContractError::InvalidSigners { text: "Threshold must be 0 without signers!".to_string()});
+        }
+
+        return Ok(());
+    }
+
+    if signers.threshold == 0 || signers.threshold as usize > signers.signers.len() {
+        return Err(ContractError::InvalidSigners { text: 
+            format!("Threshold must be between 1 and {}!", signers.signers.len())});
+    }
+
+    Ok(())
+}
+
+
+// the changes of the payouts, prices and mint status, which are 
+// approved by the signers on a collection with signers
+pub (crate) fn is_change_for_signers(changes : &Collection) -> bool {
+
+    changes.treasuries.is_some() || changes.royalties.is_some() || changes.status.is_some() ||
+    changes.prices.is_some() || changes.referral_share.is_some() || changes.mint_phases.is_some() ||
+    changes.dutch_auction.is_some() || changes.bonding_curve.is_some() || 
+    changes.launch_at.is_some() || changes.close_at.is_some()
+}
+
+
+/*
+Changes of the treasuries, royalties, prices, referral share and status 
+of a collection with signers, including the prices and mint window set by 
+the mint phases, Dutch auction, bonding curve, launch and close time,
+must be proposed to and approved by the signers
+ */
+pub (crate) fn check_if_change_needs_approval(deps : Deps, _key : &(Addr, String), 
+    changes : &Collection) -> Result<(), ContractError> {
+
+    if !COLLECTION_SIGNERS_STORE.has(deps.storage, _key.clone()) {
+        return Ok(());
+    }
+
+    if is_change_for_signers(changes) {
+        return Err(ContractError::ApprovalRequired { text: 
+            "Changes of treasuries, royalties, prices, referral share, status, mint phases, auction, bonding curve, launch and close time must be approved by the signers!".to_string()});
+    }
+
+    Ok(())
+}
+
+
+// the actions of the owner on a collection with signers are proposed as a CollectionAction
+pub (crate) fn check_if_action_needs_approval(deps : Deps, _key : &(Addr, String), 
+    action : &str) -> Result<(), ContractError> {
+
+    if COLLECTION_SIGNERS_STORE.has(deps.storage, _key.clone()) {
+        return Err(ContractError::ApprovalRequired { text: 
+            format!("{} of the collection must be approved by the signers!", action)});
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_not_paused(deps : Deps, group : PauseGroup) -> Result<(), ContractError> {
+
+    let flag = PAUSE_FLAGS_STORE.may_load(deps.storage, group.name().to_string())?;
+
+    if flag.is_some() {
+        return Err(ContractError::Paused { text: format!("{} is paused{}", group.name(),
+        flag.unwrap().reason.map(|r| format!(" : {}", r)).unwrap_or("!".to_string()))});
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_not_blocked(deps : Deps, address : &Addr) -> Result<(), ContractError> {
+
+    if BLOCKLIST_STORE.has(deps.storage, address.clone()) {
+        return Err(ContractError::AddressBlocked { text: format!("{} is blocked!", address)});
+    }
+
+    Ok(())
+}
+
+
+/*
+Checks the address against the blocklist of the admins
+and then the blocklist of the collection
+ */
+pub (crate) fn check_if_not_blocked_by_collection(deps : Deps, collection : &Collection, 
+    address : &Addr) -> Result<(), ContractError> {
+
+    check_if_not_blocked(deps, address)?;
+
+    let (owner, coll_id) = collection_key(collection)?;
+
+    if COLLECTION_BLOCKLISTS_STORE.has(deps.storage, (owner, coll_id, address.clone())) {
+        return Err(ContractError::AddressBlocked { text: 
+            format!("{} is blocked by collection {}!", address, collection.name)});
+    }
+
+    Ok(())
+}
+
+
+// the mints pay the treasuries of the collection, so none of them may be blocked
+pub (crate) fn check_if_treasuries_not_blocked(deps : Deps, collection : &Collection, 
+    treasuries : &Option<Vec<Treasury>>) -> Result<(), ContractError> {
+
+    if treasuries.is_some() {
+
+        for t in treasuries.clone().unwrap().iter() {
+            check_if_not_blocked_by_collection(deps, collection, &t.wallet)?;
+        }
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_mint_throttle_valid(throttle : &Option<MintThrottle>) -> Result<(), ContractError> {
+
+    if throttle.is_some() && throttle.clone().unwrap().max_mints_per_block == Some(0) {
+        return Err(ContractError::CustomErrorMesg { message : 
+            "Max mints per block must be greater than zero!".to_string()});
+    }
+
+    if throttle.is_some() && throttle.clone().unwrap().min_blocks_between_mints.unwrap_or(0) > MINT_THROTTLE_MAX_BLOCKS {
+        return Err(ContractError::CustomErrorMesg { message : 
+            format!("Min blocks between mints must NOT exceed {}!", MINT_THROTTLE_MAX_BLOCKS)});
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn is_contract(deps : Deps, address : &Addr) -> bool {
+
+    deps.querier.query::<ContractInfoResponse>(&QueryRequest::Wasm(WasmQuery::ContractInfo { 
+        contract_addr : address.to_string() })).is_ok()
+}
+
+
+/*
+Checks the sender against the mint throttle of the collection,
+if any, at the current block
+ */
+pub (crate) fn check_if_mint_not_throttled(deps : Deps, _env : &Env, collection : &Collection, 
+    sender : &Addr) -> Result<(), ContractError> {
+
+    if collection.mint_throttle.is_none() {
+        return Ok(());
+    }
+
+    let throttle = collection.mint_throttle.clone().unwrap();
+
+    let _key = collection_key(collection)?;
+
+    let height = _env.block.height;
+
+    if throttle.no_contract_minters.unwrap_or(false) && is_contract(deps, sender) {
+        return Err(ContractError::ContractMinterNotAllowed { text: 
+            format!("Contract {} is NOT allowed to mint!", sender)});
+    }
+
+    if throttle.max_mints_per_block.is_some() {
+
+        let block_mints = BLOCK_MINTS_STORE.may_load(deps.storage, _key.clone())?;
+
+        if block_mints.is_some() && block_mints.clone().unwrap().height == height && 
+        block_mints.clone().unwrap().count >= throttle.max_mints_per_block.unwrap() {
+            return Err(ContractError::BlockMintLimitReached { text: 
+                format!("Max {} mints in block {} reached!", throttle.max_mints_per_block.unwrap(), height)});
+        }
+    }
+
+    if let Some(min_blocks) = throttle.min_blocks_between_mints {
+
+        let last = LAST_MINT_HEIGHTS_STORE.may_load(deps.storage, 
+        (_key.0.clone(), _key.1.clone(), sender.clone()))?;
+
+        if let Some(last) = last {
+
+            let next = last.saturating_add(min_blocks);
+
+            if height < next {
+                return Err(ContractError::MintTooSoon { text: 
+                    format!("Next mint is allowed at block {}!", next)});
+            }
+        }
+    }
+
+    Ok(())
+}
+
+
+/*
+Validates an address received in a message and returns it in its 
+canonical form, a bech32 address in all uppercase is accepted and 
+lowercased while mixed case or malformed ones are rejected by the Api
+ */
+pub (crate) fn validate_address(api : &dyn Api, address : &str) -> StdResult<Addr> {
+
+    let mut address = address.to_string();
+
+    if address.chars().any(|c| c.is_ascii_uppercase()) && !address.chars().any(|c| c.is_ascii_lowercase()) {
+        address = address.to_lowercase();
+    }
+
+    api.addr_validate(&address)
+}
+
+
+pub (crate) fn validate_optional_address(api : &dyn Api, address : &Option<String>) -> StdResult<Option<Addr>> {
+
+    address.as_ref().map(|a| validate_address(api, a)).transpose()
+}
+
+
+pub (crate) fn validate_addresses(api : &dyn Api, addresses : &Vec<String>) -> StdResult<Vec<Addr>> {
+
+    addresses.iter().map(|a| validate_address(api, a)).collect()
+}
+
+
+pub (crate) fn validate_proposed_action(api : &dyn Api, action : Option<ProposedAction>) 
+-> StdResult<Option<CollectionAction>> {
+
+    action.map(|a| Ok(match a {
+
+        ProposedAction::TransferOwner { new_owner, update_treasury } => CollectionAction::TransferOwner { 
+            new_owner : validate_address(api, &new_owner)?, update_treasury : update_treasury },
+
+        ProposedAction::Remove {} => CollectionAction::Remove {},
+
+        ProposedAction::AuthorizeMinter { minter, price_type, max_mints } => CollectionAction::AuthorizeMinter { 
+            minter : validate_address(api, &minter)?, price_type : price_type, max_mints : max_mints },
+
+        ProposedAction::AddPromoCodes { codes } => CollectionAction::AddPromoCodes { codes : codes },
+    })).transpose()
+}
+
+
+/*
+Validates and canonicalizes the owner and the treasury 
+wallets of a collection received in a message
+ */
+pub (crate) fn validate_collection_addresses(api : &dyn Api, collection : CollectionMsg) -> StdResult<Collection> {
+
+    let treasuries = collection.treasuries.map(|ts| ts.into_iter().map(|t| Ok(Treasury { 
+        wallet : validate_address(api, &t.wallet)?, percentage : t.percentage, name : t.name, primary : t.primary }))
+    .collect::<StdResult<Vec<Treasury>>>()).transpose()?;
+
+    Ok(Collection {
+        owner : validate_optional_address(api, &collection.owner)?,
+        name : collection.name,
+        symbol : collection.symbol,
+        description : collection.description,
+        treasuries : treasuries,
+        attributes : collection.attributes,
+        prices : collection.prices,
+        royalties : collection.royalties,
+        status : collection.status,
+        date_created : collection.date_created,
+        date_updated : collection.date_updated,
+        referral_share : collection.referral_share,
+        dutch_auction : collection.dutch_auction,
+        bonding_curve : collection.bonding_curve,
+        mint_phases : collection.mint_phases,
+        launch_at : collection.launch_at,
+        close_at : collection.close_at,
+        review : collection.review,
+        mint_throttle : collection.mint_throttle,
+    })
+}
+
+
+pub (crate) fn validate_item_addresses(api : &dyn Api, item : ItemMsg) -> StdResult<Item> {
+
+    Ok(Item {
+        collection_owner : validate_address(api, &item.collection_owner)?,
+        collection_name : item.collection_name,
+        collection_symbol : item.collection_symbol,
+        name : item.name,
+        description : item.description,
+        links : item.links,
+        traits : item.traits,
+        background_color : item.background_color,
+        date_created : item.date_created,
+        date_updated : item.date_updated,
+    })
+}
+
+
+pub (crate) fn validate_signers_addresses(api : &dyn Api, signers : CollectionSignersMsg) -> StdResult<CollectionSigners> {
+
+    Ok(CollectionSigners { signers : validate_addresses(api, &signers.signers)?, threshold : signers.threshold })
+}
@@ -3,16 +3,44 @@ use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
 };
-use cw2::set_contract_version;
+use cw2::{set_contract_version, get_contract_version};
 use crate::error::ContractError;
+use crate::checks::{check_if_fees_valid, check_if_not_paused, validate_address, validate_optional_address,
+validate_addresses, validate_proposed_action, validate_collection_addresses, validate_item_addresses, 
+validate_signers_addresses};
 use crate::ins::{create_collection, update_collection, create_item, mint_item_by_name, mint_item, 
-    remove_collection,update_contract_info};
+    remove_collection,update_contract_info, update_item, remove_item};
 use crate::nft_ins::{burn_nft, send_nft, init_and_simple_mint, transfer_nft, receive_nft, create_sell_offer};
+use crate::fee_ins::{set_fee_tier, remove_fee_tier, set_creator_fees, remove_creator_fees};
+use crate::promo_ins::{add_promo_codes, remove_promo_code};
+use crate::auction_ins::{claim_auction_refund, claim_auction_proceeds};
+use crate::review_ins::{submit_for_review, approve_collection, reject_collection};
+use crate::moderation_ins::moderate_collection;
+use crate::curation_ins::{verify_creator, unverify_creator, feature_collection, unfeature_collection};
+use crate::pause_ins::{set_paused, pause_minting, resume_minting, pause_group};
+use crate::validation::set_validation_limits;
+use crate::names_ins::{reserve_name, release_name, prune_name_reservations};
+use crate::blocklist_ins::{block_addresses, unblock_addresses, block_collection_addresses, 
+unblock_collection_addresses};
+use crate::signers_ins::{set_collection_signers, propose_collection_change, approve_collection_change, 
+cancel_collection_change};
+use crate::delegate_ins::{authorize_minter, revoke_minter, mint_for};
+use crate::phase_ins::{add_to_phase_allowlist, remove_from_phase_allowlist};
+use crate::member_ins::{grant_collection_role, revoke_collection_role};
+use crate::ownership_ins::{propose_collection_owner, cancel_collection_owner_proposal, accept_collection_owner};
 use crate::query::{get_all_collections, get_collections,get_active_collections, 
-get_collection, get_items_count, get_items, get_item };
+get_collection, get_items_count, get_items, get_item, simulate_mint, get_fee_schedule, get_fee_quote,
+get_fee_tiers, get_effective_fees, get_referral_stats, get_referral_leaderboard, get_promo_code,
+get_current_price, get_auction_status, get_bonding_curve_quote, get_mint_phases, get_active_phase,
+get_review_queue, get_moderation, get_verified_creator, get_featured_collections,
+get_pending_owner, get_collection_members, get_delegated_minters, get_collection_signers,
+get_pause_status, get_blocked_addresses, get_collection_blocked_addresses, get_validation_limits, is_name_available,
+get_phase_allowlist };
 use crate::nft_query::*;
 use crate::msg::{ExecuteMsg,QueryMsg, MigrateMsg};
 use crate::utils::str_to_u64;
+use crate::migrations::{migrate_treasury_percentages, rekey_collections,
+migrate_collection_statuses, is_version_before, TREASURY_PERCENTAGE_VERSION, COLLECTION_STATUS_VERSION};
 use pix0_contract_common::funcs::{create_contract_info, get_contract_info, get_log_info};
 use pix0_contract_common::msg::InstantiateMsg;
 
@@ -29,6 +57,8 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     
 
+    check_if_fees_valid(&_msg.fees)?;
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
    
     create_contract_info(deps, _env, info.clone() ,_msg.allowed_admins,
@@ -48,68 +78,213 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+
+    let api = deps.api;
+
+    if let Some(group) = pause_group(&msg) {
+        check_if_not_paused(deps.as_ref(), group)?;
+    }
+
     match msg {
-        ExecuteMsg::CreateCollection {collection }
-        => create_collection(deps, _env, info, collection ),
+        ExecuteMsg::ReserveName { name, symbol } =>
+        reserve_name(deps, _env, info, name, symbol),
+
+        ExecuteMsg::ReleaseName { name, symbol } =>
+        release_name(deps, _env, info, name, symbol),
 
-        ExecuteMsg::UpdateCollection { collection }
-        => update_collection(deps, _env, info, collection ),
+        ExecuteMsg::PruneNameReservations { start_after, limit } =>
+        prune_name_reservations(deps, _env, start_after, limit),
 
-        ExecuteMsg::RemoveCollection { name, symbol}
-        => remove_collection(name,symbol, deps,info ),
+        ExecuteMsg::CreateCollection {collection } =>
+        create_collection(deps, _env, info, validate_collection_addresses(api, collection)? ),
 
-         ExecuteMsg::CreateItem { item }
-        => create_item(deps, _env, info, item ),
+        ExecuteMsg::UpdateCollection { owner, collection } =>
+        update_collection(deps, _env, info, validate_optional_address(api, &owner)?, validate_collection_addresses(api, collection)? ),
+
+        ExecuteMsg::RemoveCollection { name, symbol} =>
+        remove_collection(name,symbol, deps,info ),
+
+        ExecuteMsg::CreateItem { item } =>
+        create_item(deps, _env, info, validate_item_addresses(api, item)? ),
         
         ExecuteMsg::MintItemByName { name , owner, collection_name, collection_symbol, 
-            price_type, token_uri, token_id }
-        => mint_item_by_name(deps, _env, info, name , owner, 
-            collection_name, collection_symbol, price_type,token_uri, token_id),
+            price_type, token_uri, token_id, referrer, promo_code } =>
+        mint_item_by_name(deps, _env, info, name , validate_address(api, &owner)?, 
+        collection_name, collection_symbol, price_type,token_uri, token_id, validate_optional_address(api, &referrer)?, promo_code),
 
         ExecuteMsg::MintItem { seed , owner, collection_name, 
-            collection_symbol, price_type, token_uri, token_id }
-        => mint_item(deps, _env, info, str_to_u64(seed, 20502) , owner, 
-        collection_name, collection_symbol,price_type, token_uri, token_id),
+            collection_symbol, price_type, token_uri, token_id, referrer, promo_code } =>
+        mint_item(deps, _env, info, str_to_u64(seed, 20502) , validate_address(api, &owner)?, 
+        collection_name, collection_symbol,price_type, token_uri, token_id, validate_optional_address(api, &referrer)?, promo_code),
 
         ExecuteMsg::UpdateContractInfo { fees, treasuries , contracts,  log_last_payment} =>
-        update_contract_info(deps, _env, info, fees, treasuries, contracts,log_last_payment),
+        update_contract_info(deps, _env, info, fees, treasuries.map(|t| validate_addresses(api, &t)).transpose()?, contracts,log_last_payment),
         
-        ExecuteMsg::TransferNft { recipient, token_id} => 
-        transfer_nft(deps, _env, info, recipient, token_id),
+        ExecuteMsg::TransferNft { recipient, token_id} =>
+        transfer_nft(deps, _env, info, validate_address(api, &recipient)?.to_string(), token_id),
  
         ExecuteMsg::BurnNft { token_id} => 
         burn_nft(deps, _env, info, token_id),
 
-        ExecuteMsg::SendNft { token_id, contract_addr, action} => 
-        send_nft(deps, _env, info, token_id, contract_addr, action),
+        ExecuteMsg::SendNft { token_id, contract_addr, action} =>
+        send_nft(deps, _env, info, token_id, validate_address(api, &contract_addr)?.to_string(), action),
 
-        ExecuteMsg::SimpleMint { item, token_uri, token_id }=> 
-        init_and_simple_mint(deps, _env, info, item, token_uri, token_id),
+        ExecuteMsg::SimpleMint { item, token_uri, token_id } =>
+        init_and_simple_mint(deps, _env, info, validate_item_addresses(api, item)?, token_uri, token_id),
 
         ExecuteMsg::ReceiveNft(msg) =>
         receive_nft(deps, _env, info, msg),
 
         ExecuteMsg::CreateSellOffer { offer , create_sell_offer_fee} =>
         create_sell_offer(deps, _env, info, offer, create_sell_offer_fee),
+
+        ExecuteMsg::AddPromoCodes { collection_name, collection_symbol, codes } =>
+        add_promo_codes(deps, _env, info, collection_name, collection_symbol, codes),
+
+        ExecuteMsg::RemovePromoCode { collection_name, collection_symbol, code_hash } =>
+        remove_promo_code(deps, _env, info, collection_name, collection_symbol, code_hash),
+
+        ExecuteMsg::SubmitForReview { collection_name, collection_symbol } =>
+        submit_for_review(deps, _env, info, collection_name, collection_symbol),
+
+        ExecuteMsg::ApproveCollection { owner, collection_name, collection_symbol, reason } =>
+        approve_collection(deps, _env, info, validate_address(api, &owner)?, collection_name, collection_symbol, reason),
+
+        ExecuteMsg::RejectCollection { owner, collection_name, collection_symbol, reason } =>
+        reject_collection(deps, _env, info, validate_address(api, &owner)?, collection_name, collection_symbol, reason),
+
+        ExecuteMsg::SetPaused { group, paused, reason } =>
+        set_paused(deps, _env, info, group, paused, reason),
+
+        ExecuteMsg::SetValidationLimits { limits } =>
+        set_validation_limits(deps, info, limits),
+
+        ExecuteMsg::RekeyCollections { limit } =>
+        rekey_collections(deps, info, limit),
+
+        ExecuteMsg::PauseMinting { owner, collection_name, collection_symbol, reason, resume_at } =>
+        pause_minting(deps, _env, info, validate_address(api, &owner)?, collection_name, collection_symbol, reason, resume_at),
+
+        ExecuteMsg::ResumeMinting { owner, collection_name, collection_symbol } =>
+        resume_minting(deps, info, validate_address(api, &owner)?, collection_name, collection_symbol),
+
+        ExecuteMsg::BlockAddresses { addresses, reason } =>
+        block_addresses(deps, _env, info, validate_addresses(api, &addresses)?, reason),
+
+        ExecuteMsg::UnblockAddresses { addresses } =>
+        unblock_addresses(deps, info, validate_addresses(api, &addresses)?),
+
+        ExecuteMsg::BlockCollectionAddresses { owner, collection_name, collection_symbol, addresses, reason } =>
+        block_collection_addresses(deps, _env, info, validate_address(api, &owner)?, collection_name, collection_symbol, validate_addresses(api, &addresses)?, reason),
+
+        ExecuteMsg::UnblockCollectionAddresses { owner, collection_name, collection_symbol, addresses } =>
+        unblock_collection_addresses(deps, info, validate_address(api, &owner)?, collection_name, collection_symbol, validate_addresses(api, &addresses)?),
+
+        ExecuteMsg::AddToPhaseAllowlist { owner, collection_name, collection_symbol, phase, addresses } =>
+        add_to_phase_allowlist(deps, info, validate_address(api, &owner)?, collection_name, collection_symbol, phase, validate_addresses(api, &addresses)?),
+
+        ExecuteMsg::RemoveFromPhaseAllowlist { owner, collection_name, collection_symbol, phase, addresses } =>
+        remove_from_phase_allowlist(deps, info, validate_address(api, &owner)?, collection_name, collection_symbol, phase, validate_addresses(api, &addresses)?),
+
+        ExecuteMsg::SetCollectionSigners { collection_name, collection_symbol, signers } =>
+        set_collection_signers(deps, info, collection_name, collection_symbol, validate_signers_addresses(api, signers)?),
+
+        ExecuteMsg::ProposeCollectionChange { owner, collection_name, collection_symbol, changes, action, signers } =>
+        propose_collection_change(deps, _env, info, validate_address(api, &owner)?, collection_name, collection_symbol, 
+        changes.map(|c| validate_collection_addresses(api, c)).transpose()?, validate_proposed_action(api, action)?, 
+        signers.map(|s| validate_signers_addresses(api, s)).transpose()?),
+
+        ExecuteMsg::ApproveCollectionChange { owner, collection_name, collection_symbol, proposal_id } =>
+        approve_collection_change(deps, _env, info, validate_address(api, &owner)?, collection_name, collection_symbol, proposal_id),
+
+        ExecuteMsg::CancelCollectionChange { owner, collection_name, collection_symbol, proposal_id } =>
+        cancel_collection_change(deps, info, validate_address(api, &owner)?, collection_name, collection_symbol, proposal_id),
+
+        ExecuteMsg::AuthorizeMinter { collection_name, collection_symbol, minter, price_type, max_mints } =>
+        authorize_minter(deps, _env, info, collection_name, collection_symbol, validate_address(api, &minter)?, price_type, max_mints),
+
+        ExecuteMsg::RevokeMinter { collection_name, collection_symbol, minter } =>
+        revoke_minter(deps, info, collection_name, collection_symbol, validate_address(api, &minter)?),
+
+        ExecuteMsg::MintFor { seed, owner, collection_name, collection_symbol, recipient, token_uri, token_id } =>
+        mint_for(deps, _env, info, str_to_u64(seed, 20502), validate_address(api, &owner)?, collection_name, collection_symbol, 
+        validate_address(api, &recipient)?, token_uri, token_id),
+
+        ExecuteMsg::UpdateItem { item } =>
+        update_item(deps, _env, info, validate_item_addresses(api, item)?),
+
+        ExecuteMsg::RemoveItem { owner, collection_name, collection_symbol, item_name } =>
+        remove_item(deps, info, validate_address(api, &owner)?, collection_name, collection_symbol, item_name),
+
+        ExecuteMsg::GrantCollectionRole { collection_name, collection_symbol, member, role } =>
+        grant_collection_role(deps, info, collection_name, collection_symbol, validate_address(api, &member)?, role),
+
+        ExecuteMsg::RevokeCollectionRole { collection_name, collection_symbol, member, role } =>
+        revoke_collection_role(deps, info, collection_name, collection_symbol, validate_address(api, &member)?, role),
+
+        ExecuteMsg::ProposeCollectionOwner { collection_name, collection_symbol, new_owner, update_treasury } =>
+        propose_collection_owner(deps, _env, info, collection_name, collection_symbol, validate_address(api, &new_owner)?, update_treasury),
+
+        ExecuteMsg::CancelCollectionOwnerProposal { collection_name, collection_symbol } =>
+        cancel_collection_owner_proposal(deps, info, collection_name, collection_symbol),
+
+        ExecuteMsg::AcceptCollectionOwner { owner, collection_name, collection_symbol } =>
+        accept_collection_owner(deps, _env, info, validate_address(api, &owner)?, collection_name, collection_symbol),
+
+        ExecuteMsg::VerifyCreator { creator, note } =>
+        verify_creator(deps, _env, info, validate_address(api, &creator)?, note),
+
+        ExecuteMsg::UnverifyCreator { creator } =>
+        unverify_creator(deps, info, validate_address(api, &creator)?),
+
+        ExecuteMsg::FeatureCollection { owner, collection_name, collection_symbol, position, expiry } =>
+        feature_collection(deps, _env, info, validate_address(api, &owner)?, collection_name, collection_symbol, position, expiry),
+
+        ExecuteMsg::UnfeatureCollection { owner, collection_name, collection_symbol } =>
+        unfeature_collection(deps, info, validate_address(api, &owner)?, collection_name, collection_symbol),
+
+        ExecuteMsg::ModerateCollection { owner, name, symbol, action, reason } =>
+        moderate_collection(deps, _env, info, validate_address(api, &owner)?, name, symbol, action, reason),
+
+        ExecuteMsg::ClaimAuctionRefund { owner, collection_name, collection_symbol } =>
+        claim_auction_refund(deps, _env, info, validate_address(api, &owner)?, collection_name, collection_symbol),
+
+        ExecuteMsg::ClaimAuctionProceeds { collection_name, collection_symbol } =>
+        claim_auction_proceeds(deps, _env, info, collection_name, collection_symbol),
+
+        ExecuteMsg::SetFeeTier { tier } =>
+        set_fee_tier(deps, _env, info, tier),
+
+        ExecuteMsg::RemoveFeeTier { name } =>
+        remove_fee_tier(deps, _env, info, name),
+
+        ExecuteMsg::SetCreatorFees { creator, tier, overrides } =>
+        set_creator_fees(deps, _env, info, validate_address(api, &creator)?, tier, overrides),
+
+        ExecuteMsg::RemoveCreatorFees { creator } =>
+        remove_creator_fees(deps, _env, info, validate_address(api, &creator)?),
     }
 }
 
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+
+    let api = deps.api;
+
     match msg {
         QueryMsg::GetCollection { owner, name, symbol } =>
-        to_binary(&get_collection(deps, owner, name, symbol)?),
+        to_binary(&get_collection(deps, _env, validate_address(api, &owner)?, name, symbol)?),
         
         QueryMsg::GetCollections { owner, start_after, limit } =>
-        to_binary(&get_collections(deps,owner, start_after, limit)?),
+        to_binary(&get_collections(deps, _env, validate_address(api, &owner)?, start_after, limit)?),
 
-        QueryMsg::GetActiveCollections {keyword, category, start, limit } =>
-        to_binary(&get_active_collections(deps, keyword, category, start, limit)?),
+        QueryMsg::GetActiveCollections {keyword, category, start, limit, verified_only } =>
+        to_binary(&get_active_collections(deps, _env, keyword, category, start, limit, verified_only)?),
 
 
         QueryMsg::GetAllCollections { start_after, limit } =>
-        to_binary(&get_all_collections(deps, start_after, limit)?),
+        to_binary(&get_all_collections(deps, _env, start_after, limit)?),
 
         QueryMsg::Tokens { owner, start_after, limit } =>
         get_minted_tokens_by_owner(deps, _env, owner, start_after, limit),
@@ -137,14 +312,95 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
 
 
         QueryMsg::GetItemsCount { owner, collection_name, collection_symbol } =>
-        to_binary(&get_items_count(deps, owner, collection_name, collection_symbol)?),
+        to_binary(&get_items_count(deps, validate_address(api, &owner)?, collection_name, collection_symbol)?),
         
         QueryMsg::GetItems { owner, collection_name, collection_symbol, start_after, limit } =>
-        to_binary(&get_items(deps, owner, collection_name, collection_symbol, start_after, limit )?),
+        to_binary(&get_items(deps, validate_address(api, &owner)?, collection_name, collection_symbol, start_after, limit )?),
         
         QueryMsg::GetItem { owner, collection_name, collection_symbol, item_name } =>
-        to_binary(&get_item(deps, owner, collection_name, collection_symbol, item_name )?),
+        to_binary(&get_item(deps, validate_address(api, &owner)?, collection_name, collection_symbol, item_name )?),
         
+        QueryMsg::SimulateMint { owner, collection_name, collection_symbol, price_type, sender, quantity } =>
+        to_binary(&simulate_mint(deps, _env, validate_address(api, &owner)?, collection_name, collection_symbol, price_type, validate_address(api, &sender)?, quantity)?),
+
+        QueryMsg::GetFeeSchedule {} =>
+        to_binary(&get_fee_schedule(deps)?),
+
+        QueryMsg::FeeQuote { operation, quantity } =>
+        to_binary(&get_fee_quote(deps, operation, quantity)?),
+
+        QueryMsg::GetFeeTiers {} =>
+        to_binary(&get_fee_tiers(deps)?),
+
+        QueryMsg::GetEffectiveFees { address } =>
+        to_binary(&get_effective_fees(deps, validate_address(api, &address)?)?),
+
+        QueryMsg::GetReferralStats { referrer } =>
+        to_binary(&get_referral_stats(deps, validate_address(api, &referrer)?)?),
+
+        QueryMsg::GetReferralLeaderboard { start_after, limit } =>
+        to_binary(&get_referral_leaderboard(deps, validate_optional_address(api, &start_after)?, limit)?),
+
+        QueryMsg::GetPromoCode { owner, collection_name, collection_symbol, code_hash } =>
+        to_binary(&get_promo_code(deps, _env, validate_address(api, &owner)?, collection_name, collection_symbol, code_hash)?),
+
+        QueryMsg::GetCurrentPrice { owner, collection_name, collection_symbol, price_type } =>
+        to_binary(&get_current_price(deps, _env, validate_address(api, &owner)?, collection_name, collection_symbol, price_type)?),
+
+        QueryMsg::GetAuctionStatus { owner, collection_name, collection_symbol, buyer } =>
+        to_binary(&get_auction_status(deps, _env, validate_address(api, &owner)?, collection_name, collection_symbol, validate_optional_address(api, &buyer)?)?),
+
+        QueryMsg::GetBondingCurveQuote { owner, collection_name, collection_symbol, quantity } =>
+        to_binary(&get_bonding_curve_quote(deps, validate_address(api, &owner)?, collection_name, collection_symbol, quantity)?),
+
+        QueryMsg::GetMintPhases { owner, collection_name, collection_symbol } =>
+        to_binary(&get_mint_phases(deps, validate_address(api, &owner)?, collection_name, collection_symbol)?),
+
+        QueryMsg::GetActivePhase { owner, collection_name, collection_symbol, wallet } =>
+        to_binary(&get_active_phase(deps, _env, validate_address(api, &owner)?, collection_name, collection_symbol, validate_optional_address(api, &wallet)?)?),
+
+        QueryMsg::GetReviewQueue { start, limit } =>
+        to_binary(&get_review_queue(deps, _env, start, limit)?),
+
+        QueryMsg::GetModeration { owner, collection_name, collection_symbol } =>
+        to_binary(&get_moderation(deps, validate_address(api, &owner)?, collection_name, collection_symbol)?),
+
+        QueryMsg::GetVerifiedCreator { creator } =>
+        to_binary(&get_verified_creator(deps, validate_address(api, &creator)?)?),
+
+        QueryMsg::GetFeaturedCollections { start, limit } =>
+        to_binary(&get_featured_collections(deps, _env, start, limit)?),
+
+        QueryMsg::GetPendingOwner { owner, collection_name, collection_symbol } =>
+        to_binary(&get_pending_owner(deps, validate_address(api, &owner)?, collection_name, collection_symbol)?),
+
+        QueryMsg::GetCollectionMembers { owner, collection_name, collection_symbol } =>
+        to_binary(&get_collection_members(deps, validate_address(api, &owner)?, collection_name, collection_symbol)?),
+
+        QueryMsg::GetDelegatedMinters { owner, collection_name, collection_symbol } =>
+        to_binary(&get_delegated_minters(deps, validate_address(api, &owner)?, collection_name, collection_symbol)?),
+
+        QueryMsg::GetCollectionSigners { owner, collection_name, collection_symbol } =>
+        to_binary(&get_collection_signers(deps, validate_address(api, &owner)?, collection_name, collection_symbol)?),
+
+        QueryMsg::GetPauseStatus {} =>
+        to_binary(&get_pause_status(deps)?),
+
+        QueryMsg::GetBlockedAddresses { start_after, limit } =>
+        to_binary(&get_blocked_addresses(deps, validate_optional_address(api, &start_after)?, limit)?),
+
+        QueryMsg::GetCollectionBlockedAddresses { owner, collection_name, collection_symbol, start_after, limit } =>
+        to_binary(&get_collection_blocked_addresses(deps, validate_address(api, &owner)?, collection_name, collection_symbol, validate_optional_address(api, &start_after)?, limit)?),
+
+        QueryMsg::GetPhaseAllowlist { owner, collection_name, collection_symbol, phase, start_after, limit } =>
+        to_binary(&get_phase_allowlist(deps, validate_address(api, &owner)?, collection_name, collection_symbol, phase, validate_optional_address(api, &start_after)?, limit)?),
+
+        QueryMsg::GetValidationLimits {} =>
+        to_binary(&get_validation_limits(deps)?),
+
+        QueryMsg::IsNameAvailable { name, symbol, sender } =>
+        to_binary(&is_name_available(deps, _env, name, symbol, validate_optional_address(api, &sender)?)?),
+
         QueryMsg::GetContractInfo {} =>
         to_binary(&get_contract_info(deps)?),
 
@@ -157,8 +413,27 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
 
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
+pub fn migrate(mut _deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
+
+    let stored = get_contract_version(_deps.storage)?;
+
+    let mut migrated : u32 = 0;
+
+    if is_version_before(&stored.version, TREASURY_PERCENTAGE_VERSION) {
+        migrated = migrate_treasury_percentages(_deps.branch())?;
+    }
+
+    let mut paused : u32 = 0;
+
+    if is_version_before(&stored.version, COLLECTION_STATUS_VERSION) {
+        paused = migrate_collection_statuses(_deps.branch())?;
+    }
+
+    set_contract_version(_deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
     Ok(Response::new()
     .add_attribute("method", "migrate")
-    .add_attribute("message", _msg.message))
+    .add_attribute("message", _msg.message)
+    .add_attribute("migrated_collections", format!("{}", migrated))
+    .add_attribute("paused_collections", format!("{}", paused)))
 }
@@ -0,0 +1,113 @@
+use cosmwasm_std::{DepsMut, Env, Response, MessageInfo, Addr, Timestamp, Storage, StdResult, Empty};
+use crate::state::{VerifiedCreator, FeaturedCollection};
+use crate::indexes::{VERIFIED_CREATORS_STORE, FEATURED_COLLECTIONS_STORE, FEATURED_POSITIONS_STORE};
+use crate::error::ContractError;
+use crate::ins::{collection_id, common_response, STATUS_OK};
+use crate::query::internal_get_collection;
+use crate::checks::*;
+
+
+pub fn verify_creator(deps : DepsMut, _env : Env, info : MessageInfo, creator : Addr, 
+    note : Option<String>) -> Result<Response, ContractError> {
+
+    check_if_contract_admin(deps.as_ref(), &info.sender)?;
+
+    VERIFIED_CREATORS_STORE.save(deps.storage, creator.clone(), &VerifiedCreator {
+        creator : creator.clone(),
+        verified_by : info.sender,
+        note : note,
+        date_verified : _env.block.time,
+    })?;
+
+    common_response(creator.as_str(), "verify_creator", STATUS_OK, None, None)
+}
+
+
+pub fn unverify_creator(deps : DepsMut, info : MessageInfo, creator : Addr) -> Result<Response, ContractError> {
+
+    check_if_contract_admin(deps.as_ref(), &info.sender)?;
+
+    if !VERIFIED_CREATORS_STORE.has(deps.storage, creator.clone()) {
+        return Err(ContractError::CustomErrorMesg { message : format!("{} is NOT a verified creator!", creator)});
+    }
+
+    VERIFIED_CREATORS_STORE.remove(deps.storage, creator.clone());
+
+    common_response(creator.as_str(), "unverify_creator", STATUS_OK, None, None)
+}
+
+
+/*
+Saves the featured collection along with its position, 
+replacing the position it was featured at before
+ */
+pub (crate) fn save_featured(storage : &mut dyn Storage, _key : &(Addr, String), 
+    featured : &FeaturedCollection) -> StdResult<()> {
+
+    remove_featured(storage, _key)?;
+
+    FEATURED_COLLECTIONS_STORE.save(storage, _key.clone(), featured)?;
+
+    FEATURED_POSITIONS_STORE.save(storage, (featured.position, _key.0.clone(), _key.1.clone()), &Empty {})
+}
+
+
+// returns whether the collection was featured
+pub (crate) fn remove_featured(storage : &mut dyn Storage, _key : &(Addr, String)) -> StdResult<bool> {
+
+    let featured = FEATURED_COLLECTIONS_STORE.may_load(storage, _key.clone())?;
+
+    if featured.is_none() {
+        return Ok(false);
+    }
+
+    FEATURED_POSITIONS_STORE.remove(storage, (featured.unwrap().position, _key.0.clone(), _key.1.clone()));
+
+    FEATURED_COLLECTIONS_STORE.remove(storage, _key.clone());
+
+    Ok(true)
+}
+
+
+pub fn feature_collection(deps : DepsMut, _env : Env, info : MessageInfo, owner : Addr, 
+    collection_name : String, collection_symbol : String, position : u32, expiry : Option<Timestamp>) 
+-> Result<Response, ContractError> {
+
+    check_if_contract_admin(deps.as_ref(), &info.sender)?;
+
+    if internal_get_collection(deps.as_ref(), owner.clone(), collection_name.clone(), 
+    collection_symbol.clone()).is_none() {
+        return Err(ContractError::CollectionNotFound { text: "Collection is NOT found!".to_string()});
+    }
+
+    if expiry.is_some() && expiry.unwrap() <= _env.block.time {
+        return Err(ContractError::CustomErrorMesg { message : "Expiry must be in the future!".to_string()});
+    }
+
+    let _key = (owner.clone(), collection_id(collection_name.clone(), collection_symbol.clone()));
+
+    save_featured(deps.storage, &_key, &FeaturedCollection {
+        owner : owner,
+        collection_name : collection_name,
+        collection_symbol : collection_symbol,
+        position : position,
+        expiry : expiry,
+    })?;
+
+    common_response(format!("{}-{}",_key.0, _key.1).as_str(), "feature_collection", STATUS_OK, None, None)
+}
+
+
+pub fn unfeature_collection(deps : DepsMut, info : MessageInfo, owner : Addr, 
+    collection_name : String, collection_symbol : String) -> Result<Response, ContractError> {
+
+    check_if_contract_admin(deps.as_ref(), &info.sender)?;
+
+    let _key = (owner, collection_id(collection_name, collection_symbol));
+
+    if !remove_featured(deps.storage, &_key)? {
+        return Err(ContractError::CustomErrorMesg { message : "Collection is NOT featured!".to_string()});
+    }
+
+    common_response(format!("{}-{}",_key.0, _key.1).as_str(), "unfeature_collection", STATUS_OK, None, None)
+}
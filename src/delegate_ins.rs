@@ -0,0 +1,199 @@
+use cosmwasm_std::{DepsMut, Env, Response, MessageInfo, Addr, Coin, Uint128};
+use crate::state::{DelegatedMinter, FeeType, PRICE_TYPE_STANDARD};
+use crate::indexes::DELEGATED_MINTERS_STORE;
+use crate::error::ContractError;
+use crate::ins::{collection_id, common_response, increment_minted_count, record_phase_mint, 
+internal_remove_item, mark_sold_out_if_no_items, record_throttled_mint, STATUS_OK};
+use crate::query::{internal_get_collection, internal_get_all_items, internal_get_minted_count, add_to_coins,
+internal_get_effective_fee};
+use crate::nft_ins::init_and_mint_for;
+use crate::auction_ins::record_auction_purchase;
+use crate::fee_ins::pay_contract_fee;
+use crate::checks::*;
+use pix0_contract_common::utils::RandomNumGen;
+
+
+pub fn authorize_minter(deps : DepsMut, _env : Env, info : MessageInfo, collection_name : String, 
+    collection_symbol : String, minter : Addr, price_type : Option<u8>, max_mints : Option<u32>) 
+-> Result<Response, ContractError> {
+
+    check_if_action_needs_approval(deps.as_ref(), 
+    &(info.sender.clone(), collection_id(collection_name.clone(), collection_symbol.clone())), "Minter authorization")?;
+
+    internal_authorize_minter(deps, _env, info, collection_name, collection_symbol, minter, price_type, max_mints)
+}
+
+
+pub (crate) fn internal_authorize_minter(deps : DepsMut, _env : Env, info : MessageInfo, collection_name : String, 
+    collection_symbol : String, minter : Addr, price_type : Option<u8>, max_mints : Option<u32>) 
+-> Result<Response, ContractError> {
+
+    let owner = info.sender.clone();
+
+    let collection = internal_get_collection(deps.as_ref(), owner.clone(), collection_name.clone(), 
+    collection_symbol.clone());
+
+    if collection.is_none() {
+        return Err(ContractError::CollectionNotFound { text: "Collection is NOT found!".to_string()});
+    }
+
+    if price_type.is_some() && collection.unwrap().price_by_type(price_type.unwrap()).is_none() {
+        return Err(ContractError::PriceTypeNotFound { text: 
+            format!("Price type {} is NOT found in collection!", price_type.unwrap())});
+    }
+
+    let _key = (owner, collection_id(collection_name, collection_symbol), minter.clone());
+
+    // the usage is kept when a minter is authorized again
+    let existing = DELEGATED_MINTERS_STORE.may_load(deps.storage, _key.clone())?;
+
+    DELEGATED_MINTERS_STORE.save(deps.storage, _key.clone(), &DelegatedMinter {
+        minter : minter,
+        price_type : price_type,
+        max_mints : max_mints,
+        minted : existing.clone().map(|m| m.minted).unwrap_or(0),
+        value : existing.map(|m| m.value).unwrap_or(vec![]),
+        revoked : false,
+        date_authorized : _env.block.time,
+    })?;
+
+    common_response(format!("{}-{}={}", _key.0, _key.1, _key.2).as_str(), "authorize_minter", 
+    STATUS_OK, None, None)
+}
+
+
+pub fn revoke_minter(deps : DepsMut, info : MessageInfo, collection_name : String, 
+    collection_symbol : String, minter : Addr) -> Result<Response, ContractError> {
+
+    let _key = (info.sender, collection_id(collection_name, collection_symbol), minter);
+
+    let delegated = DELEGATED_MINTERS_STORE.may_load(deps.storage, _key.clone())?;
+
+    if delegated.is_none() {
+        return Err(ContractError::NotDelegatedMinter { text: 
+            format!("{} is NOT a delegated minter of the collection!", _key.2)});
+    }
+
+    let mut delegated = delegated.unwrap();
+
+    delegated.revoked = true;
+
+    DELEGATED_MINTERS_STORE.save(deps.storage, _key.clone(), &delegated)?;
+
+    common_response(format!("{}-{}={}", _key.0, _key.1, _key.2).as_str(), "revoke_minter", 
+    STATUS_OK, None, None)
+}
+
+
+/*
+Mints a random item of the collection into the recipient's wallet on
+behalf of a delegated minter. The minter pays the contract minting fee
+only, the price of the minter's price type, or zero for free mints, is 
+added to the minter's usage to be settled off-chain. The mint still counts 
+toward the minted count, the throttle of the minter and the phase limits 
+of the recipient
+ */
+pub fn mint_for(mut deps : DepsMut, _env : Env, info : MessageInfo, seed : u64, owner : Addr, 
+    collection_name : String, collection_symbol : String, recipient : Addr, 
+    token_uri : Option<String>, token_id : Option<String>) -> Result<Response, ContractError> {
+
+    let collection = internal_get_collection(deps.as_ref(), owner.clone(), 
+    collection_name.clone(), collection_symbol.clone());
+
+    if collection.is_none() {
+        return Err(ContractError::CollectionNotFound { text: "Collection is NOT found!".to_string()});
+    }
+
+    let collection = collection.unwrap();
+
+    let collection_key = (owner.clone(), collection_id(collection_name.clone(), collection_symbol.clone()));
+
+    let _key = (collection_key.0.clone(), collection_key.1.clone(), info.sender.clone());
+
+    let delegated = DELEGATED_MINTERS_STORE.may_load(deps.storage, _key.clone())?;
+
+    if delegated.is_none() || delegated.clone().unwrap().revoked {
+        return Err(ContractError::NotDelegatedMinter { text: 
+            format!("{} is NOT a delegated minter of the collection!", info.sender)});
+    }
+
+    let mut delegated = delegated.unwrap();
+
+    if delegated.max_mints.is_some() && delegated.minted >= delegated.max_mints.unwrap() {
+        return Err(ContractError::DelegatedMintLimitReached { text: 
+            format!("{} has minted {} of {} allowed!", info.sender, delegated.minted, delegated.max_mints.unwrap())});
+    }
+
+    check_if_collection_not_suspended(deps.as_ref(), &collection)?;
+
+    check_if_not_blocked_by_collection(deps.as_ref(), &collection, &info.sender)?;
+
+    check_if_not_blocked_by_collection(deps.as_ref(), &collection, &recipient)?;
+
+    check_if_minting_not_paused(deps.as_ref(), &collection, _env.block.time)?;
+
+    check_if_mint_not_throttled(deps.as_ref(), &_env, &collection, &info.sender)?;
+
+    let minted = internal_get_minted_count(deps.as_ref(), owner.clone(), collection_name.clone(), 
+    collection_symbol.clone());
+
+    let price = check_if_collection_mintable(&collection, 
+    Some(delegated.price_type.unwrap_or(PRICE_TYPE_STANDARD)), _env.block.time, minted)?;
+
+    let value = if delegated.price_type.is_some() { price } 
+    else { Coin { amount : Uint128::zero(), denom : price.denom } };
+
+    let phase = collection.active_phase(_env.block.time);
+
+    if phase.is_some() {
+        check_if_eligible_for_phase(deps.as_ref(), &collection, &phase.clone().unwrap(), &recipient, 1)?;
+    }
+
+    let items = internal_get_all_items(deps.as_ref(), owner.clone(), collection_name.clone(), 
+    collection_symbol.clone());
+
+    let mut rng = RandomNumGen::new(seed);
+    let index = rng.generate_range(0, items.len() as u64) as usize;
+
+    let itm = items.get(index);
+
+    if itm.is_none() {
+        return Err(ContractError::FailedToFindNft { text : format!("Failed to find item at index :{}", index)});
+    }
+
+    let i = itm.unwrap();
+
+    // counted as an auction purchase of the recipient as in mint_item, with nothing 
+    // paid here, so nothing is held for its refund, the value is owed by the minter
+    record_auction_purchase(deps.branch(), _env.block.time, &collection, &recipient, 
+    Coin { amount : Uint128::zero(), denom : value.denom.clone() })?;
+
+    delegated.minted += 1;
+
+    add_to_coins(&mut delegated.value, value)?;
+
+    DELEGATED_MINTERS_STORE.save(deps.storage, _key, &delegated)?;
+
+    increment_minted_count(deps.branch(), &collection)?;
+
+    record_phase_mint(deps.branch(), &collection, &phase, &recipient)?;
+
+    record_throttled_mint(deps.branch(), &_env, &collection, &info.sender)?;
+
+    let fee = internal_get_effective_fee(deps.as_ref(), &owner, &FeeType::NftMinting);
+
+    if fee.is_some() {
+        check_if_fund_sufficient(info.clone(), fee.unwrap())?;
+    }
+
+    let fee_msgs = pay_contract_fee(deps.branch(), _env.clone(), info.clone(), FeeType::NftMinting, &owner)?;
+
+    let res = init_and_mint_for(deps.branch(), _env.clone(), recipient, i.clone(), token_uri, token_id);
+
+    if res.is_ok() {
+        internal_remove_item(owner, collection_name, collection_symbol, i.name.clone(), deps.branch());
+        mark_sold_out_if_no_items(deps, &collection_key, _env.block.time)?;
+    }
+
+    res.map(|r| r.add_messages(fee_msgs))
+}
@@ -33,6 +33,48 @@ pub enum ContractError {
     #[error("InvalidCollectionStatus")]
     InvalidCollectionStatus { text : String },
 
+    #[error("IllegalStatusTransition")]
+    IllegalStatusTransition { text : String },
+
+    #[error("CollectionNotApproved")]
+    CollectionNotApproved { text : String },
+
+    #[error("CollectionSuspended")]
+    CollectionSuspended { text : String },
+
+    #[error("MissingCollectionRole")]
+    MissingCollectionRole { text : String },
+
+    #[error("NotDelegatedMinter")]
+    NotDelegatedMinter { text : String },
+
+    #[error("DelegatedMintLimitReached")]
+    DelegatedMintLimitReached { text : String },
+
+    #[error("InvalidSigners")]
+    InvalidSigners { text : String },
+
+    #[error("ApprovalRequired")]
+    ApprovalRequired { text : String },
+
+    #[error("Paused")]
+    Paused { text : String },
+
+    #[error("MintingPaused")]
+    MintingPaused { text : String },
+
+    #[error("AddressBlocked")]
+    AddressBlocked { text : String },
+
+    #[error("BlockMintLimitReached")]
+    BlockMintLimitReached { text : String },
+
+    #[error("MintTooSoon")]
+    MintTooSoon { text : String },
+
+    #[error("ContractMinterNotAllowed")]
+    ContractMinterNotAllowed { text : String },
+
     #[error("CollectionNotFound")]
     CollectionNotFound { text : String },
 
@@ -42,6 +84,72 @@ pub enum ContractError {
     #[error("MintByNameIsNotAllowed")]
     MintByNameIsNotAllowed { text : String },
 
+    #[error("UnknownFeeName")]
+    UnknownFeeName { text : String },
+
+    #[error("InvalidDutchAuction")]
+    InvalidDutchAuction { text : String },
+
+    #[error("InvalidBondingCurve")]
+    InvalidBondingCurve { text : String },
+
+    #[error("PriceOverflow")]
+    PriceOverflow { text : String },
+
+    #[error("InvalidMintPhases")]
+    InvalidMintPhases { text : String },
+
+    #[error("NoActiveMintPhase")]
+    NoActiveMintPhase { text : String },
+
+    #[error("NotEligibleForMintPhase")]
+    NotEligibleForMintPhase { text : String },
+
+    #[error("MintPhaseWalletLimitReached")]
+    MintPhaseWalletLimitReached { text : String },
+
+    #[error("InvalidLaunchWindow")]
+    InvalidLaunchWindow { text : String },
+
+    #[error("AuctionNotActive")]
+    AuctionNotActive { text : String },
+
+    #[error("AuctionNotEnded")]
+    AuctionNotEnded { text : String },
+
+    #[error("NothingToClaim")]
+    NothingToClaim { text : String },
+
+    #[error("InvalidPromoCode")]
+    InvalidPromoCode { text : String },
+
+    #[error("PromoCodeExpired")]
+    PromoCodeExpired { text : String },
+
+    #[error("PromoCodeUsedUp")]
+    PromoCodeUsedUp { text : String },
+
+    #[error("NameNotAvailable")]
+    NameNotAvailable { text : String },
+
+    #[error("NameReservationLimitReached")]
+    NameReservationLimitReached { text : String },
+
+    #[error("InvalidInput")]
+    InvalidInput { text : String },
+
+    #[error("InvalidReferralShare")]
+    InvalidReferralShare { text : String },
+
+    #[error("InvalidFeeDiscount")]
+    InvalidFeeDiscount { text : String },
+
+    #[error("FeeTierNotFound")]
+    FeeTierNotFound { text : String },
+
+    #[error("PriceTypeNotFound")]
+    PriceTypeNotFound { text : String },
+
     #[error("InsufficientFund")]
     InsufficientFund { text : String },
    
@@ -0,0 +1,121 @@
+use cosmwasm_std::{DepsMut, Env, Response, MessageInfo, Addr, BankMsg, Coin};
+use crate::state::{FeeType, FeeTier, FeeOverride, CreatorFees, TREASURY_PERCENTAGE_TOTAL};
+use crate::indexes::{FEE_TIERS_STORE, CREATOR_FEES_STORE};
+use crate::error::ContractError;
+use crate::query::{internal_get_contract_fee, internal_get_effective_fee, internal_get_contract_treasuries};
+use crate::ins::{common_response, STATUS_OK};
+use crate::checks::*;
+use crate::utils::split_by_percentage;
+use pix0_contract_common::funcs::try_paying_contract_treasuries;
+
+
+pub fn set_fee_tier(deps : DepsMut, _env : Env, info : MessageInfo, tier : FeeTier) 
+-> Result<Response, ContractError> {
+
+    check_if_contract_admin(deps.as_ref(), &info.sender)?;
+
+    check_if_fee_tier_valid(&tier)?;
+
+    FEE_TIERS_STORE.save(deps.storage, tier.name.clone(), &tier)?;
+
+    common_response(tier.name.as_str(), "set_fee_tier", STATUS_OK, None, None)
+}
+
+
+pub fn remove_fee_tier(deps : DepsMut, _env : Env, info : MessageInfo, name : String) 
+-> Result<Response, ContractError> {
+
+    check_if_contract_admin(deps.as_ref(), &info.sender)?;
+
+    if !FEE_TIERS_STORE.has(deps.storage, name.clone()) {
+        return Err(ContractError::FeeTierNotFound { text: format!("Fee tier {} is NOT found!", name)});
+    }
+
+    FEE_TIERS_STORE.remove(deps.storage, name.clone());
+
+    common_response(name.as_str(), "remove_fee_tier", STATUS_OK, None, None)
+}
+
+
+pub fn set_creator_fees(deps : DepsMut, _env : Env, info : MessageInfo, creator : Addr,
+    tier : Option<String>, overrides : Option<Vec<FeeOverride>>) -> Result<Response, ContractError> {
+
+    check_if_contract_admin(deps.as_ref(), &info.sender)?;
+
+    if tier.is_some() && !FEE_TIERS_STORE.has(deps.storage, tier.clone().unwrap()) {
+        return Err(ContractError::FeeTierNotFound { text: 
+            format!("Fee tier {} is NOT found!", tier.unwrap())});
+    }
+
+    check_if_fee_overrides_valid(deps.as_ref(), &overrides)?;
+
+    let creator_fees = CreatorFees {
+        creator : creator.clone(),
+        tier : tier,
+        overrides : overrides,
+    };
+
+    CREATOR_FEES_STORE.save(deps.storage, creator.clone(), &creator_fees)?;
+
+    common_response(creator.as_str(), "set_creator_fees", STATUS_OK, None, None)
+}
+
+
+pub fn remove_creator_fees(deps : DepsMut, _env : Env, info : MessageInfo, creator : Addr) 
+-> Result<Response, ContractError> {
+
+    check_if_contract_admin(deps.as_ref(), &info.sender)?;
+
+    CREATOR_FEES_STORE.remove(deps.storage, creator.clone());
+
+    common_response(creator.as_str(), "remove_creator_fees", STATUS_OK, None, None)
+}
+
+
+/*
+Pays the fee of the fee type to the contract treasuries, the fee
+is the effective fee of the creator after its fee tier or override.
+When the creator has no reduced fee, it is paid the same way as before 
+by try_paying_contract_treasuries, a reduced fee is split equally by 
+split_by_percentage, so the rounding remainder is paid out too
+ */
+pub (crate) fn pay_contract_fee(deps : DepsMut, _env : Env, info : MessageInfo, 
+    fee_type : FeeType, creator : &Addr) -> Result<Vec<BankMsg>, ContractError> {
+
+    let fee = internal_get_effective_fee(deps.as_ref(), creator, &fee_type);
+
+    if fee == internal_get_contract_fee(deps.as_ref(), fee_type.name()) {
+
+        return Ok(try_paying_contract_treasuries(deps, _env, info, fee_type.name())?);
+    }
+
+    if fee.is_none() || fee.clone().unwrap().amount.is_zero() {
+        // fee waived
+        return Ok(vec![]);
+    }
+
+    let fee = fee.unwrap();
+
+    check_if_fund_sufficient(info.clone(), fee.clone())?;
+
+    let treasuries = internal_get_contract_treasuries(deps.as_ref());
+
+    if treasuries.len() == 0 {
+        return Err(ContractError::ErrorPayingTreasuries { text: "No contract treasury is defined!".to_string()});
+    }
+
+    // split equally among the contract treasuries, the remainders go to the first
+    let share = TREASURY_PERCENTAGE_TOTAL / treasuries.len() as u16;
+
+    let percentages : Vec<u16> = (0..treasuries.len())
+    .map(|i| if i == 0 { TREASURY_PERCENTAGE_TOTAL - share * (treasuries.len() as u16 - 1) } else { share })
+    .collect();
+
+    let amounts = split_by_percentage(fee.amount, &percentages, 0);
+
+    Ok(treasuries.into_iter().zip(amounts.into_iter())
+    .filter(|(_, amount)| !amount.is_zero())
+    .map(|(wallet, amount)| BankMsg::Send { to_address : wallet.to_string(), 
+        amount : vec![Coin { amount : amount, denom : fee.denom.clone() }] })
+    .collect())
+}
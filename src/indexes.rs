@@ -1,18 +1,114 @@
-use crate::state::{Collection, Item};
-use cosmwasm_std::Addr;
-use cw_storage_plus::{UniqueIndex, Index, IndexList, IndexedMap, Map};
-use crate::ins::collection_id;
+use crate::state::{Collection, Item, FeeTier, CreatorFees, ReferralStats, PromoCode, AuctionState, AuctionPurchase,
+PhaseMint, Moderation, VerifiedCreator, FeaturedCollection,
+PendingOwner, CollectionRole, DelegatedMinter, CollectionSigners, ChangeProposal,
+PauseFlag, MintPause, BlockedAddress, BlockMints, ValidationLimits, NameReservation, COLLECTION_STATUS_DRAFT};
+use cosmwasm_std::{Addr, Empty};
+use cw_storage_plus::{UniqueIndex, MultiIndex, Index, IndexList, IndexedMap, Map, Item as StorageItem};
+use crate::ins::{collection_id, name_key};
 
 
 pub const COLLECTION_ITEMS_STORE : Map<(Addr,String,String), Item> = Map::new("COLLECTION_ITEMS_STORE");
 
+pub const FEE_TIERS_STORE : Map<String, FeeTier> = Map::new("FEE_TIERS_STORE");
+
+pub const CREATOR_FEES_STORE : Map<Addr, CreatorFees> = Map::new("CREATOR_FEES_STORE");
+
+pub const REFERRALS_STORE : Map<Addr, ReferralStats> = Map::new("REFERRALS_STORE");
+
+// the referrers by (number of referred mints, referrer), for ranking them
+pub const REFERRAL_RANKS_STORE : Map<(u64,Addr), Empty> = Map::new("REFERRAL_RANKS_STORE");
+
+// keyed by collection owner, collection id and code hash
+pub const PROMO_CODES_STORE : Map<(Addr,String,String), PromoCode> = Map::new("PROMO_CODES_STORE");
+
+// keyed by collection owner and collection id
+pub const AUCTION_STATES_STORE : Map<(Addr,String), AuctionState> = Map::new("AUCTION_STATES_STORE");
+
+// keyed by collection owner, collection id and buyer
+pub const AUCTION_PURCHASES_STORE : Map<(Addr,String,Addr), AuctionPurchase> = Map::new("AUCTION_PURCHASES_STORE");
+
+// mints per phase by (collection owner, collection id, wallet)
+pub const PHASE_MINTS_STORE : Map<(Addr,String,Addr), Vec<PhaseMint>> = Map::new("PHASE_MINTS_STORE");
+
+// wallets allowed to mint in a phase by ((collection owner, collection id), phase, wallet)
+pub const PHASE_ALLOWLISTS_STORE : Map<((Addr,String),String,Addr), Empty> = Map::new("PHASE_ALLOWLISTS_STORE");
+
+// moderation of collections by (collection owner, collection id)
+pub const MODERATIONS_STORE : Map<(Addr,String), Moderation> = Map::new("MODERATIONS_STORE");
+
+// contract wide pause flags by the name of the paused group
+pub const PAUSE_FLAGS_STORE : Map<String, PauseFlag> = Map::new("PAUSE_FLAGS_STORE");
+
+// minting paused by the creators by (collection owner, collection id)
+pub const MINT_PAUSES_STORE : Map<(Addr,String), MintPause> = Map::new("MINT_PAUSES_STORE");
+
+// addresses blocked by the admins across the contract
+pub const BLOCKLIST_STORE : Map<Addr, BlockedAddress> = Map::new("BLOCKLIST_STORE");
+
+// addresses blocked by the creators by (collection owner, collection id, address)
+pub const COLLECTION_BLOCKLISTS_STORE : Map<(Addr,String,Addr), BlockedAddress> = Map::new("COLLECTION_BLOCKLISTS_STORE");
+
+// the limits of the fields of collections and items, the defaults apply when not set
+pub const VALIDATION_LIMITS_STORE : StorageItem<ValidationLimits> = StorageItem::new("VALIDATION_LIMITS_STORE");
+
+// the key of the last collection checked by the paged re-keying of the legacy collection ids
+pub const COLLECTION_IDS_CURSOR_STORE : StorageItem<(Addr,String)> = StorageItem::new("COLLECTION_IDS_CURSOR_STORE");
+
+pub const VERIFIED_CREATORS_STORE : Map<Addr, VerifiedCreator> = Map::new("VERIFIED_CREATORS_STORE");
+
+// featured collections by (collection owner, collection id)
+pub const FEATURED_COLLECTIONS_STORE : Map<(Addr,String), FeaturedCollection> = Map::new("FEATURED_COLLECTIONS_STORE");
+
+// the featured collections by (position, collection owner, collection id), for paging them in order
+pub const FEATURED_POSITIONS_STORE : Map<(u32,Addr,String), Empty> = Map::new("FEATURED_POSITIONS_STORE");
+
+// names and symbols reserved by the creators by name key, 
+// as the names and symbols are unique across the creators
+pub const NAME_RESERVATIONS_STORE : Map<String, NameReservation> = Map::new("NAME_RESERVATIONS_STORE");
+
+// the reservations by (reserver, name key), for counting those of a creator
+pub const NAME_RESERVERS_STORE : Map<(Addr,String), Empty> = Map::new("NAME_RESERVERS_STORE");
+
+// proposed owners by (collection owner, collection id)
+pub const PENDING_OWNERS_STORE : Map<(Addr,String), PendingOwner> = Map::new("PENDING_OWNERS_STORE");
+
+// roles of the members by (collection owner, collection id, member)
+pub const COLLECTION_MEMBERS_STORE : Map<(Addr,String,Addr), Vec<CollectionRole>> = Map::new("COLLECTION_MEMBERS_STORE");
+
+// delegated minters by (collection owner, collection id, minter)
+pub const DELEGATED_MINTERS_STORE : Map<(Addr,String,Addr), DelegatedMinter> = Map::new("DELEGATED_MINTERS_STORE");
+
+// signers approving the sensitive changes by (collection owner, collection id)
+pub const COLLECTION_SIGNERS_STORE : Map<(Addr,String), CollectionSigners> = Map::new("COLLECTION_SIGNERS_STORE");
+
+// pending change proposals by (collection owner, collection id, proposal id)
+pub const CHANGE_PROPOSALS_STORE : Map<(Addr,String,u64), ChangeProposal> = Map::new("CHANGE_PROPOSALS_STORE");
+
+// the last proposal id by (collection owner, collection id), never reused once
+// a proposal is applied or cancelled
+pub const LAST_PROPOSAL_IDS_STORE : Map<(Addr,String), u64> = Map::new("LAST_PROPOSAL_IDS_STORE");
+
+// mints in the latest block by (collection owner, collection id)
+pub const BLOCK_MINTS_STORE : Map<(Addr,String), BlockMints> = Map::new("BLOCK_MINTS_STORE");
+
+// block height of the latest mint by (collection owner, collection id, sender)
+pub const LAST_MINT_HEIGHTS_STORE : Map<(Addr,String,Addr), u64> = Map::new("LAST_MINT_HEIGHTS_STORE");
+
+// number of items minted so far by (collection owner, collection id)
+pub const MINTED_COUNTS_STORE : Map<(Addr,String), u64> = Map::new("MINTED_COUNTS_STORE");
+
 pub struct CollectionIndexes<'a> {
 
     // unique index by wallet address
     pub collections : UniqueIndex<'a, (Addr,String), Collection>,
 
-    // unique index by name and symbols
+    // unique index by name and symbols, a name and symbol 
+    // is used by one collection across all creators
     pub name_symbols : UniqueIndex<'a, String, Collection>,
+
+    // index by status and the date submitted for review, 
+    // which orders the review queue
+    pub statuses : MultiIndex<'a, (u8, u64), Collection, (Addr,String)>,
 }
 
 
@@ -20,7 +116,7 @@ impl IndexList<Collection> for CollectionIndexes<'_> {
 
     fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Collection>> + '_> {
 
-        let v : Vec<&dyn Index<Collection>> = vec![&self.collections, &self.name_symbols];
+        let v : Vec<&dyn Index<Collection>> = vec![&self.collections, &self.name_symbols, &self.statuses];
         Box::new(v.into_iter())
     } 
 }
@@ -29,14 +125,18 @@ pub fn collections_store<'a>() -> IndexedMap<'a,(Addr,String), Collection, Colle
 
     let indexes = CollectionIndexes {
 
-        collections : UniqueIndex::new(|u| (u.owner.clone().unwrap_or(Addr::unchecked("unknown")),
+        // a collection is only saved under the key of its owner, see collection_key, 
+        // a stored one without an owner is indexed under an empty owner rather than aborting
+        collections : UniqueIndex::new(|u| (u.owner.clone().unwrap_or_else(|| Addr::unchecked("")),
         collection_id(u.name.clone(), u.symbol.clone())), "COLLECTIONS"),
 
         name_symbols :  UniqueIndex::new(|u|  
-        collection_id(u.name.clone(), u.symbol.clone()), "COLLECTION_NAME_SYMBS"),
+        name_key(&u.name, &u.symbol), "COLLECTION_NAME_SYMBS"),
+
+        statuses : MultiIndex::new(|_pk, u| (u.status.unwrap_or(COLLECTION_STATUS_DRAFT), 
+        u.review.clone().and_then(|r| r.date_submitted).map(|d| d.nanos()).unwrap_or(0)), 
+        "COLLECTIONS_STORE", "COLLECTION_STATUSES"),
     };
 
     IndexedMap::new("COLLECTIONS_STORE", indexes)
 }
-
-
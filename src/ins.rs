@@ -1,18 +1,56 @@
-use cosmwasm_std::{DepsMut, Env, Response, MessageInfo, Addr, Order, BankMsg};
-use crate::state::{Collection, Treasury, Attribute, PriceType, Item, 
-COLLECTION_STATUS_ACTIVATED, PRICE_TYPE_STANDARD};
+use cosmwasm_std::{DepsMut, Env, Response, MessageInfo, Addr, Order, BankMsg, Timestamp};
+use crate::state::{Collection, Treasury, Attribute, PriceType, Item, FeeType, DutchAuction, BondingCurve, MintPhase, PhaseMint,
+COLLECTION_STATUS_ACTIVATED, COLLECTION_STATUS_DRAFT, COLLECTION_STATUS_SOLD_OUT, COLLECTION_STATUS_PENDING_REVIEW,
+CollectionReview, REVIEW_STATUS_PENDING, CollectionRole, MintThrottle, BlockMints};
 use pix0_market_handlers::state::Royalty;
-use crate::indexes::{collections_store,COLLECTION_ITEMS_STORE };
+use crate::indexes::{collections_store,COLLECTION_ITEMS_STORE, MINTED_COUNTS_STORE, PHASE_MINTS_STORE, 
+BLOCK_MINTS_STORE, LAST_MINT_HEIGHTS_STORE };
 use crate::error::ContractError;
-use crate::query::{internal_get_collection, internal_get_all_items, internal_get_item};
+use crate::query::{internal_get_collection, internal_get_all_items, internal_get_item, internal_get_minted_count};
 use crate::nft_ins::init_and_mint_nft;
-use pix0_contract_common::funcs::{try_paying_contract_treasuries};
+use crate::fee_ins::pay_contract_fee;
+use crate::promo_ins::apply_promo_code;
+use crate::auction_ins::{record_auction_purchase, mark_auction_ended, check_if_auction_settled};
 use pix0_contract_common::utils::RandomNumGen;
 use pix0_contract_common::state::{Fee, Contract};
 use crate::checks::*;
+use crate::ownership_ins::remove_collection_records;
+use crate::validation::{check_if_collection_fields_valid, check_if_item_fields_valid};
+use crate::names_ins::remove_name_reservation;
 
+/*
+The dashes and backslashes in the name and symbol are escaped, 
+so the first unescaped dash always separates the two and 
+no two pairs of name and symbol have the same id
+ */
 pub fn collection_id ( name : String, symbol : String ) -> String {
-    format!("{}-{}", name, symbol)
+    format!("{}-{}", escape_id_part(&name), escape_id_part(&symbol))
+}
+
+fn escape_id_part ( part : &str ) -> String {
+    part.replace('\\', "\\\\").replace('-', "\\-")
+}
+
+/*
+The key of a name and symbol across the creators, the case is ignored 
+so that names differing only in case are taken as the same
+ */
+pub fn name_key ( name : &str, symbol : &str ) -> String {
+    collection_id(name.to_lowercase(), symbol.to_lowercase())
+}
+
+/*
+The key of a stored collection by its owner and id, 
+a collection without an owner is never stored
+ */
+pub (crate) fn collection_key ( collection : &Collection ) -> Result<(Addr, String), ContractError> {
+
+    if collection.owner.is_none() {
+        return Err(ContractError::CollectionNotFound { text: 
+            format!("Collection {} has no owner!", collection.name)});
+    }
+
+    Ok((collection.owner.clone().unwrap(), collection_id(collection.name.clone(), collection.symbol.clone())))
 }
 
 /*
@@ -25,6 +63,8 @@ pub fn update_contract_info (deps: DepsMut,
     _log_last_payment : Option<bool>, 
  ) -> Result<Response, ContractError> {
 
+    check_if_fees_valid(&_fees)?;
+
     let res =  pix0_contract_common::funcs::update_contract_info(
         deps, _env, info, _fees, treasuries, contracts, _log_last_payment);
            
@@ -41,24 +81,50 @@ pub fn update_contract_info (deps: DepsMut,
 pub fn create_collection (deps: DepsMut, 
     _env : Env, info: MessageInfo,
     collection : Collection) -> Result<Response, ContractError> {
+
+    check_if_not_blocked(deps.as_ref(), &info.sender)?;
+
+    check_if_collection_fields_valid(deps.as_ref(), &collection, true)?;
         
     internal_create_collection(deps, _env, info, collection.name, 
         collection.symbol, collection.description, collection.treasuries, 
-        collection.attributes, collection.prices, collection.royalties, collection.status)
+        collection.attributes, collection.prices, collection.royalties, collection.status,
+        collection.referral_share, collection.dutch_auction, collection.bonding_curve,
+        collection.mint_phases, collection.launch_at, collection.close_at, collection.mint_throttle)
    
 }
 
 
 pub fn update_collection(deps: DepsMut, 
     _env : Env, info: MessageInfo,
+    owner : Option<Addr>,
     collection : Collection) -> Result<Response, ContractError> {
-  
-    let owner = info.clone().sender;
+
+    // members with a role update the collection of its owner
+    let owner = owner.unwrap_or(info.clone().sender);
+
+    check_if_change_needs_approval(deps.as_ref(), 
+    &(owner.clone(), collection_id(collection.name.clone(), collection.symbol.clone())), &collection)?;
+
+    internal_update_collection(deps, _env, info, owner, collection)
+}
+
+
+pub (crate) fn internal_update_collection(deps: DepsMut, 
+    _env : Env, info: MessageInfo,
+    owner : Addr,
+    collection : Collection) -> Result<Response, ContractError> {
+
+    check_if_collection_fields_valid(deps.as_ref(), &collection, false)?;
 
     are_treasuries_valid(&collection.treasuries)?;
     
     are_royalties_valid(&collection.royalties)?;
 
+    check_if_referral_share_valid(collection.referral_share)?;
+
+    check_if_dutch_auction_valid(&collection.dutch_auction)?;
+
     let _key = (owner.clone(), collection_id(collection.name.clone(), collection.symbol.clone()) );
   
     check_if_collection_status_valid(collection.status)?;
@@ -72,37 +138,127 @@ pub fn update_collection(deps: DepsMut,
 
     let mut collection_to_update = collection_to_update.unwrap();
 
+    check_if_not_blocked_by_collection(deps.as_ref(), &collection_to_update, &info.sender)?;
+
+    check_if_treasuries_not_blocked(deps.as_ref(), &collection_to_update, &collection.treasuries)?;
+
+    let collection_before = collection_to_update.clone();
+
     let mut to_update : bool = false;
 
+    let sender = info.sender.clone();
+
     if collection.description.is_some() {
+        check_if_collection_role(deps.as_ref(), &_key, &sender, CollectionRole::Editor)?;
         collection_to_update.description = collection.description;
         to_update = true; 
     }
     if collection.treasuries.is_some() {
+        check_if_collection_role(deps.as_ref(), &_key, &sender, CollectionRole::Finance)?;
         collection_to_update.treasuries = collection.treasuries;
         to_update = true; 
     }
 
     if collection.royalties.is_some() {
+        check_if_collection_role(deps.as_ref(), &_key, &sender, CollectionRole::Finance)?;
         collection_to_update.royalties = collection.royalties;
         to_update = true; 
     }
 
     if collection.prices.is_some() {
+        check_if_collection_role(deps.as_ref(), &_key, &sender, CollectionRole::Manager)?;
         collection_to_update.prices = collection.prices;
         to_update = true; 
     }
 
     if collection.attributes.is_some() {
+        check_if_collection_role(deps.as_ref(), &_key, &sender, CollectionRole::Editor)?;
         collection_to_update.attributes = collection.attributes;
         to_update = true; 
     }
 
     if collection.status.is_some() {
+
+        check_if_collection_role(deps.as_ref(), &_key, &sender, CollectionRole::Manager)?;
+
+        let from = collection_to_update.status.unwrap_or(COLLECTION_STATUS_DRAFT);
+
+        let to = collection.status.unwrap();
+
+        check_if_status_transition_allowed(Some(from), to)?;
+
+        if to == COLLECTION_STATUS_ACTIVATED && 
+        (from == COLLECTION_STATUS_DRAFT || from == COLLECTION_STATUS_PENDING_REVIEW) {
+
+            let review = check_if_activation_approved(deps.as_ref(), _env.block.time, 
+            &info.sender, &collection_to_update)?;
+
+            if review.is_some() {
+                collection_to_update.review = review;
+            }
+        }
+
         collection_to_update.status = collection.status; 
         to_update = true; 
     }
 
+    if collection.referral_share.is_some() {
+        check_if_collection_role(deps.as_ref(), &_key, &sender, CollectionRole::Finance)?;
+        collection_to_update.referral_share = collection.referral_share; 
+        to_update = true; 
+    }
+
+    if collection.dutch_auction.is_some() {
+        check_if_collection_role(deps.as_ref(), &_key, &sender, CollectionRole::Manager)?;
+        collection_to_update.dutch_auction = collection.dutch_auction; 
+        to_update = true; 
+    }
+
+    if collection.bonding_curve.is_some() {
+        check_if_collection_role(deps.as_ref(), &_key, &sender, CollectionRole::Manager)?;
+        collection_to_update.bonding_curve = collection.bonding_curve; 
+        to_update = true; 
+    }
+
+    if collection.mint_phases.is_some() {
+        check_if_collection_role(deps.as_ref(), &_key, &sender, CollectionRole::Manager)?;
+        collection_to_update.mint_phases = collection.mint_phases; 
+        to_update = true; 
+    }
+
+    if collection.launch_at.is_some() {
+        check_if_collection_role(deps.as_ref(), &_key, &sender, CollectionRole::Manager)?;
+        collection_to_update.launch_at = collection.launch_at; 
+        to_update = true; 
+    }
+
+    if collection.close_at.is_some() {
+        check_if_collection_role(deps.as_ref(), &_key, &sender, CollectionRole::Manager)?;
+        collection_to_update.close_at = collection.close_at; 
+        to_update = true; 
+    }
+
+    if collection.mint_throttle.is_some() {
+        check_if_collection_role(deps.as_ref(), &_key, &sender, CollectionRole::Manager)?;
+        check_if_mint_throttle_valid(&collection.mint_throttle)?;
+        collection_to_update.mint_throttle = collection.mint_throttle; 
+        to_update = true; 
+    }
+
+    check_if_launch_window_valid(collection_to_update.launch_at, collection_to_update.close_at)?;
+
+    check_if_bonding_curve_valid(&collection_to_update.bonding_curve, &collection_to_update.dutch_auction)?;
+
+    check_if_mint_phases_valid(&collection_to_update.mint_phases, &collection_to_update.dutch_auction, 
+    &collection_to_update.bonding_curve)?;
+
+    // any change of the approved content by the creator goes back to review
+    if collection_before.is_approved() && collection_before.is_content_changed(&collection_to_update) &&
+    check_if_contract_admin(deps.as_ref(), &sender).is_err() {
+        collection_to_update.status = Some(COLLECTION_STATUS_PENDING_REVIEW);
+        collection_to_update.review = Some(pending_review(_env.block.time));
+    }
+
     if to_update {
         collection_to_update.date_updated = Some(_env.block.time);
     }
@@ -123,6 +279,13 @@ pub fn update_collection(deps: DepsMut,
 
 
 
+pub (crate) fn pending_review(time : Timestamp) -> CollectionReview {
+
+    CollectionReview { status : REVIEW_STATUS_PENDING, reviewer : None, reason : None, 
+        date_submitted : Some(time), date_reviewed : None }
+}
+
+
 pub (crate) fn internal_create_collection(mut deps: DepsMut, 
     _env : Env, info: MessageInfo,
     name : String, symbol : String, 
@@ -132,29 +295,52 @@ pub (crate) fn internal_create_collection(mut deps: DepsMut,
     prices : Option<Vec<PriceType>>,
     royalties : Option<Vec<Royalty>>,
     _status : Option<u8>, 
+    referral_share : Option<u16>,
+    dutch_auction : Option<DutchAuction>,
+    bonding_curve : Option<BondingCurve>,
+    mint_phases : Option<Vec<MintPhase>>,
+    launch_at : Option<Timestamp>,
+    close_at : Option<Timestamp>,
+    mint_throttle : Option<MintThrottle>,
     ) -> Result<Response, ContractError> {
   
     let owner = info.clone().sender;
 
     check_if_collection_exists(&deps, info.clone(), name.clone(), symbol.clone(), true)?;
 
-    let _msgs = try_paying_contract_treasuries(deps.branch(), _env.clone(), 
-    info, "CREATE_COLLECTION_FEE")?;
+    check_if_name_available(deps.as_ref(), &owner, name.clone(), symbol.clone(), _env.block.time)?;
+
+    let _msgs = pay_contract_fee(deps.branch(), _env.clone(), 
+    info, FeeType::CreateCollection, &owner)?;
  
     are_treasuries_valid(&treasuries)?;
 
     are_royalties_valid(&royalties)?;
 
+    check_if_referral_share_valid(referral_share)?;
+
+    check_if_dutch_auction_valid(&dutch_auction)?;
+
+    check_if_bonding_curve_valid(&bonding_curve, &dutch_auction)?;
+
+    check_if_mint_phases_valid(&mint_phases, &dutch_auction, &bonding_curve)?;
+
+    check_if_launch_window_valid(launch_at, close_at)?;
+
+    check_if_mint_throttle_valid(&mint_throttle)?;
+
     let _key = (owner.clone(), collection_id(name.clone(), symbol.clone()) );
 
     let date_created = _env.block.time;
     
     let status = check_if_collection_status_valid(_status)?;
-    
-    let new_collection = Collection {
+
+    check_if_initial_status_valid(status)?;
+
+    let mut new_collection = Collection {
         name : name.clone(), 
         symbol : symbol.clone(),
-        owner : Some(owner), 
+        owner : Some(owner.clone()), 
         treasuries : treasuries,
         attributes : attributes,
         prices : prices, 
@@ -163,10 +349,31 @@ pub (crate) fn internal_create_collection(mut deps: DepsMut,
         royalties : royalties,
         date_created : Some(date_created),
         date_updated : Some(date_created),
+        referral_share : referral_share,
+        dutch_auction : dutch_auction,
+        bonding_curve : bonding_curve,
+        mint_phases : mint_phases,
+        launch_at : launch_at,
+        close_at : close_at,
+        review : None,
+        mint_throttle : mint_throttle,
     };
 
+    if status == COLLECTION_STATUS_ACTIVATED {
+        new_collection.review = check_if_activation_approved(deps.as_ref(), date_created, 
+        &owner, &new_collection)?;
+    }
+    else if status == COLLECTION_STATUS_PENDING_REVIEW {
+        new_collection.review = Some(pending_review(date_created));
+    }
+
+    check_if_treasuries_not_blocked(deps.as_ref(), &new_collection, &new_collection.treasuries)?;
+
     collections_store().save(deps.storage, _key.clone(), &new_collection)?;
 
+    // the reservation of the name, if any, is claimed
+    remove_name_reservation(deps.storage, &name_key(&name, &symbol))?;
+
     common_response(format!("{}-{}",_key.0, _key.1).as_str(), "create_collection", STATUS_OK, 
     None, Some(_msgs))
 
@@ -214,21 +421,31 @@ pub (crate) fn internal_remove_item (
 
 
 pub fn create_item(mut deps: DepsMut, 
-    _env : Env, info: MessageInfo,item : Item 
+    _env : Env, info: MessageInfo, mut item : Item 
 ) -> Result<Response, ContractError> {
   
-    let mut item = item; 
+    check_if_item_fields_valid(deps.as_ref(), &item, true)?;
 
-    let owner = info.clone().sender;
+    // editors create items in the collection of its owner
+    let owner = item.collection_owner.clone();
+
+    let collection = internal_get_collection(deps.as_ref(), owner.clone(), item.collection_name.clone(), 
+    item.collection_symbol.clone());
+
+    if collection.is_none() {
+        return Err(ContractError::CollectionNotFound { text: "Collection is NOT found!".to_string()});
+    }
 
-    check_if_collection_exists(&deps, info.clone(), item.collection_name.clone(), 
-    item.collection_symbol.clone(), false )?;
+    check_if_not_blocked_by_collection(deps.as_ref(), &collection.unwrap(), &info.sender)?;
 
-    check_if_item_exists(&deps, info.clone(), item.collection_name.clone(), 
+    check_if_collection_role(deps.as_ref(), &(owner.clone(), collection_id(item.collection_name.clone(), 
+    item.collection_symbol.clone())), &info.sender, CollectionRole::Editor)?;
+
+    check_if_item_exists(&deps, owner.clone(), item.collection_name.clone(), 
     item.collection_symbol.clone(), item.name.clone())?;
 
-    let _msgs = try_paying_contract_treasuries(deps.branch(), _env.clone(), 
-    info, "CREATE_ITEM_FEE")?;
+    let _msgs = pay_contract_fee(deps.branch(), _env.clone(), 
+    info, FeeType::CreateItem, &owner)?;
  
     let _key = (item.collection_owner.clone(), 
     collection_id(item.collection_name.clone(), item.collection_symbol.clone()), 
@@ -250,13 +467,80 @@ pub fn create_item(mut deps: DepsMut,
 
 
 
+pub fn update_item(deps: DepsMut, 
+    _env : Env, info: MessageInfo, item : Item 
+) -> Result<Response, ContractError> {
+
+    check_if_item_fields_valid(deps.as_ref(), &item, false)?;
+
+    let _key = (item.collection_owner.clone(), 
+    collection_id(item.collection_name.clone(), item.collection_symbol.clone()), 
+    item.name.clone() );
+
+    check_if_collection_role(deps.as_ref(), &(_key.0.clone(), _key.1.clone()), 
+    &info.sender, CollectionRole::Editor)?;
+
+    let item_to_update = internal_get_item(deps.as_ref(), item.collection_owner.clone(), 
+    item.collection_name.clone(), item.collection_symbol.clone(), item.name.clone());
+
+    if item_to_update.is_none() {
+        return Err(ContractError::CustomErrorMesg { message: format!("Item named {} not found", item.name)});
+    }
+
+    let mut item_to_update = item_to_update.unwrap();
+
+    if item.description.is_some() {
+        item_to_update.description = item.description;
+    }
+
+    if item.links.len() > 0 {
+        item_to_update.links = item.links;
+    }
+
+    if item.traits.len() > 0 {
+        item_to_update.traits = item.traits;
+    }
+
+    if item.background_color.is_some() {
+        item_to_update.background_color = item.background_color;
+    }
+
+    item_to_update.date_updated = Some(_env.block.time);
+
+    COLLECTION_ITEMS_STORE.save(deps.storage, _key.clone(), &item_to_update)?;
+
+    common_response( format!("{}-{}={}",_key.0, _key.1,
+    _key.2).as_str(), "update_item", STATUS_OK, None, None)
+}
+
+
+pub fn remove_item(deps: DepsMut, info: MessageInfo, owner : Addr, 
+    collection_name : String, collection_symbol : String, item_name : String 
+) -> Result<Response, ContractError> {
+
+    let _key = (owner.clone(), collection_id(collection_name.clone(), collection_symbol.clone()));
+
+    check_if_collection_role(deps.as_ref(), &_key, &info.sender, CollectionRole::Editor)?;
+
+    if !internal_remove_item(owner, collection_name, collection_symbol, item_name.clone(), deps) {
+        return Err(ContractError::CustomErrorMesg { message: format!("Item named {} not found", item_name)});
+    }
+
+    common_response( format!("{}-{}={}",_key.0, _key.1,
+    item_name).as_str(), "remove_item", STATUS_OK, None, None)
+}
+
+
+
 pub fn mint_item (mut deps : DepsMut , 
     _env : Env, info: MessageInfo, seed : u64,
     owner : Addr,collection_name : String,  
     collection_symbol : String , 
     price_type : Option<u8>, 
     token_uri : Option<String>,
-    token_id : Option<String> )-> Result<Response, ContractError> {
+    token_id : Option<String>,
+    referrer : Option<Addr>,
+    promo_code : Option<String> )-> Result<Response, ContractError> {
 
     let collection = internal_get_collection(deps.as_ref(), owner.clone(), 
     collection_name.clone(), collection_symbol.clone());
@@ -267,8 +551,31 @@ pub fn mint_item (mut deps : DepsMut ,
 
     let collection = collection.unwrap();
 
-    if collection.status.is_none() || collection.status.unwrap() != COLLECTION_STATUS_ACTIVATED{
-        return Err(ContractError::NftStatusIsNotReadyForMinting { text: "Collection is NOT ready for minting!".to_string()});
+    check_if_collection_not_suspended(deps.as_ref(), &collection)?;
+
+    check_if_not_blocked_by_collection(deps.as_ref(), &collection, &info.sender)?;
+
+    check_if_referrer_valid(&referrer, &info.sender)?;
+
+    if referrer.is_some() {
+        check_if_not_blocked_by_collection(deps.as_ref(), &collection, &referrer.clone().unwrap())?;
+    }
+
+    check_if_treasuries_not_blocked(deps.as_ref(), &collection, &collection.treasuries)?;
+
+    check_if_minting_not_paused(deps.as_ref(), &collection, _env.block.time)?;
+
+    check_if_mint_not_throttled(deps.as_ref(), &_env, &collection, &info.sender)?;
+
+    let minted = internal_get_minted_count(deps.as_ref(), owner.clone(), collection_name.clone(), 
+    collection_symbol.clone());
+
+    let mut price = check_if_collection_mintable(&collection, price_type, _env.block.time, minted)?;
+
+    let phase = collection.active_phase(_env.block.time);
+
+    if phase.is_some() {
+        check_if_eligible_for_phase(deps.as_ref(), &collection, &phase.clone().unwrap(), &info.sender, 1)?;
     }
 
 
@@ -285,15 +592,29 @@ pub fn mint_item (mut deps : DepsMut ,
 
         let i = itm.unwrap();
 
-        let price = collection.price_by_type(price_type.unwrap_or(PRICE_TYPE_STANDARD));
+        if promo_code.is_some() {
+            price = apply_promo_code(deps.branch(), &_env, &collection, promo_code.unwrap(), price)?;
+        }
 
-        check_if_fund_sufficient(info.clone(), price.unwrap())?;
+        check_if_fund_sufficient(info.clone(), price.clone())?;
 
-        let res = init_and_mint_nft(deps.branch(), _env, info, 
-        i.clone(), collection, price_type, token_uri, Some("random-mint".to_string()), token_id);
+        let price = record_auction_purchase(deps.branch(), _env.block.time, &collection, &info.sender, price)?;
+
+        increment_minted_count(deps.branch(), &collection)?;
+
+        record_phase_mint(deps.branch(), &collection, &phase, &info.sender)?;
+
+        record_throttled_mint(deps.branch(), &_env, &collection, &info.sender)?;
+
+        let collection_key = (owner.clone(), collection_id(collection_name.clone(), 
+        collection_symbol.clone()));
+
+        let res = init_and_mint_nft(deps.branch(), _env.clone(), info, 
+        i.clone(), collection, price, token_uri, Some("random-mint".to_string()), token_id, referrer);
 
         if res.is_ok() {
-            internal_remove_item(owner, collection_name, collection_symbol, i.name.clone(), deps);
+            internal_remove_item(owner, collection_name, collection_symbol, i.name.clone(), deps.branch());
+            mark_sold_out_if_no_items(deps, &collection_key, _env.block.time)?;
         }
         
         res 
@@ -310,7 +631,9 @@ pub fn mint_item_by_name (mut deps : DepsMut ,
     collection_symbol : String , 
     price_type : Option<u8>, 
     token_uri : Option<String>,
-    token_id : Option<String>)-> Result<Response, ContractError> {
+    token_id : Option<String>,
+    referrer : Option<Addr>,
+    promo_code : Option<String>)-> Result<Response, ContractError> {
 
     let collection = internal_get_collection(deps.as_ref(), owner.clone(), 
     collection_name.clone(), collection_symbol.clone());
@@ -320,9 +643,32 @@ pub fn mint_item_by_name (mut deps : DepsMut ,
     }
 
     let collection = collection.unwrap();
+
+    check_if_collection_not_suspended(deps.as_ref(), &collection)?;
+
+    check_if_not_blocked_by_collection(deps.as_ref(), &collection, &info.sender)?;
+
+    check_if_referrer_valid(&referrer, &info.sender)?;
+
+    if referrer.is_some() {
+        check_if_not_blocked_by_collection(deps.as_ref(), &collection, &referrer.clone().unwrap())?;
+    }
+
+    check_if_treasuries_not_blocked(deps.as_ref(), &collection, &collection.treasuries)?;
+
+    check_if_minting_not_paused(deps.as_ref(), &collection, _env.block.time)?;
+
+    check_if_mint_not_throttled(deps.as_ref(), &_env, &collection, &info.sender)?;
     
-    if collection.status.is_none() || collection.status.unwrap() != COLLECTION_STATUS_ACTIVATED {
-        return Err(ContractError::NftStatusIsNotReadyForMinting { text: "Collection is NOT ready for minting!".to_string()});
+    let minted = internal_get_minted_count(deps.as_ref(), owner.clone(), collection_name.clone(), 
+    collection_symbol.clone());
+
+    let mut price = check_if_collection_mintable(&collection, price_type, _env.block.time, minted)?;
+
+    let phase = collection.active_phase(_env.block.time);
+
+    if phase.is_some() {
+        check_if_eligible_for_phase(deps.as_ref(), &collection, &phase.clone().unwrap(), &info.sender, 1)?;
     }
 
     if !collection.is_mint_by_name_allowed() {
@@ -335,19 +681,34 @@ pub fn mint_item_by_name (mut deps : DepsMut ,
     let item = internal_get_item(deps.as_ref(), owner.clone(), collection_name.clone(), 
     collection_symbol.clone(), item_name.clone());
 
-    let price = collection.price_by_type(price_type.unwrap_or(PRICE_TYPE_STANDARD));
+    if promo_code.is_some() {
+        price = apply_promo_code(deps.branch(), &_env, &collection, promo_code.unwrap(), price)?;
+    }
 
-    check_if_fund_sufficient(info.clone(), price.unwrap())?;
+    check_if_fund_sufficient(info.clone(), price.clone())?;
 
 
     if item.is_some() {
         let itm = item.unwrap();
+
+        let price = record_auction_purchase(deps.branch(), _env.block.time, &collection, &info.sender, price)?;
+
+        increment_minted_count(deps.branch(), &collection)?;
+
+        record_phase_mint(deps.branch(), &collection, &phase, &info.sender)?;
+
+        record_throttled_mint(deps.branch(), &_env, &collection, &info.sender)?;
+
+        let collection_key = (owner.clone(), collection_id(collection_name.clone(), 
+        collection_symbol.clone()));
+
         let res = init_and_mint_nft(deps.branch(), 
-        _env, info, itm.clone(), collection,price_type,token_uri,
-        Some("mint-by-name".to_string()),token_id);
+        _env.clone(), info, itm.clone(), collection,price,token_uri,
+        Some("mint-by-name".to_string()),token_id, referrer);
 
         if res.is_ok() {
-            internal_remove_item(owner, collection_name, collection_symbol, itm.name.clone(), deps);
+            internal_remove_item(owner, collection_name, collection_symbol, itm.name.clone(), deps.branch());
+            mark_sold_out_if_no_items(deps, &collection_key, _env.block.time)?;
         }
 
         res 
@@ -361,7 +722,123 @@ pub fn mint_item_by_name (mut deps : DepsMut ,
 
 
 
+/*
+An activated collection moves to sold out once 
+its last item has been minted, which also ends its auction
+ */
+pub (crate) fn mark_sold_out_if_no_items(deps : DepsMut, _key : &(Addr, String), time : Timestamp) -> Result<(), ContractError> {
+
+    let items = COLLECTION_ITEMS_STORE
+    .prefix(_key.clone())
+    .keys(deps.storage, None, None, Order::Ascending)
+    .next();
+
+    if items.is_some() {
+        return Ok(());
+    }
+
+    mark_auction_ended(deps.storage, _key, time)?;
+
+    let collection = collections_store().may_load(deps.storage, _key.clone())?;
+
+    if collection.is_some() {
+
+        let mut collection = collection.unwrap();
+
+        // a scheduled draft is activated by its launch window
+        if collection.status == Some(COLLECTION_STATUS_ACTIVATED) || 
+        (collection.status == Some(COLLECTION_STATUS_DRAFT) && collection.launch_at.is_some()) {
+
+            collection.status = Some(COLLECTION_STATUS_SOLD_OUT);
+
+            collections_store().save(deps.storage, _key.clone(), &collection)?;
+        }
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn increment_minted_count(deps : DepsMut, collection : &Collection) -> Result<u64, ContractError> {
+
+    let _key = collection_key(collection)?;
+
+    let minted = MINTED_COUNTS_STORE.may_load(deps.storage, _key.clone())?.unwrap_or(0) + 1;
+
+    MINTED_COUNTS_STORE.save(deps.storage, _key, &minted)?;
+
+    Ok(minted)
+}
+
+
+
+pub (crate) fn record_throttled_mint(deps : DepsMut, _env : &Env, collection : &Collection, 
+    sender : &Addr) -> Result<(), ContractError> {
+
+    if collection.mint_throttle.is_none() {
+        return Ok(());
+    }
+
+    let _key = collection_key(collection)?;
+
+    let height = _env.block.height;
+
+    let block_mints = BLOCK_MINTS_STORE.may_load(deps.storage, _key.clone())?
+    .filter(|b| b.height == height)
+    .unwrap_or(BlockMints { height : height, count : 0 });
+
+    BLOCK_MINTS_STORE.save(deps.storage, _key.clone(), &BlockMints { height : height, 
+        count : block_mints.count + 1 })?;
+
+    LAST_MINT_HEIGHTS_STORE.save(deps.storage, (_key.0, _key.1, sender.clone()), &height)?;
+
+    Ok(())
+}
+
+
+pub (crate) fn record_phase_mint(deps : DepsMut, collection : &Collection, phase : &Option<MintPhase>, 
+    wallet : &Addr) -> Result<(), ContractError> {
+
+    if phase.is_none() || phase.clone().unwrap().max_per_wallet.is_none() {
+        return Ok(());
+    }
+
+    let phase = phase.clone().unwrap();
+
+    let (owner, coll_id) = collection_key(collection)?;
+
+    let _key = (owner, coll_id, wallet.clone());
+
+    let mut mints = PHASE_MINTS_STORE.may_load(deps.storage, _key.clone())?.unwrap_or(vec![]);
+
+    if let Some(m) = mints.iter_mut().find(|m| m.phase == phase.name) {
+        m.count += 1;
+    }
+    else {
+        mints.push(PhaseMint { phase : phase.name, count : 1 });
+    }
+
+    PHASE_MINTS_STORE.save(deps.storage, _key, &mints)?;
+
+    Ok(())
+}
+
+
+
 pub fn remove_collection (
+    name : String,
+    symbol : String,
+    deps: DepsMut ,  
+    info: MessageInfo) -> Result<Response, ContractError> {
+
+    check_if_action_needs_approval(deps.as_ref(), 
+    &(info.sender.clone(), collection_id(name.clone(), symbol.clone())), "Removal")?;
+
+    internal_remove_collection(name, symbol, deps, info)
+}
+
+
+pub (crate) fn internal_remove_collection (
     name : String,
     symbol : String,
     mut deps: DepsMut ,  
@@ -371,6 +848,10 @@ pub fn remove_collection (
 
     collectionn_allowed_for_removal(owner.clone(), name.clone(), symbol.clone(), &deps)?;
 
+    let collection = internal_get_collection(deps.as_ref(), owner.clone(), name.clone(), symbol.clone());
+
+    check_if_auction_settled(deps.as_ref(), &collection.unwrap())?;
+
     let _key = (owner.clone(), collection_id(name.clone(), symbol.clone()) );
 
     let removed_res = collections_store().remove(deps.branch().storage, _key.clone());
@@ -378,6 +859,7 @@ pub fn remove_collection (
     match removed_res {
 
         Ok(_)=> {
+            remove_collection_records(deps.branch().storage, &_key)?;
             remove_all_items(owner, name, symbol, deps);
             common_response(format!("{}-{}",_key.0, _key.1).as_str(), "remove_collection", STATUS_OK, None, None)
         },
@@ -427,9 +909,9 @@ pub (crate) fn remove_all_items(
 
 
 #[allow(dead_code)]
-const STATUS_ERROR : i8 = -1;
+pub (crate) const STATUS_ERROR : i8 = -1;
 
-const STATUS_OK : i8 = 1;
+pub (crate) const STATUS_OK : i8 = 1;
 
 
 pub (crate) fn common_response (key : &str , method : &str, status : i8,
@@ -7,7 +7,23 @@ pub mod ins;
 pub mod query;
 pub mod nft_query;
 pub mod nft_ins;
+pub mod fee_ins;
+pub mod promo_ins;
+pub mod auction_ins;
+pub mod review_ins;
+pub mod moderation_ins;
+pub mod curation_ins;
+pub mod ownership_ins;
+pub mod member_ins;
+pub mod delegate_ins;
+pub mod signers_ins;
+pub mod pause_ins;
+pub mod blocklist_ins;
+pub mod phase_ins;
+pub mod validation;
+pub mod names_ins;
 pub mod utils;
+pub mod migrations;
 mod checks;
 mod tests;
 
@@ -0,0 +1,67 @@
+use cosmwasm_std::{DepsMut, Response, MessageInfo, Addr};
+use crate::state::CollectionRole;
+use crate::indexes::COLLECTION_MEMBERS_STORE;
+use crate::error::ContractError;
+use crate::ins::{collection_id, common_response, STATUS_OK};
+use crate::query::internal_get_collection;
+use crate::checks::check_if_not_blocked_by_collection;
+
+
+pub fn grant_collection_role(deps : DepsMut, info : MessageInfo, collection_name : String, 
+    collection_symbol : String, member : Addr, role : CollectionRole) -> Result<Response, ContractError> {
+
+    let owner = info.sender.clone();
+
+    let collection = internal_get_collection(deps.as_ref(), owner.clone(), collection_name.clone(), 
+    collection_symbol.clone());
+
+    if collection.is_none() {
+        return Err(ContractError::CollectionNotFound { text: "Collection is NOT found!".to_string()});
+    }
+
+    // the owner holds every role already
+    if member == owner {
+        return Err(ContractError::CustomErrorMesg { message : "The owner can NOT be a member!".to_string()});
+    }
+
+    check_if_not_blocked_by_collection(deps.as_ref(), &collection.unwrap(), &member)?;
+
+    let _key = (owner, collection_id(collection_name, collection_symbol), member);
+
+    let mut roles = COLLECTION_MEMBERS_STORE.may_load(deps.storage, _key.clone())?.unwrap_or(vec![]);
+
+    if !roles.contains(&role) {
+        roles.push(role.clone());
+    }
+
+    COLLECTION_MEMBERS_STORE.save(deps.storage, _key.clone(), &roles)?;
+
+    common_response(format!("{}-{}={}", _key.0, _key.1, _key.2).as_str(), "grant_collection_role", 
+    STATUS_OK, Some(role.name().to_string()), None)
+}
+
+
+pub fn revoke_collection_role(deps : DepsMut, info : MessageInfo, collection_name : String, 
+    collection_symbol : String, member : Addr, role : CollectionRole) -> Result<Response, ContractError> {
+
+    let _key = (info.sender, collection_id(collection_name, collection_symbol), member);
+
+    let roles = COLLECTION_MEMBERS_STORE.may_load(deps.storage, _key.clone())?.unwrap_or(vec![]);
+
+    if !roles.contains(&role) {
+        return Err(ContractError::MissingCollectionRole { text: 
+            format!("{} does NOT have the {} role in the collection!", _key.2, role.name())});
+    }
+
+    let roles : Vec<CollectionRole> = roles.into_iter().filter(|r| r != &role).collect();
+
+    if roles.len() == 0 {
+        COLLECTION_MEMBERS_STORE.remove(deps.storage, _key.clone());
+    }
+    else {
+        COLLECTION_MEMBERS_STORE.save(deps.storage, _key.clone(), &roles)?;
+    }
+
+    common_response(format!("{}-{}={}", _key.0, _key.1, _key.2).as_str(), "revoke_collection_role", 
+    STATUS_OK, Some(role.name().to_string()), None)
+}
@@ -0,0 +1,241 @@
+use cosmwasm_std::{DepsMut, Order, Addr, StdResult, Empty, Response, MessageInfo, Storage};
+use cw_storage_plus::{Map, Bound, Index, PrimaryKey};
+use crate::state::{Collection, TREASURY_PERCENTAGE_TOTAL, COLLECTION_STATUS_DEACTIVATED, COLLECTION_STATUS_PAUSED};
+use crate::indexes::{collections_store, COLLECTION_IDS_CURSOR_STORE};
+use crate::ins::{collection_id, name_key, common_response, STATUS_OK};
+use crate::error::ContractError;
+use crate::checks::check_if_contract_admin;
+use crate::ownership_ins::{move_items, move_collection_records};
+
+// the old treasury percentage was a whole percentage (u8) totalling 100
+const LEGACY_TREASURY_PERCENTAGE_TOTAL : u32 = 100;
+
+// the first contract version storing the treasury percentages in 2-decimal
+pub const TREASURY_PERCENTAGE_VERSION : &str = "0.9.0";
+
+// the first contract version with the paused status in place of deactivated
+pub const COLLECTION_STATUS_VERSION : &str = "0.9.0";
+
+// the collections checked by a page of the re-keying of the legacy collection ids
+const REKEY_DEFAULT_LIMIT : u32 = 100;
+
+const REKEY_MAX_LIMIT : u32 = 500;
+
+
+// the collections as stored, without updating their indexes, for the migrations 
+// of the fields the name and symbol indexes do not use, as a re-indexing save fails 
+// on the legacy collections differing only in case
+const COLLECTIONS_DATA : Map<(Addr,String), Collection> = Map::new("COLLECTIONS_STORE");
+
+
+/*
+Compares two dotted versions such as 0.8.8 by their numeric parts,
+a part that is not a number counts as zero
+ */
+pub fn is_version_before(version : &str, target : &str) -> bool {
+
+    let parts = |v : &str| -> Vec<u64> { v.split('.')
+        .map(|p| p.parse::<u64>().unwrap_or(0)).collect() };
+
+    parts(version) < parts(target)
+}
+
+
+/*
+Convert the treasuries stored with whole percentages into
+2-decimal percentages, it is only run when migrating from a 
+version before TREASURY_PERCENTAGE_VERSION, as a 2-decimal 
+treasury could also total 100
+ */
+pub fn migrate_treasury_percentages(deps : DepsMut) -> StdResult<u32> {
+
+    let to_migrate : Vec<((Addr,String), Collection)> = COLLECTIONS_DATA
+    .range(deps.storage, None, None, Order::Ascending)
+    .filter_map(|c| c.ok())
+    .filter(|(_, c)| {
+        
+        let total : u32 = c.treasuries.clone().unwrap_or(vec![])
+        .iter().map(|t| t.percentage as u32).sum();
+
+        c.treasuries.is_some() && total == LEGACY_TREASURY_PERCENTAGE_TOTAL
+    })
+    .collect();
+
+    let multiplier = TREASURY_PERCENTAGE_TOTAL / LEGACY_TREASURY_PERCENTAGE_TOTAL as u16;
+
+    let mut count : u32 = 0;
+
+    for (key, mut collection) in to_migrate {
+
+        collection.treasuries = collection.treasuries.map(|ts| ts.into_iter()
+        .map(|mut t| { t.percentage = t.percentage * multiplier; t })
+        .collect());
+
+        COLLECTIONS_DATA.save(deps.storage, key, &collection)?;
+
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+
+// the unique indexes of the collections, only used to remove their legacy keys
+const LEGACY_COLLECTIONS_INDEX : Map<(Addr,String), Empty> = Map::new("COLLECTIONS");
+
+const LEGACY_NAME_SYMBOLS_INDEX : Map<String, Empty> = Map::new("COLLECTION_NAME_SYMBS");
+
+// the old collection id, which was ambiguous when the name or symbol has a dash
+fn legacy_collection_id(name : &str, symbol : &str) -> String {
+    format!("{}-{}", name, symbol)
+}
+
+// whether the name key is held by the collection itself or by another one
+// differing only in case, which is left as it is rather than breaking the unique index
+fn name_key_holder(storage : &dyn Storage, collection : &Collection) -> StdResult<Option<bool>> {
+
+    let holder = collections_store().idx.name_symbols
+    .item(storage, name_key(&collection.name, &collection.symbol))?;
+
+    Ok(holder.map(|(_, c)| c.owner == collection.owner && 
+        c.name == collection.name && c.symbol == collection.symbol))
+}
+
+/*
+Re-key the collections stored under the legacy collection id, together
+with their items and other records, the collections with no dash or backslash
+in the name and symbol have the same id, and those already re-keyed no longer
+match the legacy id, so it is safe to run more than once. The collections 
+with upper case in the name or symbol are also re-indexed under the 
+case-insensitive name key, unless another collection already holds it.
+
+It checks up to limit collections from where the last page stopped
+and returns the number re-keyed and whether all have been checked
+ */
+pub fn migrate_collection_ids(deps : DepsMut, limit : u32) -> StdResult<(u32, bool)> {
+
+    let start = COLLECTION_IDS_CURSOR_STORE.may_load(deps.storage)?.map(Bound::exclusive);
+
+    let checked : Vec<((Addr,String), Collection)> = collections_store()
+    .range(deps.storage, start, None, Order::Ascending)
+    .take(limit as usize)
+    .collect::<StdResult<_>>()?;
+
+    let done = checked.len() < limit as usize;
+
+    let last = checked.last().map(|(k, _)| k.clone());
+
+    let mut count : u32 = 0;
+
+    for (from, collection) in checked {
+
+        let _id = collection_id(collection.name.clone(), collection.symbol.clone());
+
+        let legacy_id = legacy_collection_id(&collection.name, &collection.symbol);
+
+        let is_legacy = from.1 == legacy_id && legacy_id != _id;
+
+        let is_case_indexed = from.1 == _id && _id != name_key(&collection.name, &collection.symbol);
+
+        if !(is_legacy || is_case_indexed) {
+            continue;
+        }
+
+        // a collection already re-indexed holds its own name key
+        match name_key_holder(deps.storage, &collection)? {
+            Some(false) => continue,
+            Some(true) if is_case_indexed => continue,
+            _ => (),
+        }
+
+        if is_case_indexed {
+
+            // the stale index key is removed here, the save adds the name key
+            LEGACY_NAME_SYMBOLS_INDEX.remove(deps.storage, _id);
+
+            collections_store().save(deps.storage, from, &collection)?;
+
+            continue;
+        }
+
+        let to = (from.0.clone(), _id);
+
+        // the indexes are computed with the new id, so their legacy keys are removed here
+        collections_store().remove(deps.storage, from.clone())?;
+
+        LEGACY_COLLECTIONS_INDEX.remove(deps.storage, from.clone());
+        LEGACY_NAME_SYMBOLS_INDEX.remove(deps.storage, from.1.clone());
+
+        collections_store().save(deps.storage, to.clone(), &collection)?;
+
+        move_items(deps.storage, &from, &to)?;
+
+        move_collection_records(deps.storage, &from, &to, true)?;
+
+        count += 1;
+    }
+
+    // a re-keyed collection is checked again after the cursor, but no longer matches
+    if done || last.is_none() {
+        COLLECTION_IDS_CURSOR_STORE.remove(deps.storage);
+    }
+    else {
+        COLLECTION_IDS_CURSOR_STORE.save(deps.storage, &last.unwrap())?;
+    }
+
+    Ok((count, done))
+}
+
+
+/*
+Runs a page of the re-keying of the legacy collection ids,
+the admins repeat it until the response says it is done
+ */
+pub fn rekey_collections(deps : DepsMut, info : MessageInfo, limit : Option<u32>) 
+-> Result<Response, ContractError> {
+
+    check_if_contract_admin(deps.as_ref(), &info.sender)?;
+
+    let limit = limit.unwrap_or(REKEY_DEFAULT_LIMIT).min(REKEY_MAX_LIMIT).max(1);
+
+    let (count, done) = migrate_collection_ids(deps, limit)?;
+
+    common_response("collection_ids", "rekey_collections", STATUS_OK, 
+    Some(format!("{} re-keyed, done : {}", count, done)), None)
+}
+
+
+/*
+The deactivated collections stored before COLLECTION_STATUS_VERSION
+are paused, which can be activated again just like deactivated, 
+only the status index is updated along with them
+ */
+pub fn migrate_collection_statuses(deps : DepsMut) -> StdResult<u32> {
+
+    let to_migrate : Vec<((Addr,String), Collection)> = COLLECTIONS_DATA
+    .range(deps.storage, None, None, Order::Ascending)
+    .filter_map(|c| c.ok())
+    .filter(|(_, c)| c.status == Some(COLLECTION_STATUS_DEACTIVATED))
+    .collect();
+
+    let statuses = collections_store().idx.statuses;
+
+    let mut count : u32 = 0;
+
+    for (key, mut collection) in to_migrate {
+
+        let pk = key.joined_key();
+
+        statuses.remove(deps.storage, &pk, &collection)?;
+
+        collection.status = Some(COLLECTION_STATUS_PAUSED);
+
+        statuses.save(deps.storage, &pk, &collection)?;
+
+        COLLECTIONS_DATA.save(deps.storage, key, &collection)?;
+
+        count += 1;
+    }
+
+    Ok(count)
+}
@@ -0,0 +1,47 @@
+use cosmwasm_std::{DepsMut, Env, Response, MessageInfo, Addr};
+use crate::state::{Moderation, ModerationAction, ModerationRecord, MODERATION_RECORDS_MAX};
+use crate::indexes::MODERATIONS_STORE;
+use crate::error::ContractError;
+use crate::ins::{collection_id, common_response, STATUS_OK};
+use crate::query::internal_get_collection;
+use crate::checks::*;
+
+
+/*
+Lets the contract admins suspend or flag a collection regardless of 
+its status, the collection owner can NOT lift a moderation
+ */
+pub fn moderate_collection(deps : DepsMut, _env : Env, info : MessageInfo, owner : Addr, 
+    name : String, symbol : String, action : ModerationAction, reason : Option<String>) 
+-> Result<Response, ContractError> {
+
+    check_if_contract_admin(deps.as_ref(), &info.sender)?;
+
+    if internal_get_collection(deps.as_ref(), owner.clone(), name.clone(), symbol.clone()).is_none() {
+        return Err(ContractError::CollectionNotFound { text: "Collection is NOT found!".to_string()});
+    }
+
+    let _key = (owner, collection_id(name, symbol));
+
+    let mut moderation = MODERATIONS_STORE.may_load(deps.storage, _key.clone())?
+    .unwrap_or(Moderation { suspended : false, flagged : false, records : vec![] });
+
+    match action {
+        ModerationAction::Suspend => moderation.suspended = true,
+        ModerationAction::Unsuspend => moderation.suspended = false,
+        ModerationAction::Flag => moderation.flagged = true,
+        ModerationAction::Unflag => moderation.flagged = false,
+    }
+
+    moderation.records.push(ModerationRecord { action : action, reason : reason, 
+        moderator : info.sender, date_moderated : _env.block.time });
+
+    if moderation.records.len() > MODERATION_RECORDS_MAX {
+        let excess = moderation.records.len() - MODERATION_RECORDS_MAX;
+        moderation.records.drain(0..excess);
+    }
+
+    MODERATIONS_STORE.save(deps.storage, _key.clone(), &moderation)?;
+
+    common_response(format!("{}-{}",_key.0, _key.1).as_str(), "moderate_collection", STATUS_OK, None, None)
+}
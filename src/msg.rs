@@ -1,9 +1,15 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use crate::state::{Collection,Item};
-use cosmwasm_std::{Addr, Coin};
+use crate::state::{Collection,Item,FeeType,FeeTier,FeeOverride,ReferralStats,PromoCode,
+DutchAuction,AuctionState,AuctionPurchase,BondingCurve,MintPhase,
+Treasury,Attribute,PriceType,Percentage,CollectionReview,MintThrottle,Link,
+Moderation,ModerationAction,
+VerifiedCreator,FeaturedCollection,PendingOwner,
+CollectionRole,CollectionMember,DelegatedMinter,
+CollectionSigners, ChangeProposal, PauseGroup, PauseFlag, MintPause, BlockedAddress, ValidationLimits, NameReservation};
+use cosmwasm_std::{Addr, Coin, Timestamp};
 use pix0_contract_common::state::{Fee, Contract};
-use pix0_market_handlers::state::SellOffer;
+use pix0_market_handlers::state::{SellOffer, Trait, Royalty};
 use cw721::Cw721ReceiveMsg;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -20,7 +26,7 @@ pub enum ExecuteMsg {
 
         fees : Option<Vec<Fee>>, 
 
-        treasuries : Option<Vec<Addr>>,
+        treasuries : Option<Vec<String>>,
 
         contracts : Option<Vec<Contract>>, 
 
@@ -29,13 +35,17 @@ pub enum ExecuteMsg {
 
     CreateCollection {
        
-       collection : Collection, 
+       collection : CollectionMsg, 
 
     },
 
     UpdateCollection {
+
+       // the owner of the collection updated by a member, 
+       // the sender when not set, the owner in the collection is ignored
+       owner : Option<String>,
        
-       collection : Collection, 
+       collection : CollectionMsg, 
 
     },
 
@@ -48,267 +58,1340 @@ pub enum ExecuteMsg {
 
     CreateItem {
 
-       item : Item, 
+       item : ItemMsg, 
     },
 
 
-    MintItem {
+    SetPaused {
 
-        seed : String,
+        group : PauseGroup,
+
+        paused : bool,
+
+        reason : Option<String>,
+    },
+
+    SetValidationLimits {
+
+        limits : ValidationLimits,
+    },
+
+    // re-keys a page of the collections stored under the legacy collection id
+    RekeyCollections {
+
+        limit : Option<u32>,
+    },
+
+    PauseMinting {
 
-        owner : Addr, 
+        owner : String, 
 
         collection_name : String, 
 
         collection_symbol : String, 
 
-        price_type : Option<u8>, 
-        
-        token_uri : Option<String>, 
-
-        token_id : Option<String>,
+        reason : Option<String>,
 
+        resume_at : Option<Timestamp>,
     },
 
-    MintItemByName {
-        
-        name : String,
+    ResumeMinting {
 
-        owner : Addr, 
+        owner : String, 
 
         collection_name : String, 
 
         collection_symbol : String, 
+    },
 
-        price_type : Option<u8>, 
+    BlockAddresses {
 
-        token_uri : Option<String>, 
+        addresses : Vec<String>,
 
-        token_id : Option<String>,
+        reason : Option<String>,
+    },
+
+    UnblockAddresses {
 
+        addresses : Vec<String>,
     },
 
-    SimpleMint {
+    BlockCollectionAddresses {
 
-        item : Item , 
+        owner : String, 
 
-        token_uri : Option<String>, 
+        collection_name : String, 
 
-        token_id : Option<String>,
+        collection_symbol : String, 
+
+        addresses : Vec<String>,
 
+        reason : Option<String>,
     },
-   
-    TransferNft {
 
-        recipient : String ,
+    UnblockCollectionAddresses {
 
-        token_id : String, 
-    }, 
+        owner : String, 
 
-    BurnNft { token_id: String },
+        collection_name : String, 
 
-    SendNft {
-        token_id : String, 
-   
-        contract_addr : String,
+        collection_symbol : String, 
 
-        action : String , 
+        addresses : Vec<String>,
     },
 
-    ReceiveNft(Cw721ReceiveMsg),
+    AddToPhaseAllowlist {
 
-    CreateSellOffer {
-        
-        offer : SellOffer,
+        owner : String, 
 
-        create_sell_offer_fee : Option<Coin>,
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        phase : String,
+
+        addresses : Vec<String>,
     },
 
-}
+    RemoveFromPhaseAllowlist {
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-#[serde(rename_all = "snake_case")]
-pub enum QueryMsg {
-    
-    GetCollection { 
-        owner : Addr,
+        owner : String, 
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        phase : String,
+
+        addresses : Vec<String>,
+    },
+
+    ReserveName {
 
         name : String,
 
-        symbol : String, 
+        symbol : String,
     },
 
-    GetCollections { 
+    ReleaseName {
 
-        owner : Addr, 
-        
-        start_after : Option<String>,
-        
-        limit : Option<u32>,
+        name : String,
+
+        symbol : String,
     },
 
-    GetAllCollections { 
-        
+    // open to anyone, removes the reservations past their cooldown
+    PruneNameReservations {
+
         start_after : Option<String>,
-        
-        limit : Option<u32>,
-    },
 
-    GetActiveCollections { 
-        
-        keyword : Option<String>,
-        
-        category : Option<String>,
-        
-        start : Option<u32>,
-        
         limit : Option<u32>,
     },
 
-    GetItem { 
+    SetCollectionSigners {
 
-        owner : Addr, 
-        
         collection_name : String, 
 
         collection_symbol : String, 
 
-        item_name : String, 
-      
+        signers : CollectionSignersMsg,
     },
-    
-    GetItems { 
 
-        owner : Addr, 
-        
+    ProposeCollectionChange {
+
+        owner : String, 
+
         collection_name : String, 
 
         collection_symbol : String, 
-      
-        start_after : Option<String>,
-        
-        limit : Option<u32>,
-  
+
+        changes : Option<CollectionMsg>,
+
+        action : Option<ProposedAction>,
+
+        signers : Option<CollectionSignersMsg>,
     },
 
-    GetItemsCount { 
+    ApproveCollectionChange {
+
+        owner : String, 
 
-        owner : Addr, 
-        
         collection_name : String, 
 
         collection_symbol : String, 
+
+        proposal_id : u64,
     },
 
+    CancelCollectionChange {
 
-    NumTokens {},
-    
-    OwnerOf {
-        token_id: String,
-        include_expired: Option<bool>,
+        owner : String, 
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        proposal_id : u64,
     },
 
-    NftInfo {
-        token_id: String,
+    AuthorizeMinter {
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        minter : String, 
+
+        price_type : Option<u8>, 
+
+        max_mints : Option<u32>, 
     },
 
-    // for backward compatiblity
-    NftTokenInfo {
-        token_id: String,
+    RevokeMinter {
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        minter : String, 
     },
 
-    AllNftInfo {
-        token_id: String,
-        include_expired: Option<bool>,
+    MintFor {
+
+        seed : String,
+
+        owner : String, 
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        recipient : String, 
+
+        token_uri : Option<String>, 
+
+        token_id : Option<String>,
     },
 
-    // for backward compatiblity
-    MintedTokensByOwner {
-        owner: String,
-        start_after: Option<String>,
-        limit: Option<u32>,
+    UpdateItem {
+
+       item : ItemMsg, 
     },
-    
-    
-    Tokens {
-        owner: String,
-        start_after: Option<String>,
-        limit: Option<u32>,
+
+    RemoveItem {
+
+        owner : String, 
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        item_name : String, 
     },
-    
-    AllTokens {
-        start_after: Option<String>,
-        limit: Option<u32>,
+
+    GrantCollectionRole {
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        member : String, 
+
+        role : CollectionRole, 
     },
 
-    GetContractInfo{},
-     
-    GetLogInfo{},
+    RevokeCollectionRole {
 
-   // OutstandingRewards {},
-    
-}
+        collection_name : String, 
 
+        collection_symbol : String, 
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct CollectionResponse {
-    
-    pub collection : Option<Collection>,
-}
+        member : String, 
 
+        role : CollectionRole, 
+    },
 
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct CollectionsResponse {
+    MintItem {
 
-    pub collections : Vec<Collection>,
-}
+        seed : String,
 
+        owner : String, 
 
+        collection_name : String, 
 
+        collection_symbol : String, 
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct CollectionsWithParamsResponse {
+        price_type : Option<u8>, 
+        
+        token_uri : Option<String>, 
 
-    pub collections : Vec<Collection>,
+        token_id : Option<String>,
 
-    pub total : Option<u32>,
+        referrer : Option<String>,
 
-    pub start : Option<u32>,
+        promo_code : Option<String>,
 
-    pub limit : Option<u32>,
-}
+    },
 
-impl CollectionsWithParamsResponse {
+    MintItemByName {
+        
+        name : String,
 
-    pub fn empty_response() -> Self {
+        owner : String, 
 
-        CollectionsWithParamsResponse {
-            collections: vec![],
-            total : None,
-            start : None,
-            limit : None, 
-        }
-    }
-}
+        collection_name : String, 
 
+        collection_symbol : String, 
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct ItemResponse {
-    
-    pub item : Option<Item>,
-}
+        price_type : Option<u8>, 
 
+        token_uri : Option<String>, 
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct ItemsResponse {
-    
-    pub items : Vec<Item>,
-}
+        token_id : Option<String>,
 
+        referrer : Option<String>,
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct ItemCountResponse {
-    pub count : usize,
-}
+        promo_code : Option<String>,
+
+    },
 
+    SimpleMint {
+
+        item : ItemMsg , 
+
+        token_uri : Option<String>, 
+
+        token_id : Option<String>,
+
+    },
+   
+    TransferNft {
+
+        recipient : String ,
+
+        token_id : String, 
+    }, 
+
+    BurnNft { token_id: String },
+
+    SendNft {
+        token_id : String, 
+   
+        contract_addr : String,
+
+        action : String , 
+    },
+
+    ReceiveNft(Cw721ReceiveMsg),
+
+    CreateSellOffer {
+        
+        offer : SellOffer,
+
+        create_sell_offer_fee : Option<Coin>,
+    },
+
+    AddPromoCodes {
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        codes : Vec<PromoCode>,
+    },
+
+    RemovePromoCode {
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        code_hash : String,
+    },
+
+    SubmitForReview {
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+    },
+
+    ApproveCollection {
+
+        owner : String, 
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        reason : Option<String>, 
+    },
+
+    RejectCollection {
+
+        owner : String, 
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        reason : String, 
+    },
+
+    ProposeCollectionOwner {
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        new_owner : String, 
+
+        update_treasury : Option<bool>, 
+    },
+
+    CancelCollectionOwnerProposal {
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+    },
+
+    AcceptCollectionOwner {
+
+        owner : String, 
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+    },
+
+    VerifyCreator {
+
+        creator : String, 
+
+        note : Option<String>, 
+    },
+
+    UnverifyCreator {
+
+        creator : String, 
+    },
+
+    FeatureCollection {
+
+        owner : String, 
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        position : u32, 
+
+        expiry : Option<Timestamp>, 
+    },
+
+    UnfeatureCollection {
+
+        owner : String, 
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+    },
+
+    ModerateCollection {
+
+        owner : String, 
+
+        name : String, 
+
+        symbol : String, 
+
+        action : ModerationAction, 
+
+        reason : Option<String>, 
+    },
+
+    ClaimAuctionRefund {
+
+        owner : String, 
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+    },
+
+    ClaimAuctionProceeds {
+
+        collection_name : String, 
+
+        collection_symbol : String, 
+    },
+
+    SetFeeTier {
+
+        tier : FeeTier,
+    },
+
+    RemoveFeeTier {
+
+        name : String,
+    },
+
+    SetCreatorFees {
+
+        creator : String,
+
+        tier : Option<String>,
+
+        overrides : Option<Vec<FeeOverride>>,
+    },
+
+    RemoveCreatorFees {
+
+        creator : String,
+    },
+
+}
+
+// the action of the owner proposed to the signers, validated into a CollectionAction
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProposedAction {
+
+    TransferOwner { new_owner : String, update_treasury : Option<bool> },
+
+    Remove {},
+
+    AuthorizeMinter { minter : String, price_type : Option<u8>, max_mints : Option<u32> },
+
+    AddPromoCodes { codes : Vec<PromoCode> },
+}
+
+/*
+A collection received in a message, the same as the stored 
+Collection, with its addresses validated on entry
+ */
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct CollectionMsg {
+   
+    pub owner : Option<String>,
+
+    pub name : String,
+
+    pub symbol : String, 
+
+    pub description : Option<String>,
+
+    pub treasuries: Option<Vec<TreasuryMsg>>,
+
+    pub attributes : Option<Vec<Attribute>>,
+
+    pub prices : Option<Vec<PriceType>>,
+
+    pub royalties : Option<Vec<Royalty>>,
+
+    pub status : Option<u8>, 
+
+    pub date_created : Option<Timestamp>,
+
+    pub date_updated : Option<Timestamp>,
+
+    pub referral_share : Option<Percentage>,
+
+    pub dutch_auction : Option<DutchAuction>,
+
+    pub bonding_curve : Option<BondingCurve>,
+
+    pub mint_phases : Option<Vec<MintPhase>>,
+
+    pub launch_at : Option<Timestamp>,
+
+    pub close_at : Option<Timestamp>,
+
+    pub review : Option<CollectionReview>,
+
+    pub mint_throttle : Option<MintThrottle>,
+}
+
+// a collection as queried is sent back as is to be updated
+impl From<Collection> for CollectionMsg {
+
+    fn from(c : Collection) -> Self {
+
+        CollectionMsg { owner : c.owner.map(|o| o.to_string()), name : c.name, symbol : c.symbol, 
+            description : c.description, 
+            treasuries : c.treasuries.map(|t| t.into_iter().map(TreasuryMsg::from).collect()),
+            attributes : c.attributes, prices : c.prices, royalties : c.royalties, status : c.status, 
+            date_created : c.date_created, date_updated : c.date_updated, 
+            referral_share : c.referral_share, dutch_auction : c.dutch_auction, 
+            bonding_curve : c.bonding_curve, mint_phases : c.mint_phases, 
+            launch_at : c.launch_at, close_at : c.close_at, review : c.review, 
+            mint_throttle : c.mint_throttle }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TreasuryMsg {
+
+    pub wallet : String, 
+
+    pub percentage : Percentage, 
+
+    pub name : Option<String>,
+
+    pub primary : Option<bool>,
+}
+
+impl From<Treasury> for TreasuryMsg {
+
+    fn from(t : Treasury) -> Self {
+
+        TreasuryMsg { wallet : t.wallet.to_string(), percentage : t.percentage, 
+            name : t.name, primary : t.primary }
+    }
+}
+
+
+// an item received in a message, with its collection owner validated on entry
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ItemMsg {
+   
+    pub collection_owner : String,
+
+    pub collection_name : String, 
+
+    pub collection_symbol : String, 
+    
+    pub name : String,
+
+    pub description : Option<String>,
+
+    pub links : Vec<Link>,
+
+    pub traits : Vec<Trait>,
+
+    pub background_color : Option<String>,
+
+    pub date_created : Option<Timestamp>,
+
+    pub date_updated : Option<Timestamp>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CollectionSignersMsg {
+
+    pub signers : Vec<String>,
+
+    // the number of signers to approve a change
+    pub threshold : u32,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    
+    GetCollection { 
+        owner : String,
+
+        name : String,
+
+        symbol : String, 
+    },
+
+    GetCollections { 
+
+        owner : String, 
+        
+        start_after : Option<String>,
+        
+        limit : Option<u32>,
+    },
+
+    GetAllCollections { 
+        
+        start_after : Option<String>,
+        
+        limit : Option<u32>,
+    },
+
+    GetActiveCollections { 
+        
+        keyword : Option<String>,
+        
+        category : Option<String>,
+        
+        start : Option<u32>,
+        
+        limit : Option<u32>,
+
+        verified_only : Option<bool>,
+    },
+
+    GetItem { 
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        item_name : String, 
+      
+    },
+    
+    GetItems { 
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+      
+        start_after : Option<String>,
+        
+        limit : Option<u32>,
+  
+    },
+
+    GetItemsCount { 
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+    },
+
+
+    NumTokens {},
+    
+    OwnerOf {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+
+    NftInfo {
+        token_id: String,
+    },
+
+    // for backward compatiblity
+    NftTokenInfo {
+        token_id: String,
+    },
+
+    AllNftInfo {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+
+    // for backward compatiblity
+    MintedTokensByOwner {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    
+    
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    
+    AllTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    SimulateMint {
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        price_type : Option<u8>, 
+
+        sender : String, 
+
+        quantity : Option<u32>,
+    },
+
+    GetFeeSchedule {},
+
+    FeeQuote {
+
+        operation : FeeType,
+
+        quantity : Option<u32>,
+    },
+
+    GetFeeTiers {},
+
+    GetEffectiveFees {
+
+        address : String,
+    },
+
+    GetReferralStats {
+
+        referrer : String,
+    },
+
+    GetReferralLeaderboard {
+
+        start_after : Option<String>,
+        
+        limit : Option<u32>,
+    },
+
+    GetPromoCode {
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        code_hash : String,
+    },
+
+    GetCurrentPrice {
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        price_type : Option<u8>, 
+    },
+
+    GetAuctionStatus {
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        buyer : Option<String>,
+    },
+
+    GetBondingCurveQuote {
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        quantity : Option<u32>, 
+    },
+
+    GetMintPhases {
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+    },
+
+    GetActivePhase {
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        wallet : Option<String>, 
+    },
+
+    GetReviewQueue {
+
+        start : Option<u32>,
+
+        limit : Option<u32>,
+    },
+
+    GetModeration {
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+    },
+
+    GetVerifiedCreator {
+
+        creator : String,
+    },
+
+    GetFeaturedCollections {
+
+        start : Option<u32>,
+
+        limit : Option<u32>,
+    },
+
+    GetPendingOwner {
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+    },
+
+    GetCollectionMembers {
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+    },
+
+    GetDelegatedMinters {
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+    },
+
+    GetCollectionSigners {
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+    },
+
+    GetPauseStatus {},
+
+    GetBlockedAddresses {
+
+        start_after : Option<String>,
+
+        limit : Option<u32>,
+    },
+
+    GetCollectionBlockedAddresses {
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        start_after : Option<String>,
+
+        limit : Option<u32>,
+    },
+
+    GetPhaseAllowlist {
+
+        owner : String, 
+        
+        collection_name : String, 
+
+        collection_symbol : String, 
+
+        phase : String,
+
+        start_after : Option<String>,
+
+        limit : Option<u32>,
+    },
+
+    GetValidationLimits {},
+
+    IsNameAvailable {
+
+        name : String,
+
+        symbol : String,
+
+        // a name reserved by the sender is available to the sender
+        sender : Option<String>,
+    },
+
+    GetContractInfo{},
+     
+    GetLogInfo{},
+
+   // OutstandingRewards {},
+    
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CollectionResponse {
+    
+    pub collection : Option<Collection>,
+
+    pub verified : bool,
+
+    // minting is paused until resume_at, when set
+    pub mint_pause : Option<MintPause>,
+}
+
+
+// the minting pause in effect for a listed collection
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CollectionMintPause {
+
+    pub owner : Addr,
+
+    pub name : String,
+
+    pub symbol : String,
+
+    pub mint_pause : MintPause,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CollectionsResponse {
+
+    pub collections : Vec<Collection>,
+
+    // the listed collections whose minting is paused
+    pub mint_pauses : Option<Vec<CollectionMintPause>>,
+}
+
+
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CollectionsWithParamsResponse {
+
+    pub collections : Vec<Collection>,
+
+    pub total : Option<u32>,
+
+    pub start : Option<u32>,
+
+    pub limit : Option<u32>,
+
+    // the owners of the listed collections who are verified creators
+    pub verified_owners : Option<Vec<Addr>>,
+
+    // the listed collections whose minting is paused
+    pub mint_pauses : Option<Vec<CollectionMintPause>>,
+}
+
+impl CollectionsWithParamsResponse {
+
+    pub fn empty_response() -> Self {
+
+        CollectionsWithParamsResponse {
+            collections: vec![],
+            total : None,
+            start : None,
+            limit : None, 
+            verified_owners : None,
+            mint_pauses : None,
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ItemResponse {
+    
+    pub item : Option<Item>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ItemsResponse {
+    
+    pub items : Vec<Item>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ItemCountResponse {
+    pub count : usize,
+}
+
+
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TreasuryPayout {
+
+    pub wallet : Addr,
+
+    pub amount : Coin, 
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SimulateMintResponse {
+
+    pub quantity : u32,
+
+    // the collection price for each mint
+    pub price : Option<Coin>,
+
+    // the NFT_MINTING_FEE for each mint, after the fee tier of the collection owner
+    pub minting_fee : Option<Coin>,
+
+    // the total of price and minting fee for all the quantity
+    pub total_required : Vec<Coin>,
+
+    // payouts to the collection treasuries for all the quantity
+    pub payouts : Vec<TreasuryPayout>,
+
+    // the reason the mint would currently fail, none if it would succeed
+    pub error : Option<String>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeQuoteResponse {
+
+    pub operation : FeeType,
+
+    pub fee_name : String,
+
+    pub quantity : u32,
+
+    // the fee for each operation, none if the fee is not charged
+    pub fee : Option<Coin>,
+
+    // the fee for all the quantity
+    pub total : Option<Coin>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeScheduleResponse {
+
+    pub fees : Vec<FeeQuoteResponse>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeTiersResponse {
+
+    pub tiers : Vec<FeeTier>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EffectiveFeesResponse {
+
+    pub address : Addr,
+
+    pub tier : Option<String>,
+
+    pub fees : Vec<FeeQuoteResponse>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReferralStatsResponse {
+
+    pub stats : Option<ReferralStats>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReferralLeaderboardResponse {
+
+    pub referrers : Vec<ReferralStats>,
+
+    pub start_after : Option<Addr>,
+
+    pub limit : Option<u32>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PromoCodeResponse {
+
+    pub promo_code : Option<PromoCode>,
+
+    pub expired : bool,
+
+    // none when the code has no usage limit
+    pub remaining_uses : Option<u32>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CurrentPriceResponse {
+
+    pub price : Option<Coin>,
+
+    pub auction_active : bool,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AuctionStatusResponse {
+
+    pub auction : Option<DutchAuction>,
+
+    pub state : Option<AuctionState>,
+
+    pub current_price : Option<Coin>,
+
+    pub ended : bool,
+
+    // available once the auction has ended
+    pub clearing_price : Option<Coin>,
+
+    pub purchase : Option<AuctionPurchase>,
+
+    // the refund still to be claimed by the buyer
+    pub refund : Option<Coin>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BondingCurveQuoteResponse {
+
+    pub curve : Option<BondingCurve>,
+
+    pub minted : u64,
+
+    // the price of each of the next mints
+    pub prices : Vec<Coin>,
+
+    pub total : Option<Coin>,
+
+    pub error : Option<String>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintPhasesResponse {
+
+    pub phases : Vec<MintPhase>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ActivePhaseResponse {
+
+    pub phase : Option<MintPhase>,
+
+    // only when a wallet is given
+    pub minted_by_wallet : Option<u32>,
+
+    pub eligible : Option<bool>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ModerationResponse {
+
+    pub moderation : Option<Moderation>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VerifiedCreatorResponse {
+
+    pub verified_creator : Option<VerifiedCreator>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeaturedCollectionInfo {
+
+    pub featured : FeaturedCollection,
+
+    pub collection : Option<Collection>,
+
+    pub verified : bool,
+
+    // minting is paused until resume_at, when set
+    pub mint_pause : Option<MintPause>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeaturedCollectionsResponse {
+
+    pub featured : Vec<FeaturedCollectionInfo>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingOwnerResponse {
+
+    pub pending_owner : Option<PendingOwner>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CollectionMembersResponse {
+
+    pub members : Vec<CollectionMember>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DelegatedMintersResponse {
+
+    pub minters : Vec<DelegatedMinter>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CollectionSignersResponse {
+
+    pub signers : Option<CollectionSigners>,
+
+    pub proposals : Vec<ChangeProposal>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PauseStatusResponse {
+
+    pub paused : Vec<PauseFlag>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BlockedAddressesResponse {
+
+    pub addresses : Vec<BlockedAddress>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PhaseAllowlistResponse {
+
+    pub addresses : Vec<Addr>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NameAvailabilityResponse {
+
+    pub available : bool,
+
+    // true when a collection already uses the name and symbol
+    pub taken : bool,
+
+    pub reservation : Option<NameReservation>,
+}
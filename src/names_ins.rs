@@ -0,0 +1,200 @@
+use cosmwasm_std::{DepsMut, Env, Response, MessageInfo, BankMsg, Addr, Order, Storage, StdResult, Timestamp, Empty};
+use cw_storage_plus::Bound;
+use crate::state::{NameReservation, FeeType, NAME_RESERVATION_DURATION, NAME_RESERVATION_COOLDOWN, 
+MAX_ACTIVE_NAME_RESERVATIONS};
+use crate::indexes::{NAME_RESERVATIONS_STORE, NAME_RESERVERS_STORE};
+use crate::error::ContractError;
+use crate::ins::{name_key, common_response, STATUS_OK};
+use crate::query::{internal_get_validation_limits, internal_get_effective_fee, DEFAULT_LIMIT, MAX_LIMIT};
+use crate::validation::{check_if_name_valid, check_if_symbol_valid};
+use crate::checks::{check_if_contract_admin, check_if_name_available, check_if_not_blocked, check_if_fund_sufficient};
+use crate::fee_ins::pay_contract_fee;
+
+
+/*
+Reserves a name and symbol for the sender, so nobody else can
+create a collection with them until the reservation expires.
+The reservation is paid by the name reservation fee if set, 
+a creator holds a limited number of them at once and can NOT 
+reserve the same name again until the cooldown after it ends
+ */
+pub fn reserve_name(mut deps : DepsMut, _env : Env, info : MessageInfo,
+    name : String, symbol : String) -> Result<Response, ContractError> {
+
+    check_if_not_blocked(deps.as_ref(), &info.sender)?;
+
+    let limits = internal_get_validation_limits(deps.as_ref());
+
+    check_if_name_valid("name", &name, &limits)?;
+
+    check_if_symbol_valid(&symbol, &limits)?;
+
+    check_if_name_available(deps.as_ref(), &info.sender, name.clone(), symbol.clone(), _env.block.time)?;
+
+    let _key = name_key(&name, &symbol);
+
+    let previous = NAME_RESERVATIONS_STORE.may_load(deps.storage, _key.clone())?
+    .filter(|r| r.reserved_by == info.sender);
+
+    if let Some(previous) = previous {
+
+        let renewable_at = previous.expires_at.plus_seconds(NAME_RESERVATION_COOLDOWN);
+
+        if _env.block.time < renewable_at {
+            return Err(ContractError::NameNotAvailable { 
+                text : format!("Name {} with symbol {} can NOT be reserved again by {} until {}!", 
+                name, symbol, info.sender, renewable_at.seconds())});
+        }
+    }
+
+    let active = prune_reservations_of(deps.storage, &info.sender, _env.block.time)?;
+
+    if active >= MAX_ACTIVE_NAME_RESERVATIONS {
+        return Err(ContractError::NameReservationLimitReached { 
+            text : format!("{} already holds {} reserved names!", info.sender, active)});
+    }
+
+    let fee = internal_get_effective_fee(deps.as_ref(), &info.sender, &FeeType::NameReservation);
+
+    let mut bank_msgs : Vec<BankMsg> = vec![];
+
+    // no fee is charged when the admins have not set one
+    if let Some(fee) = fee {
+
+        check_if_fund_sufficient(info.clone(), fee)?;
+
+        bank_msgs = pay_contract_fee(deps.branch(), _env.clone(), info.clone(), 
+        FeeType::NameReservation, &info.sender)?;
+    }
+
+    let expires_at = _env.block.time.plus_seconds(NAME_RESERVATION_DURATION);
+
+    // the previous reserver of an expired reservation no longer counts it
+    if let Some(r) = NAME_RESERVATIONS_STORE.may_load(deps.storage, _key.clone())? {
+        NAME_RESERVERS_STORE.remove(deps.storage, (r.reserved_by, _key.clone()));
+    }
+
+    NAME_RESERVATIONS_STORE.save(deps.storage, _key.clone(), &NameReservation {
+        name : name,
+        symbol : symbol,
+        reserved_by : info.sender.clone(),
+        expires_at : expires_at,
+    })?;
+
+    NAME_RESERVERS_STORE.save(deps.storage, (info.sender, _key.clone()), &Empty {})?;
+
+    common_response(_key.as_str(), "reserve_name", STATUS_OK,
+    Some(format!("Reserved until {}", expires_at.seconds())), Some(bank_msgs))
+}
+
+
+/*
+Releases a reservation by the creator who made it, or by an admin,
+it ends at once but is kept for the cooldown, so releasing it 
+does not let its reserver take it again right away
+ */
+pub fn release_name(deps : DepsMut, _env : Env, info : MessageInfo,
+    name : String, symbol : String) -> Result<Response, ContractError> {
+
+    let _key = name_key(&name, &symbol);
+
+    let reservation = NAME_RESERVATIONS_STORE.may_load(deps.storage, _key.clone())?
+    .filter(|r| r.is_active_at(_env.block.time));
+
+    let mut reservation = reservation.ok_or(ContractError::CustomErrorMesg { 
+        message : "No reservation is found!".to_string()})?;
+
+    if reservation.reserved_by != info.sender {
+        check_if_contract_admin(deps.as_ref(), &info.sender)?;
+    }
+
+    reservation.expires_at = _env.block.time;
+
+    NAME_RESERVATIONS_STORE.save(deps.storage, _key.clone(), &reservation)?;
+
+    common_response(_key.as_str(), "release_name", STATUS_OK, None, None)
+}
+
+
+/*
+Removes the reservations whose cooldown has passed, checking up to 
+limit reservations after start_after, anyone can run it
+ */
+pub fn prune_name_reservations(deps : DepsMut, _env : Env, start_after : Option<String>, 
+    limit : Option<u32>) -> Result<Response, ContractError> {
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let checked : Vec<(String, NameReservation)> = NAME_RESERVATIONS_STORE
+    .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+    .take(limit)
+    .collect::<StdResult<_>>()?;
+
+    let last = checked.last().map(|(k, _)| k.clone());
+
+    let mut pruned : u32 = 0;
+
+    for (_key, r) in checked {
+
+        if is_stale(&r, _env.block.time) {
+
+            remove_name_reservation(deps.storage, &_key)?;
+
+            pruned += 1;
+        }
+    }
+
+    common_response("name_reservations", "prune_name_reservations", STATUS_OK, 
+    Some(format!("{} pruned, last : {}", pruned, last.unwrap_or_default())), None)
+}
+
+
+// a reservation past its cooldown no longer matters to anyone
+fn is_stale(reservation : &NameReservation, time : Timestamp) -> bool {
+
+    time >= reservation.expires_at.plus_seconds(NAME_RESERVATION_COOLDOWN)
+}
+
+
+// removes a reservation, when it is claimed or pruned, with its reserver entry
+pub (crate) fn remove_name_reservation(storage : &mut dyn Storage, _key : &str) -> StdResult<()> {
+
+    if let Some(r) = NAME_RESERVATIONS_STORE.may_load(storage, _key.to_string())? {
+        NAME_RESERVERS_STORE.remove(storage, (r.reserved_by, _key.to_string()));
+    }
+
+    NAME_RESERVATIONS_STORE.remove(storage, _key.to_string());
+
+    Ok(())
+}
+
+
+/*
+Prunes the stale reservations of a reserver and the entries of
+those taken over by others, returns the number still active
+ */
+fn prune_reservations_of(storage : &mut dyn Storage, reserver : &Addr, time : Timestamp) -> StdResult<usize> {
+
+    let keys : Vec<String> = NAME_RESERVERS_STORE
+    .prefix(reserver.clone())
+    .keys(storage, None, None, Order::Ascending)
+    .collect::<StdResult<_>>()?;
+
+    let mut active : usize = 0;
+
+    for _key in keys {
+
+        match NAME_RESERVATIONS_STORE.may_load(storage, _key.clone())?.filter(|r| r.reserved_by == *reserver) {
+
+            None => NAME_RESERVERS_STORE.remove(storage, (reserver.clone(), _key)),
+
+            Some(r) if is_stale(&r, time) => remove_name_reservation(storage, &_key)?,
+
+            Some(r) if r.is_active_at(time) => active += 1,
+
+            Some(_) => (),
+        }
+    }
+
+    Ok(active)
+}
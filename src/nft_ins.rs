@@ -1,17 +1,62 @@
-use cosmwasm_std::{DepsMut, MessageInfo, Env, Response, BankMsg, Binary, Coin };
-use crate::state::{Item, Collection, PRICE_TYPE_STANDARD};
+use cosmwasm_std::{Deps, DepsMut, MessageInfo, Env, Response, BankMsg, Binary, Coin, Addr, Storage, StdResult, 
+StdError, Empty };
+use crate::state::{Item, Collection, FeeType, ReferralStats, TREASURY_PERCENTAGE_TOTAL};
+use crate::indexes::{collections_store, REFERRALS_STORE, REFERRAL_RANKS_STORE};
 use crate::error::ContractError;
 use crate::utils::nft_token_id;
-use pix0_contract_common::funcs::{pay_by_percentage_checked, to_bank_messages, try_paying_contract_treasuries};
+use pix0_contract_common::funcs::try_paying_contract_treasuries;
+use crate::fee_ins::pay_contract_fee;
 use pix0_market_handlers::nft_ins::NftContract;
-use pix0_market_handlers::state::{Metadata, SellOffer};
+use pix0_market_handlers::state::{Metadata, SellOffer, SimpleCollectionInfo};
 use pix0_market_handlers::handlers::process_nft_action;
 use cw721::Cw721ReceiveMsg;
 use crate::error::MContractError;
+use crate::checks::{check_if_not_blocked, check_if_not_blocked_by_collection, check_if_referrer_valid};
+use crate::validation::check_if_item_fields_valid;
+use crate::ins::{collection_key, name_key};
+
+
+/*
+The collection of a token minted by this contract, from the collection 
+info kept in its traits, it is looked up by its name and symbol which
+are unique across the creators, as the owner in the traits is the 
+owner at the time of the mint
+ */
+fn token_collection(deps : Deps, token_id : &str) -> Option<Collection> {
+
+    let token = NftContract::default().tokens.may_load(deps.storage, token_id).ok()??;
+
+    let info = token.extension?.attributes?.into_iter()
+    .find(|t| t.trait_type == "collection-info")?;
+
+    let info : SimpleCollectionInfo = serde_json::from_str(&info.value).ok()?;
+
+    collections_store().idx.name_symbols
+    .item(deps.storage, name_key(&info.collection_name, &info.collection_symbol)).ok()?
+    .map(|(_, c)| c)
+}
+
+
+/*
+Checks the address against the blocklist of the admins, and 
+the blocklist of the collection when the token is of this contract
+ */
+fn check_if_not_blocked_for_token(deps : Deps, token_id : &str, address : &Addr) -> Result<(), ContractError> {
+
+    match token_collection(deps, token_id) {
+
+        Some(collection) => check_if_not_blocked_by_collection(deps, &collection, address),
+
+        None => check_if_not_blocked(deps, address),
+    }
+}
+
 
 pub fn create_sell_offer(deps : DepsMut, _env : Env, info : MessageInfo, offer : SellOffer, 
 create_sell_offer_fee :Option<Coin>) -> Result<Response, ContractError> {
 
+    check_if_not_blocked_for_token(deps.as_ref(), &offer.token_id, &info.sender)?;
+
     let res = pix0_market_handlers::triggers::create_sell_offer(deps, _env,
     info, offer,create_sell_offer_fee, None);
 
@@ -27,6 +72,9 @@ create_sell_offer_fee :Option<Coin>) -> Result<Response, ContractError> {
 pub fn receive_nft(deps : DepsMut, _env : Env, info : MessageInfo, nft_msg: Cw721ReceiveMsg) 
 -> Result<Response, ContractError> {
 
+    check_if_not_blocked_for_token(deps.as_ref(), &nft_msg.token_id, 
+    &deps.api.addr_validate(&nft_msg.sender)?)?;
+
     let res : Result<Response, MContractError> = process_nft_action(deps, _env, info, nft_msg, None);
 
     match res {
@@ -49,10 +97,11 @@ pub fn mint_nft(mut deps: DepsMut,
     contract :  NftContract,
     item : Item, 
     collection : Collection,
-    price_type : Option<u8>,
+    price : Coin,
     token_uri : Option<String>,
     method : Option<String>,
-    _token_id : Option<String>)-> Result<Response, ContractError>  {
+    _token_id : Option<String>,
+    referrer : Option<Addr>)-> Result<Response, ContractError>  {
 
     let new_owner = info.clone().sender;
    
@@ -102,32 +151,16 @@ pub fn mint_nft(mut deps: DepsMut,
 
         Ok(_res) =>  {
 
-           let mut prc_typ = PRICE_TYPE_STANDARD;
-
-           if price_type.is_some() {
-                prc_typ = price_type.unwrap();
-           }
-
-           // let bank_msgs = pay_collection_treasuries(deps, _env, 
-           // info, collection, prc_typ);
-
            let bank_msgs = pay_all_treasuries(deps, _env, 
-            info, collection, prc_typ);
-
-            if bank_msgs.is_some() {
+            info, collection, price, referrer)?;
 
-                let mut mthd = "mint-nft".to_string();
-                if method.is_some() {
-                    mthd = method.unwrap();
-                }
-
-                Ok(Response::new().add_attribute("method", mthd)
-                .add_messages(bank_msgs.unwrap()))
-    
-            }
-            else {
-                Err(ContractError::FailedToMakePayment { text: "Failed to make payment when minting NFT".to_string()})
+            let mut mthd = "mint-nft".to_string();
+            if method.is_some() {
+                mthd = method.unwrap();
             }
+
+            Ok(Response::new().add_attribute("method", mthd)
+            .add_messages(bank_msgs))
            
         },
 
@@ -143,10 +176,11 @@ pub fn init_and_mint_nft(mut deps: DepsMut,  _env : Env,
     info: MessageInfo, 
     item : Item, 
     collection : Collection, 
-    price_type : Option<u8>,
+    price : Coin,
     token_uri : Option<String>,
     method : Option<String>,
-    _token_id : Option<String>) -> Result<Response, ContractError>{
+    _token_id : Option<String>,
+    referrer : Option<Addr>) -> Result<Response, ContractError>{
 
     let msg =  cw721_base::InstantiateMsg {
         name: item.collection_name.clone(),
@@ -158,7 +192,7 @@ pub fn init_and_mint_nft(mut deps: DepsMut,  _env : Env,
 
     let _res = contract.instantiate(deps.branch(), _env.clone(), info.clone(),msg);
     
-    mint_nft(deps, _env, info, contract, item,collection, price_type, token_uri, method, _token_id)
+    mint_nft(deps, _env, info, contract, item,collection, price, token_uri, method, _token_id, referrer)
     
 }
 
@@ -170,6 +204,10 @@ pub fn init_and_simple_mint(mut deps: DepsMut,  _env : Env,
     token_uri : Option<String>,
     _token_id : Option<String>) -> Result<Response, ContractError>{
 
+    check_if_not_blocked(deps.as_ref(), &info.sender)?;
+
+    check_if_item_fields_valid(deps.as_ref(), &item, true)?;
+
     let msg =  cw721_base::InstantiateMsg {
         name: item.collection_name.clone(),
         symbol: item.collection_symbol.clone(),
@@ -185,17 +223,19 @@ pub fn init_and_simple_mint(mut deps: DepsMut,  _env : Env,
 }
 
 
-fn simple_mint(mut deps: DepsMut,  
+/*
+Mints the item into the new owner's wallet by the minter 
+set when the NFT contract is instantiated
+ */
+fn mint_to(deps: DepsMut,  
     _env : Env, 
     info: MessageInfo, 
-    contract :  NftContract,
+    contract :  &NftContract,
     item : Item, 
     token_uri : Option<String>,
-    method : Option<String>,
-    _token_id : Option<String>)-> Result<Response, ContractError>  {
+    _token_id : Option<String>,
+    new_owner : Addr)-> Result<(), ContractError>  {
 
-    let new_owner = info.clone().sender;
-   
     let ext_url = item.external_link();
 
     let mut token_id = _token_id;
@@ -223,7 +263,6 @@ fn simple_mint(mut deps: DepsMut,
         ..Metadata::default()
     });
 
-    
     let msg = cw721_base::msg::MintMsg {
         token_id: token_id.unwrap() ,
         owner: new_owner.to_string(),
@@ -233,43 +272,87 @@ fn simple_mint(mut deps: DepsMut,
 
     let mint_msg = cw721_base::msg::ExecuteMsg::Mint(msg);
 
-    let res = contract.execute(deps.branch(), _env.clone(), info.clone(), mint_msg);
+    let res = contract.execute(deps, _env, info, mint_msg);
 
     match res {
 
-        Ok(_res) =>  {
+        Ok(_res) => Ok(()),
 
-        
-           let bank_msgs = pay_simple_mint_fee(deps, _env, info);
+        Err(e) => Err(ContractError::CustomErrorMesg{message : e.to_string()}), 
+    }
+}
+
+
+fn simple_mint(mut deps: DepsMut,  
+    _env : Env, 
+    info: MessageInfo, 
+    contract :  NftContract,
+    item : Item, 
+    token_uri : Option<String>,
+    method : Option<String>,
+    _token_id : Option<String>)-> Result<Response, ContractError>  {
 
-            if bank_msgs.is_some() {
+    let new_owner = info.clone().sender;
+   
+    mint_to(deps.branch(), _env.clone(), info.clone(), &contract, item, token_uri, _token_id, new_owner)?;
 
-                let mut mthd = "simple-mint-nft".to_string();
-                if method.is_some() {
-                    mthd = method.unwrap();
-                }
+    let bank_msgs = pay_simple_mint_fee(deps, _env, info);
 
-                Ok(Response::new().add_attribute("method", mthd)
-                .add_messages(bank_msgs.unwrap()))
-    
-            }
-            else {
-                Err(ContractError::FailedToMakePayment { text: "Failed to make payment when minting NFT".to_string()})
-            }
-           
-        },
+    if bank_msgs.is_some() {
 
-        Err(e) => Err(ContractError::CustomErrorMesg{message : e.to_string()}), 
+        let mut mthd = "simple-mint-nft".to_string();
+        if method.is_some() {
+            mthd = method.unwrap();
+        }
 
+        Ok(Response::new().add_attribute("method", mthd)
+        .add_messages(bank_msgs.unwrap()))
+
+    }
+    else {
+        Err(ContractError::FailedToMakePayment { text: "Failed to make payment when minting NFT".to_string()})
     }
 
 }
 
 
+/*
+Mints the item into the recipient's wallet for a delegated minter, 
+the contract itself is the minter of the NFT contract, and the fees
+are paid by the delegated minter beforehand
+ */
+pub (crate) fn init_and_mint_for(mut deps: DepsMut,  _env : Env, 
+    recipient : Addr, 
+    item : Item, 
+    token_uri : Option<String>,
+    _token_id : Option<String>) -> Result<Response, ContractError>{
+
+    let minter_info = MessageInfo { sender : _env.contract.address.clone(), funds : vec![] };
+
+    let msg =  cw721_base::InstantiateMsg {
+        name: item.collection_name.clone(),
+        symbol: item.collection_symbol.clone(),
+        minter: String::from(minter_info.sender.clone()),
+    };
+
+    let contract = NftContract::default();
+
+    let _res = contract.instantiate(deps.branch(), _env.clone(), minter_info.clone(), msg);
+
+    mint_to(deps, _env, minter_info, &contract, item, token_uri, _token_id, recipient)?;
+
+    Ok(Response::new().add_attribute("method", "mint-for"))
+}
+
+
 
 pub fn transfer_nft ( deps: DepsMut,  _env : Env, 
     info: MessageInfo,  recipient : String, token_id : String ) -> Result<Response, ContractError> {
 
+    check_if_not_blocked_for_token(deps.as_ref(), &token_id, &info.sender)?;
+
+    check_if_not_blocked_for_token(deps.as_ref(), &token_id, &deps.api.addr_validate(&recipient)?)?;
+
     let res = pix0_market_handlers::nft_ins::transfer_nft( deps, _env, info, recipient, token_id);
 
     match res {
@@ -318,6 +401,9 @@ pub fn send_nft ( deps: DepsMut,  _env : Env,
     info: MessageInfo,  token_id : String, contract_addr : String,
     action : String ) -> Result<Response, ContractError>  {
 
+    check_if_not_blocked_for_token(deps.as_ref(), &token_id, &info.sender)?;
+
+    check_if_not_blocked_for_token(deps.as_ref(), &token_id, &deps.api.addr_validate(&contract_addr)?)?;
 
     let binary_action = Binary::from(serde_json::to_vec(&action).unwrap());
 
@@ -348,35 +434,19 @@ pub fn send_nft ( deps: DepsMut,  _env : Env,
 
 
 
-pub fn pay_all_treasuries (mut deps : DepsMut, _env: Env, info : MessageInfo, collection : Collection, price_type : u8) -> 
-Option<Vec<BankMsg>>{
-
-    let bank_msgs = pay_collection_treasuries(deps.branch(), _env.clone(), info.clone(), collection, price_type);
-
-    let mut new_bmsgs : Vec<BankMsg> = Vec::new();
-
-    if bank_msgs.is_some() {
-        new_bmsgs.extend(bank_msgs.unwrap());
-    }
-
-    let _msgs = try_paying_contract_treasuries(deps, _env, 
-    info, "NFT_MINTING_FEE");
+pub fn pay_all_treasuries (mut deps : DepsMut, _env: Env, info : MessageInfo, collection : Collection, price : Coin,
+referrer : Option<Addr>) -> Result<Vec<BankMsg>, ContractError>{
 
-    if _msgs.is_ok() {
+    let (creator, _) = collection_key(&collection)?;
 
-        let bmsgs = _msgs.ok();
-        if bmsgs.is_some() {
-            new_bmsgs.extend(bmsgs.unwrap());
-        }
-    }
+    let mut new_bmsgs = pay_collection_treasuries(deps.branch(), _env.clone(), info.clone(), 
+    collection, price, referrer)?;
 
-    if new_bmsgs.len() > 0 {
+    // a misconfigured fee fails the mint rather than letting it through unpaid
+    new_bmsgs.extend(pay_contract_fee(deps, _env, info, FeeType::NftMinting, &creator)?);
 
-        Some(new_bmsgs)
-    }
-    else {
-        None 
-    } 
+    // a free mint has nothing to pay
+    Ok(new_bmsgs)
 }
 
 
@@ -384,7 +454,7 @@ pub fn pay_simple_mint_fee (deps : DepsMut, _env: Env, info : MessageInfo) ->
 Option<Vec<BankMsg>>{
 
     let _msgs = try_paying_contract_treasuries(deps, _env, 
-    info, "SIMPLE_NFT_MINTING_FEE");
+    info, FeeType::SimpleNftMinting.name());
 
     if _msgs.is_ok() {
 
@@ -395,26 +465,75 @@ Option<Vec<BankMsg>>{
     } 
 }
 
+/*
+The stats of the referrer and its position in 
+REFERRAL_RANKS_STORE are updated together
+ */
+fn record_referral(storage : &mut dyn Storage, referrer : &Addr, reward : &Coin) -> StdResult<()> {
+
+    let mut stats = REFERRALS_STORE.may_load(storage, referrer.clone())?
+    .unwrap_or(ReferralStats { referrer : referrer.clone(), mints : 0, earnings : vec![] });
+
+    REFERRAL_RANKS_STORE.remove(storage, (stats.mints, referrer.clone()));
+
+    stats.mints += 1;
+
+    REFERRAL_RANKS_STORE.save(storage, (stats.mints, referrer.clone()), &Empty {})?;
+
+    if !reward.amount.is_zero() {
+
+        if let Some(e) = stats.earnings.iter_mut().find(|e| e.denom == reward.denom) {
+            e.amount = e.amount.checked_add(reward.amount)?;
+        }
+        else {
+            stats.earnings.push(reward.clone());
+        }
+    }
+
+    REFERRALS_STORE.save(storage, referrer.clone(), &stats)
+}
+
+
+/*
+The referral share of the collection is carved out of the price 
+before it is split among the collection treasuries, a minter 
+cannot be its own referrer
+ */
 pub fn pay_collection_treasuries (
 deps: DepsMut,  _env : Env, 
 info: MessageInfo,     
-collection : Collection, price_type : u8 ) -> Option<Vec<BankMsg>>{
+collection : Collection, price : Coin,
+referrer : Option<Addr> ) -> Result<Vec<BankMsg>, ContractError>{
 
-    let payments = collection.treasuries_to_payments();
-    let price = collection.price_by_type( price_type);
+    let mut proceeds = price.clone();
 
-    if price.is_some() {
+    let mut bank_msgs : Vec<BankMsg> = Vec::new();
 
-        let amts = 
-        pay_by_percentage_checked(deps, info, _env.block.time, payments, price.unwrap());
+    check_if_referrer_valid(&referrer, &info.sender)?;
 
-        let bank_msgs = to_bank_messages(amts);
+    if referrer.is_some() {
 
-        bank_msgs
-    }
-    else {
+        let referrer = referrer.unwrap();
 
-        None
+        let share = collection.referral_share.unwrap_or(0).min(TREASURY_PERCENTAGE_TOTAL);
+
+        let reward = Coin { amount : price.amount.multiply_ratio(share as u128, 
+            TREASURY_PERCENTAGE_TOTAL as u128), denom : price.denom.clone() };
+
+        proceeds.amount = price.amount.checked_sub(reward.amount).map_err(|e| StdError::from(e))?;
+
+        record_referral(deps.storage, &referrer, &reward)?;
+
+        if !reward.amount.is_zero() {
+            bank_msgs.push(BankMsg::Send { to_address : referrer.to_string(), amount : vec![reward] });
+        }
     }
 
+    let payments = collection.treasuries_to_payments(proceeds)?;
+
+    bank_msgs.extend(payments.into_iter()
+    .filter(|(_, amt)| !amt.amount.is_zero())
+    .map(|(wallet, amt)| BankMsg::Send { to_address : wallet.to_string(), amount : vec![amt] }));
+
+    Ok(bank_msgs)
 }
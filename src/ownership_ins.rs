@@ -0,0 +1,481 @@
+use cosmwasm_std::{DepsMut, Env, Response, MessageInfo, Addr, Order, StdResult, Storage, Empty};
+use crate::state::{Item, PendingOwner, PromoCode, AuctionPurchase, PhaseMint, BlockedAddress, Treasury, TREASURY_PERCENTAGE_TOTAL,
+DelegatedMinter, ChangeProposal, CollectionRole, COLLECTION_MOVE_MAX_RECORDS};
+use crate::indexes::{collections_store, COLLECTION_ITEMS_STORE, PENDING_OWNERS_STORE, PROMO_CODES_STORE, 
+AUCTION_STATES_STORE, AUCTION_PURCHASES_STORE, PHASE_MINTS_STORE, MODERATIONS_STORE, MINT_PAUSES_STORE,
+FEATURED_COLLECTIONS_STORE, MINTED_COUNTS_STORE, COLLECTION_MEMBERS_STORE, 
+DELEGATED_MINTERS_STORE, COLLECTION_SIGNERS_STORE, CHANGE_PROPOSALS_STORE, LAST_PROPOSAL_IDS_STORE, 
+COLLECTION_BLOCKLISTS_STORE, BLOCK_MINTS_STORE, LAST_MINT_HEIGHTS_STORE, PHASE_ALLOWLISTS_STORE};
+use crate::error::ContractError;
+use crate::ins::{collection_id, common_response, STATUS_OK};
+use crate::query::internal_get_collection;
+use crate::curation_ins::{save_featured, remove_featured};
+use crate::checks::*;
+
+
+pub fn propose_collection_owner(deps : DepsMut, _env : Env, info : MessageInfo, 
+    collection_name : String, collection_symbol : String, new_owner : Addr, 
+    update_treasury : Option<bool>) -> Result<Response, ContractError> {
+
+    check_if_action_needs_approval(deps.as_ref(), 
+    &(info.sender.clone(), collection_id(collection_name.clone(), collection_symbol.clone())), "Ownership transfer")?;
+
+    internal_propose_collection_owner(deps, _env, info, collection_name, collection_symbol, new_owner, update_treasury)
+}
+
+
+pub (crate) fn internal_propose_collection_owner(deps : DepsMut, _env : Env, info : MessageInfo, 
+    collection_name : String, collection_symbol : String, new_owner : Addr, 
+    update_treasury : Option<bool>) -> Result<Response, ContractError> {
+
+    let owner = info.sender.clone();
+
+    let collection = internal_get_collection(deps.as_ref(), owner.clone(), 
+    collection_name.clone(), collection_symbol.clone());
+
+    if collection.is_none() {
+        return Err(ContractError::CollectionNotFound { text: "Collection is NOT found!".to_string()});
+    }
+
+    check_if_collection_not_suspended(deps.as_ref(), &collection.unwrap())?;
+
+    // the new owner can be a wallet or a contract such as a multisig, 
+    // which accepts the collection by executing the acceptance itself
+    deps.api.addr_validate(new_owner.as_str())?;
+
+    if new_owner == owner {
+        return Err(ContractError::CustomErrorMesg { message : "New owner is the current owner!".to_string()});
+    }
+
+    let _key = (owner, collection_id(collection_name, collection_symbol));
+
+    check_if_collection_movable(deps.storage, &_key)?;
+
+    PENDING_OWNERS_STORE.save(deps.storage, _key.clone(), &PendingOwner {
+        new_owner : new_owner,
+        update_treasury : update_treasury.unwrap_or(false),
+        date_proposed : _env.block.time,
+    })?;
+
+    common_response(format!("{}-{}",_key.0, _key.1).as_str(), "propose_collection_owner", STATUS_OK, None, None)
+}
+
+
+pub fn cancel_collection_owner_proposal(deps : DepsMut, info : MessageInfo, 
+    collection_name : String, collection_symbol : String) -> Result<Response, ContractError> {
+
+    let _key = (info.sender, collection_id(collection_name, collection_symbol));
+
+    if !PENDING_OWNERS_STORE.has(deps.storage, _key.clone()) {
+        return Err(ContractError::CustomErrorMesg { message : "No owner proposal is found!".to_string()});
+    }
+
+    PENDING_OWNERS_STORE.remove(deps.storage, _key.clone());
+
+    common_response(format!("{}-{}",_key.0, _key.1).as_str(), "cancel_collection_owner_proposal", 
+    STATUS_OK, None, None)
+}
+
+
+/*
+The proposed owner accepts the collection, which re-keys the collection,
+its items and the other per collection records under the new owner
+ */
+pub fn accept_collection_owner(deps : DepsMut, _env : Env, info : MessageInfo, 
+    owner : Addr, collection_name : String, collection_symbol : String) -> Result<Response, ContractError> {
+
+    let coll_id = collection_id(collection_name.clone(), collection_symbol.clone());
+
+    let from = (owner.clone(), coll_id.clone());
+
+    let proposal = PENDING_OWNERS_STORE.may_load(deps.storage, from.clone())?;
+
+    if proposal.is_none() || proposal.clone().unwrap().new_owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let proposal = proposal.unwrap();
+
+    let collection = internal_get_collection(deps.as_ref(), owner.clone(), 
+    collection_name, collection_symbol);
+
+    if collection.is_none() {
+        return Err(ContractError::CollectionNotFound { text: "Collection is NOT found!".to_string()});
+    }
+
+    let mut collection = collection.unwrap();
+
+    check_if_not_blocked_by_collection(deps.as_ref(), &collection, &info.sender)?;
+
+    check_if_collection_movable(deps.storage, &from)?;
+
+    let new_owner = proposal.new_owner;
+
+    let to = (new_owner.clone(), coll_id);
+
+    if proposal.update_treasury {
+
+        if collection.treasuries.is_some() {
+            collection.treasuries = Some(collection.treasuries.unwrap().into_iter().map(|mut t| {
+                if t.wallet == owner {
+                    t.wallet = new_owner.clone();
+                }
+                t
+            }).collect());
+        }
+    }
+    else if collection.treasuries.is_none() {
+
+        // keep paying the previous owner, as the default treasury follows the owner
+        collection.treasuries = Some(vec![Treasury { wallet : owner.clone(), 
+            percentage : TREASURY_PERCENTAGE_TOTAL, name : None, primary : Some(true) }]);
+    }
+
+    collection.owner = Some(new_owner.clone());
+    collection.date_updated = Some(_env.block.time);
+
+    collections_store().remove(deps.storage, from.clone())?;
+    collections_store().save(deps.storage, to.clone(), &collection)?;
+
+    let moved = move_items(deps.storage, &from, &to)?;
+
+    move_collection_records(deps.storage, &from, &to, false)?;
+
+    PENDING_OWNERS_STORE.remove(deps.storage, from.clone());
+
+    common_response(format!("{}-{}",to.0, to.1).as_str(), "accept_collection_owner", STATUS_OK, 
+    Some(format!("{} item(s) moved from {}", moved, owner)), None)
+}
+
+
+/*
+The records of a collection re-keyed along with it, counted up to 
+COLLECTION_MOVE_MAX_RECORDS + 1, as a move is done in one transaction
+ */
+fn count_collection_records(storage : &dyn Storage, from : &(Addr, String)) -> usize {
+
+    let max = COLLECTION_MOVE_MAX_RECORDS + 1;
+
+    let mut count = COLLECTION_ITEMS_STORE.prefix(from.clone())
+    .keys(storage, None, None, Order::Ascending).take(max).count();
+
+    count += PROMO_CODES_STORE.prefix(from.clone())
+    .keys(storage, None, None, Order::Ascending).take(max).count();
+
+    count += AUCTION_PURCHASES_STORE.prefix(from.clone())
+    .keys(storage, None, None, Order::Ascending).take(max).count();
+
+    count += COLLECTION_BLOCKLISTS_STORE.prefix(from.clone())
+    .keys(storage, None, None, Order::Ascending).take(max).count();
+
+    count += DELEGATED_MINTERS_STORE.prefix(from.clone())
+    .keys(storage, None, None, Order::Ascending).take(max).count();
+
+    count += CHANGE_PROPOSALS_STORE.prefix(from.clone())
+    .keys(storage, None, None, Order::Ascending).take(max).count();
+
+    count += COLLECTION_MEMBERS_STORE.prefix(from.clone())
+    .keys(storage, None, None, Order::Ascending).take(max).count();
+
+    count += PHASE_MINTS_STORE.prefix(from.clone())
+    .keys(storage, None, None, Order::Ascending).take(max).count();
+
+    count += PHASE_ALLOWLISTS_STORE.sub_prefix(from.clone())
+    .keys(storage, None, None, Order::Ascending).take(max).count();
+
+    count += LAST_MINT_HEIGHTS_STORE.prefix(from.clone())
+    .keys(storage, None, None, Order::Ascending).take(max).count();
+
+    count.min(max)
+}
+
+
+fn check_if_collection_movable(storage : &dyn Storage, from : &(Addr, String)) -> Result<(), ContractError> {
+
+    if count_collection_records(storage, from) > COLLECTION_MOVE_MAX_RECORDS {
+        return Err(ContractError::CustomErrorMesg { message : format!(
+            "Collection has more than {} items and records to move to a new owner!", COLLECTION_MOVE_MAX_RECORDS)});
+    }
+
+    Ok(())
+}
+
+
+/*
+Re-keys the items of the collection from the owner and 
+collection id of the key from to those of the key to
+ */
+pub (crate) fn move_items(storage : &mut dyn Storage, from : &(Addr, String), to : &(Addr, String)) -> StdResult<u32> {
+
+    let items : StdResult<Vec<(String, Item)>> = COLLECTION_ITEMS_STORE
+    .prefix(from.clone())
+    .range(storage, None, None, Order::Ascending)
+    .collect();
+
+    let items = items?;
+
+    for (name, item) in items.iter() {
+
+        let mut item = item.clone();
+
+        item.collection_owner = to.0.clone();
+
+        COLLECTION_ITEMS_STORE.remove(storage, (from.0.clone(), from.1.clone(), name.clone()));
+        COLLECTION_ITEMS_STORE.save(storage, (to.0.clone(), to.1.clone(), name.clone()), &item)?;
+    }
+
+    Ok(items.len() as u32)
+}
+
+
+/*
+Re-keys the other per collection records, when the owner stays the same,
+the roles, minters and proposals granted by the owner are kept as well
+ */
+pub (crate) fn move_collection_records(storage : &mut dyn Storage, from : &(Addr, String), to : &(Addr, String),
+    same_owner : bool) -> StdResult<()> {
+
+    if let Some(s) = AUCTION_STATES_STORE.may_load(storage, from.clone())? {
+        AUCTION_STATES_STORE.remove(storage, from.clone());
+        AUCTION_STATES_STORE.save(storage, to.clone(), &s)?;
+    }
+
+    if let Some(m) = MODERATIONS_STORE.may_load(storage, from.clone())? {
+        MODERATIONS_STORE.remove(storage, from.clone());
+        MODERATIONS_STORE.save(storage, to.clone(), &m)?;
+    }
+
+    if let Some(p) = MINT_PAUSES_STORE.may_load(storage, from.clone())? {
+        MINT_PAUSES_STORE.remove(storage, from.clone());
+        MINT_PAUSES_STORE.save(storage, to.clone(), &p)?;
+    }
+
+    if let Some(mut f) = FEATURED_COLLECTIONS_STORE.may_load(storage, from.clone())? {
+        f.owner = to.0.clone();
+        remove_featured(storage, from)?;
+        save_featured(storage, to, &f)?;
+    }
+
+    if let Some(c) = MINTED_COUNTS_STORE.may_load(storage, from.clone())? {
+        MINTED_COUNTS_STORE.remove(storage, from.clone());
+        MINTED_COUNTS_STORE.save(storage, to.clone(), &c)?;
+    }
+
+    let codes : StdResult<Vec<(String, PromoCode)>> = PROMO_CODES_STORE
+    .prefix(from.clone())
+    .range(storage, None, None, Order::Ascending)
+    .collect();
+
+    for (k, v) in codes? {
+        PROMO_CODES_STORE.remove(storage, (from.0.clone(), from.1.clone(), k.clone()));
+        PROMO_CODES_STORE.save(storage, (to.0.clone(), to.1.clone(), k), &v)?;
+    }
+
+    let purchases : StdResult<Vec<(Addr, AuctionPurchase)>> = AUCTION_PURCHASES_STORE
+    .prefix(from.clone())
+    .range(storage, None, None, Order::Ascending)
+    .collect();
+
+    for (k, v) in purchases? {
+        AUCTION_PURCHASES_STORE.remove(storage, (from.0.clone(), from.1.clone(), k.clone()));
+        AUCTION_PURCHASES_STORE.save(storage, (to.0.clone(), to.1.clone(), k), &v)?;
+    }
+
+    // the blocked addresses stay blocked
+    let blocked : StdResult<Vec<(Addr, BlockedAddress)>> = COLLECTION_BLOCKLISTS_STORE
+    .prefix(from.clone())
+    .range(storage, None, None, Order::Ascending)
+    .collect();
+
+    for (k, v) in blocked? {
+        COLLECTION_BLOCKLISTS_STORE.remove(storage, (from.0.clone(), from.1.clone(), k.clone()));
+        COLLECTION_BLOCKLISTS_STORE.save(storage, (to.0.clone(), to.1.clone(), k), &v)?;
+    }
+
+    // the delegated minters were authorized by the owner
+    let minters : StdResult<Vec<(Addr, DelegatedMinter)>> = DELEGATED_MINTERS_STORE
+    .prefix(from.clone())
+    .range(storage, None, None, Order::Ascending)
+    .collect();
+
+    for (k, v) in minters? {
+        DELEGATED_MINTERS_STORE.remove(storage, (from.0.clone(), from.1.clone(), k.clone()));
+
+        if same_owner {
+            DELEGATED_MINTERS_STORE.save(storage, (to.0.clone(), to.1.clone(), k), &v)?;
+        }
+    }
+
+    // the signers stay with the collection, its pending proposals do not
+    let signers = COLLECTION_SIGNERS_STORE.may_load(storage, from.clone())?;
+
+    if signers.is_some() {
+        COLLECTION_SIGNERS_STORE.remove(storage, from.clone());
+        COLLECTION_SIGNERS_STORE.save(storage, to.clone(), &signers.unwrap())?;
+    }
+
+    // the proposal ids go on from the last one, even when the proposals are dropped
+    if let Some(id) = LAST_PROPOSAL_IDS_STORE.may_load(storage, from.clone())? {
+        LAST_PROPOSAL_IDS_STORE.remove(storage, from.clone());
+        LAST_PROPOSAL_IDS_STORE.save(storage, to.clone(), &id)?;
+    }
+
+    let proposals : StdResult<Vec<(u64, ChangeProposal)>> = CHANGE_PROPOSALS_STORE
+    .prefix(from.clone())
+    .range(storage, None, None, Order::Ascending)
+    .collect();
+
+    for (id, v) in proposals? {
+        CHANGE_PROPOSALS_STORE.remove(storage, (from.0.clone(), from.1.clone(), id));
+
+        if same_owner {
+            CHANGE_PROPOSALS_STORE.save(storage, (to.0.clone(), to.1.clone(), id), &v)?;
+        }
+    }
+
+    // the members were granted their roles by the owner
+    let members : StdResult<Vec<(Addr, Vec<CollectionRole>)>> = COLLECTION_MEMBERS_STORE
+    .prefix(from.clone())
+    .range(storage, None, None, Order::Ascending)
+    .collect();
+
+    for (k, v) in members? {
+        COLLECTION_MEMBERS_STORE.remove(storage, (from.0.clone(), from.1.clone(), k.clone()));
+
+        if same_owner {
+            COLLECTION_MEMBERS_STORE.save(storage, (to.0.clone(), to.1.clone(), k), &v)?;
+        }
+    }
+
+    let phase_mints : StdResult<Vec<(Addr, Vec<PhaseMint>)>> = PHASE_MINTS_STORE
+    .prefix(from.clone())
+    .range(storage, None, None, Order::Ascending)
+    .collect();
+
+    for (k, v) in phase_mints? {
+        PHASE_MINTS_STORE.remove(storage, (from.0.clone(), from.1.clone(), k.clone()));
+        PHASE_MINTS_STORE.save(storage, (to.0.clone(), to.1.clone(), k), &v)?;
+    }
+
+    let allowlisted : StdResult<Vec<(String, Addr)>> = PHASE_ALLOWLISTS_STORE
+    .sub_prefix(from.clone())
+    .keys(storage, None, None, Order::Ascending)
+    .collect();
+
+    for (phase, wallet) in allowlisted? {
+        PHASE_ALLOWLISTS_STORE.remove(storage, (from.clone(), phase.clone(), wallet.clone()));
+        PHASE_ALLOWLISTS_STORE.save(storage, (to.clone(), phase, wallet), &Empty {})?;
+    }
+
+    // the throttled mints follow the collection, so a transfer does not reset the throttle
+    if let Some(b) = BLOCK_MINTS_STORE.may_load(storage, from.clone())? {
+        BLOCK_MINTS_STORE.remove(storage, from.clone());
+        BLOCK_MINTS_STORE.save(storage, to.clone(), &b)?;
+    }
+
+    let heights : StdResult<Vec<(Addr, u64)>> = LAST_MINT_HEIGHTS_STORE
+    .prefix(from.clone())
+    .range(storage, None, None, Order::Ascending)
+    .collect();
+
+    for (k, v) in heights? {
+        LAST_MINT_HEIGHTS_STORE.remove(storage, (from.0.clone(), from.1.clone(), k.clone()));
+        LAST_MINT_HEIGHTS_STORE.save(storage, (to.0.clone(), to.1.clone(), k), &v)?;
+    }
+
+    // the pending owner proposal was made by the owner
+    if same_owner {
+
+        if let Some(p) = PENDING_OWNERS_STORE.may_load(storage, from.clone())? {
+            PENDING_OWNERS_STORE.remove(storage, from.clone());
+            PENDING_OWNERS_STORE.save(storage, to.clone(), &p)?;
+        }
+    }
+
+    Ok(())
+}
+
+
+/*
+Removes the other per collection records of a removed collection, 
+so a collection created later with the same name and symbol 
+does not inherit them
+ */
+pub (crate) fn remove_collection_records(storage : &mut dyn Storage, _key : &(Addr, String)) -> StdResult<()> {
+
+    AUCTION_STATES_STORE.remove(storage, _key.clone());
+    MODERATIONS_STORE.remove(storage, _key.clone());
+    MINT_PAUSES_STORE.remove(storage, _key.clone());
+    MINTED_COUNTS_STORE.remove(storage, _key.clone());
+    PENDING_OWNERS_STORE.remove(storage, _key.clone());
+    COLLECTION_SIGNERS_STORE.remove(storage, _key.clone());
+    LAST_PROPOSAL_IDS_STORE.remove(storage, _key.clone());
+    BLOCK_MINTS_STORE.remove(storage, _key.clone());
+
+    remove_featured(storage, _key)?;
+
+    let codes : StdResult<Vec<String>> = PROMO_CODES_STORE.prefix(_key.clone())
+    .keys(storage, None, None, Order::Ascending).collect();
+
+    for k in codes? {
+        PROMO_CODES_STORE.remove(storage, (_key.0.clone(), _key.1.clone(), k));
+    }
+
+    let purchases : StdResult<Vec<Addr>> = AUCTION_PURCHASES_STORE.prefix(_key.clone())
+    .keys(storage, None, None, Order::Ascending).collect();
+
+    for k in purchases? {
+        AUCTION_PURCHASES_STORE.remove(storage, (_key.0.clone(), _key.1.clone(), k));
+    }
+
+    let blocked : StdResult<Vec<Addr>> = COLLECTION_BLOCKLISTS_STORE.prefix(_key.clone())
+    .keys(storage, None, None, Order::Ascending).collect();
+
+    for k in blocked? {
+        COLLECTION_BLOCKLISTS_STORE.remove(storage, (_key.0.clone(), _key.1.clone(), k));
+    }
+
+    let minters : StdResult<Vec<Addr>> = DELEGATED_MINTERS_STORE.prefix(_key.clone())
+    .keys(storage, None, None, Order::Ascending).collect();
+
+    for k in minters? {
+        DELEGATED_MINTERS_STORE.remove(storage, (_key.0.clone(), _key.1.clone(), k));
+    }
+
+    let proposals : StdResult<Vec<u64>> = CHANGE_PROPOSALS_STORE.prefix(_key.clone())
+    .keys(storage, None, None, Order::Ascending).collect();
+
+    for id in proposals? {
+        CHANGE_PROPOSALS_STORE.remove(storage, (_key.0.clone(), _key.1.clone(), id));
+    }
+
+    let members : StdResult<Vec<Addr>> = COLLECTION_MEMBERS_STORE.prefix(_key.clone())
+    .keys(storage, None, None, Order::Ascending).collect();
+
+    for k in members? {
+        COLLECTION_MEMBERS_STORE.remove(storage, (_key.0.clone(), _key.1.clone(), k));
+    }
+
+    let phase_mints : StdResult<Vec<Addr>> = PHASE_MINTS_STORE.prefix(_key.clone())
+    .keys(storage, None, None, Order::Ascending).collect();
+
+    for k in phase_mints? {
+        PHASE_MINTS_STORE.remove(storage, (_key.0.clone(), _key.1.clone(), k));
+    }
+
+    let allowlisted : StdResult<Vec<(String, Addr)>> = PHASE_ALLOWLISTS_STORE.sub_prefix(_key.clone())
+    .keys(storage, None, None, Order::Ascending).collect();
+
+    for (phase, wallet) in allowlisted? {
+        PHASE_ALLOWLISTS_STORE.remove(storage, (_key.clone(), phase, wallet));
+    }
+
+    let heights : StdResult<Vec<Addr>> = LAST_MINT_HEIGHTS_STORE.prefix(_key.clone())
+    .keys(storage, None, None, Order::Ascending).collect();
+
+    for k in heights? {
+        LAST_MINT_HEIGHTS_STORE.remove(storage, (_key.0.clone(), _key.1.clone(), k));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use cosmwasm_std::{DepsMut, Env, Response, MessageInfo, Addr, Timestamp};
+use crate::state::{PauseGroup, PauseFlag, MintPause, CollectionRole};
+use crate::indexes::{PAUSE_FLAGS_STORE, MINT_PAUSES_STORE};
+use crate::error::ContractError;
+use crate::ins::{collection_id, common_response, STATUS_OK};
+use crate::query::internal_get_collection;
+use crate::checks::{check_if_contract_admin, check_if_collection_role};
+use crate::msg::ExecuteMsg;
+
+
+/*
+Pauses or resumes a group of operations across the contract,
+the pausing itself and the queries are never paused
+ */
+pub fn set_paused(deps : DepsMut, _env : Env, info : MessageInfo, group : PauseGroup, 
+    paused : bool, reason : Option<String>) -> Result<Response, ContractError> {
+
+    check_if_contract_admin(deps.as_ref(), &info.sender)?;
+
+    let _key = group.name().to_string();
+
+    if paused {
+
+        PAUSE_FLAGS_STORE.save(deps.storage, _key.clone(), &PauseFlag {
+            group : group,
+            reason : reason,
+            paused_by : info.sender,
+            date_paused : _env.block.time,
+        })?;
+    }
+    else {
+        PAUSE_FLAGS_STORE.remove(deps.storage, _key.clone());
+    }
+
+    common_response(_key.as_str(), if paused { "pause" } else { "resume" }, STATUS_OK, None, None)
+}
+
+
+/*
+Pauses minting of a collection by its owner or a manager
+without changing its status, so it stays listed
+ */
+pub fn pause_minting(deps : DepsMut, _env : Env, info : MessageInfo, owner : Addr, 
+    collection_name : String, collection_symbol : String, reason : Option<String>, 
+    resume_at : Option<Timestamp>) -> Result<Response, ContractError> {
+
+    let _key = (owner.clone(), collection_id(collection_name.clone(), collection_symbol.clone()));
+
+    if internal_get_collection(deps.as_ref(), owner, collection_name, collection_symbol).is_none() {
+        return Err(ContractError::CollectionNotFound { text: "Collection is NOT found!".to_string()});
+    }
+
+    check_if_collection_role(deps.as_ref(), &_key, &info.sender, CollectionRole::Manager)?;
+
+    if resume_at.is_some() && resume_at.unwrap() <= _env.block.time {
+        return Err(ContractError::CustomErrorMesg { message : "Resume time must be in the future!".to_string()});
+    }
+
+    MINT_PAUSES_STORE.save(deps.storage, _key.clone(), &MintPause {
+        reason : reason,
+        resume_at : resume_at,
+        paused_by : info.sender,
+        date_paused : _env.block.time,
+    })?;
+
+    common_response(format!("{}-{}",_key.0, _key.1).as_str(), "pause_minting", STATUS_OK, None, None)
+}
+
+
+pub fn resume_minting(deps : DepsMut, info : MessageInfo, owner : Addr, 
+    collection_name : String, collection_symbol : String) -> Result<Response, ContractError> {
+
+    let _key = (owner, collection_id(collection_name, collection_symbol));
+
+    check_if_collection_role(deps.as_ref(), &_key, &info.sender, CollectionRole::Manager)?;
+
+    if !MINT_PAUSES_STORE.has(deps.storage, _key.clone()) {
+        return Err(ContractError::CustomErrorMesg { message : "Minting is NOT paused!".to_string()});
+    }
+
+    MINT_PAUSES_STORE.remove(deps.storage, _key.clone());
+
+    common_response(format!("{}-{}",_key.0, _key.1).as_str(), "resume_minting", STATUS_OK, None, None)
+}
+
+
+/*
+The group pausing a message, every message but the pausing itself
+and those only the admins can send is paused by a group, 
+so a new message must be grouped here
+ */
+pub (crate) fn pause_group(msg : &ExecuteMsg) -> Option<PauseGroup> {
+
+    match msg {
+
+        ExecuteMsg::SetPaused { .. } => None,
+
+        ExecuteMsg::CreateItem { .. } |
+        ExecuteMsg::UpdateItem { .. } |
+        ExecuteMsg::RemoveItem { .. } => Some(PauseGroup::Items),
+
+        ExecuteMsg::MintItem { .. } |
+        ExecuteMsg::MintItemByName { .. } |
+        ExecuteMsg::SimpleMint { .. } |
+        ExecuteMsg::MintFor { .. } |
+        ExecuteMsg::PauseMinting { .. } |
+        ExecuteMsg::ResumeMinting { .. } |
+        ExecuteMsg::ClaimAuctionRefund { .. } |
+        ExecuteMsg::ClaimAuctionProceeds { .. } => Some(PauseGroup::Minting),
+
+        ExecuteMsg::TransferNft { .. } |
+        ExecuteMsg::SendNft { .. } |
+        ExecuteMsg::ReceiveNft(_) |
+        ExecuteMsg::BurnNft { .. } => Some(PauseGroup::Transfers),
+
+        ExecuteMsg::CreateSellOffer { .. } => Some(PauseGroup::SellOffers),
+
+        ExecuteMsg::CreateCollection { .. } |
+        ExecuteMsg::UpdateCollection { .. } |
+        ExecuteMsg::RemoveCollection { .. } |
+        ExecuteMsg::BlockCollectionAddresses { .. } |
+        ExecuteMsg::UnblockCollectionAddresses { .. } |
+        ExecuteMsg::AddToPhaseAllowlist { .. } |
+        ExecuteMsg::RemoveFromPhaseAllowlist { .. } |
+        ExecuteMsg::ReserveName { .. } |
+        ExecuteMsg::ReleaseName { .. } |
+        ExecuteMsg::PruneNameReservations { .. } |
+        ExecuteMsg::SetCollectionSigners { .. } |
+        ExecuteMsg::ProposeCollectionChange { .. } |
+        ExecuteMsg::ApproveCollectionChange { .. } |
+        ExecuteMsg::CancelCollectionChange { .. } |
+        ExecuteMsg::AuthorizeMinter { .. } |
+        ExecuteMsg::RevokeMinter { .. } |
+        ExecuteMsg::GrantCollectionRole { .. } |
+        ExecuteMsg::RevokeCollectionRole { .. } |
+        ExecuteMsg::AddPromoCodes { .. } |
+        ExecuteMsg::RemovePromoCode { .. } |
+        ExecuteMsg::SubmitForReview { .. } |
+        ExecuteMsg::ProposeCollectionOwner { .. } |
+        ExecuteMsg::CancelCollectionOwnerProposal { .. } |
+        ExecuteMsg::AcceptCollectionOwner { .. } => Some(PauseGroup::Collections),
+
+        // the admins block, moderate and configure during an incident too
+        ExecuteMsg::UpdateContractInfo { .. } |
+        ExecuteMsg::SetValidationLimits { .. } |
+        ExecuteMsg::RekeyCollections { .. } |
+        ExecuteMsg::BlockAddresses { .. } |
+        ExecuteMsg::UnblockAddresses { .. } |
+        ExecuteMsg::ApproveCollection { .. } |
+        ExecuteMsg::RejectCollection { .. } |
+        ExecuteMsg::VerifyCreator { .. } |
+        ExecuteMsg::UnverifyCreator { .. } |
+        ExecuteMsg::FeatureCollection { .. } |
+        ExecuteMsg::UnfeatureCollection { .. } |
+        ExecuteMsg::ModerateCollection { .. } |
+        ExecuteMsg::SetFeeTier { .. } |
+        ExecuteMsg::RemoveFeeTier { .. } |
+        ExecuteMsg::SetCreatorFees { .. } |
+        ExecuteMsg::RemoveCreatorFees { .. } => None,
+    }
+}
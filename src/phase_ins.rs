@@ -0,0 +1,72 @@
+use cosmwasm_std::{Deps, DepsMut, Response, MessageInfo, Addr, Empty};
+use crate::state::{CollectionRole, MintPhase};
+use crate::indexes::PHASE_ALLOWLISTS_STORE;
+use crate::error::ContractError;
+use crate::ins::{collection_id, common_response, STATUS_OK};
+use crate::query::internal_get_collection;
+use crate::checks::check_if_collection_role;
+
+
+fn allowlisted_phase(deps : Deps, owner : Addr, collection_name : String, collection_symbol : String,
+    phase : &str) -> Result<MintPhase, ContractError> {
+
+    let collection = internal_get_collection(deps, owner, collection_name, collection_symbol);
+
+    if collection.is_none() {
+        return Err(ContractError::CollectionNotFound { text: "Collection is NOT found!".to_string()});
+    }
+
+    let found = collection.unwrap().mint_phases.unwrap_or(vec![])
+    .into_iter()
+    .find(|p| p.name == phase);
+
+    if found.is_none() {
+        return Err(ContractError::InvalidMintPhases { text: format!("Mint phase {} is NOT found!", phase)});
+    }
+
+    Ok(found.unwrap())
+}
+
+
+/*
+The owner or a manager of a collection adds the wallets allowed
+to mint in a phase, a phase only checks its allowlist when it is
+set as allowlist_only
+ */
+pub fn add_to_phase_allowlist(deps : DepsMut, info : MessageInfo, owner : Addr,
+    collection_name : String, collection_symbol : String, phase : String, addresses : Vec<Addr>)
+-> Result<Response, ContractError> {
+
+    let _key = (owner.clone(), collection_id(collection_name.clone(), collection_symbol.clone()));
+
+    allowlisted_phase(deps.as_ref(), owner, collection_name, collection_symbol, &phase)?;
+
+    check_if_collection_role(deps.as_ref(), &_key, &info.sender, CollectionRole::Manager)?;
+
+    for a in addresses.iter() {
+        PHASE_ALLOWLISTS_STORE.save(deps.storage, (_key.clone(), phase.clone(), a.clone()),
+        &Empty {})?;
+    }
+
+    common_response(format!("{}-{}", _key.0, _key.1).as_str(), "add_to_phase_allowlist", STATUS_OK,
+    Some(format!("{} address(es) added to {}", addresses.len(), phase)), None)
+}
+
+
+pub fn remove_from_phase_allowlist(deps : DepsMut, info : MessageInfo, owner : Addr,
+    collection_name : String, collection_symbol : String, phase : String, addresses : Vec<Addr>)
+-> Result<Response, ContractError> {
+
+    let _key = (owner.clone(), collection_id(collection_name.clone(), collection_symbol.clone()));
+
+    allowlisted_phase(deps.as_ref(), owner, collection_name, collection_symbol, &phase)?;
+
+    check_if_collection_role(deps.as_ref(), &_key, &info.sender, CollectionRole::Manager)?;
+
+    for a in addresses.iter() {
+        PHASE_ALLOWLISTS_STORE.remove(deps.storage, (_key.clone(), phase.clone(), a.clone()));
+    }
+
+    common_response(format!("{}-{}", _key.0, _key.1).as_str(), "remove_from_phase_allowlist", STATUS_OK,
+    Some(format!("{} address(es) removed from {}", addresses.len(), phase)), None)
+}
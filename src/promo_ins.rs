@@ -0,0 +1,121 @@
+use cosmwasm_std::{DepsMut, Env, Response, MessageInfo, Coin};
+use crate::state::{Collection, PromoCode};
+use crate::indexes::PROMO_CODES_STORE;
+use crate::error::ContractError;
+use crate::ins::{collection_id, collection_key, common_response, STATUS_OK};
+use crate::utils::promo_code_hash;
+use crate::checks::*;
+
+
+pub fn add_promo_codes(deps : DepsMut, _env : Env, info : MessageInfo, 
+    collection_name : String, collection_symbol : String, codes : Vec<PromoCode>) 
+-> Result<Response, ContractError> {
+
+    check_if_action_needs_approval(deps.as_ref(), 
+    &(info.sender.clone(), collection_id(collection_name.clone(), collection_symbol.clone())), "Adding promo codes")?;
+
+    internal_add_promo_codes(deps, _env, info, collection_name, collection_symbol, codes)
+}
+
+
+/*
+The codes already added are refused rather than overwritten, 
+so their uses are never reset, a code is removed to be added again
+ */
+pub (crate) fn internal_add_promo_codes(deps : DepsMut, _env : Env, info : MessageInfo, 
+    collection_name : String, collection_symbol : String, codes : Vec<PromoCode>) 
+-> Result<Response, ContractError> {
+
+    let owner = info.clone().sender;
+
+    check_if_collection_exists(&deps, info, collection_name.clone(), 
+    collection_symbol.clone(), false)?;
+
+    let coll_id = collection_id(collection_name, collection_symbol);
+
+    for code in codes.iter() {
+
+        check_if_promo_code_valid(code)?;
+
+        let mut code = code.clone();
+
+        code.code_hash = code.code_hash.to_lowercase();
+
+        code.used = 0;
+
+        let _key = (owner.clone(), coll_id.clone(), code.code_hash.clone());
+
+        // also catches a code repeated in the same batch, as it is saved already
+        if PROMO_CODES_STORE.has(deps.storage, _key.clone()) {
+            return Err(ContractError::InvalidPromoCode { text: 
+                format!("Promo code {} is already added!", code.code_hash)});
+        }
+
+        PROMO_CODES_STORE.save(deps.storage, _key, &code)?;
+    }
+
+    common_response(format!("{}-{}", owner, coll_id).as_str(), "add_promo_codes", STATUS_OK, 
+    Some(format!("{} promo code(s) added", codes.len())), None)
+}
+
+
+pub fn remove_promo_code(deps : DepsMut, _env : Env, info : MessageInfo, 
+    collection_name : String, collection_symbol : String, code_hash : String) 
+-> Result<Response, ContractError> {
+
+    let owner = info.clone().sender;
+
+    let _key = (owner, collection_id(collection_name, collection_symbol), code_hash.to_lowercase());
+
+    if !PROMO_CODES_STORE.has(deps.storage, _key.clone()) {
+        return Err(ContractError::InvalidPromoCode { text: "Promo code is NOT found!".to_string()});
+    }
+
+    PROMO_CODES_STORE.remove(deps.storage, _key.clone());
+
+    common_response(format!("{}-{}={}", _key.0, _key.1, _key.2).as_str(), "remove_promo_code", 
+    STATUS_OK, None, None)
+}
+
+
+/*
+Hashes the plaintext code and checks it against the codes of the 
+collection, returns the discounted price and counts the code as used
+ */
+pub (crate) fn apply_promo_code(deps : DepsMut, _env : &Env, collection : &Collection, 
+    code : String, price : Coin) -> Result<Coin, ContractError> {
+
+    let (owner, coll_id) = collection_key(collection)?;
+
+    let _key = (owner, coll_id, promo_code_hash(&code));
+
+    let promo_code = PROMO_CODES_STORE.may_load(deps.storage, _key.clone())?;
+
+    if promo_code.is_none() {
+        return Err(ContractError::InvalidPromoCode { text: "Promo code is NOT found!".to_string()});
+    }
+
+    let mut promo_code = promo_code.unwrap();
+
+    if promo_code.expiry.is_some() && promo_code.expiry.unwrap() <= _env.block.time {
+        return Err(ContractError::PromoCodeExpired { text: "Promo code has expired!".to_string()});
+    }
+
+    if promo_code.max_uses.is_some() && promo_code.used >= promo_code.max_uses.unwrap() {
+        return Err(ContractError::PromoCodeUsedUp { text: 
+            format!("Promo code has been used {} time(s)!", promo_code.used)});
+    }
+
+    let discounted = promo_code.discount.apply(&price);
+
+    if discounted.is_none() {
+        return Err(ContractError::InvalidPromoCode { text: 
+            format!("Promo code is NOT applicable to price in {}", price.denom)});
+    }
+
+    promo_code.used += 1;
+
+    PROMO_CODES_STORE.save(deps.storage, _key, &promo_code)?;
+
+    Ok(discounted.unwrap())
+}
@@ -1,20 +1,47 @@
 use std::convert::TryInto;
 
 use crate::msg::{CollectionResponse, CollectionsResponse, ItemCountResponse, ItemsResponse, ItemResponse, 
-    CollectionsWithParamsResponse};
-use cosmwasm_std::{Deps, StdResult, Order, Addr};
-use crate::state::{Collection, Item, COLLECTION_STATUS_ACTIVATED};
-use crate::indexes::{collections_store, COLLECTION_ITEMS_STORE};
+    CollectionsWithParamsResponse, SimulateMintResponse, TreasuryPayout, FeeQuoteResponse, FeeScheduleResponse,
+    EffectiveFeesResponse, FeeTiersResponse, ReferralStatsResponse, ReferralLeaderboardResponse, PromoCodeResponse,
+    CurrentPriceResponse, AuctionStatusResponse, BondingCurveQuoteResponse, MintPhasesResponse, ActivePhaseResponse,
+    ModerationResponse, VerifiedCreatorResponse, FeaturedCollectionInfo, FeaturedCollectionsResponse, CollectionMintPause,
+    PendingOwnerResponse, CollectionMembersResponse,
+    DelegatedMintersResponse, CollectionSignersResponse, PauseStatusResponse,
+    BlockedAddressesResponse, NameAvailabilityResponse, PhaseAllowlistResponse};
+use cosmwasm_std::{Deps, Env, StdResult, StdError, Order, Addr, Coin, Uint128, Timestamp};
+use crate::state::{Collection, Item, FeaturedCollection, MintPause, CollectionMember, DelegatedMinter, ChangeProposal, PauseFlag, BlockedAddress, ValidationLimits, FeeType, FeeTier, ReferralStats, COLLECTION_STATUS_ACTIVATED, FEE_DISCOUNT_MAX,
+COLLECTION_STATUS_PENDING_REVIEW,
+PRICE_TYPE_STANDARD};
+use crate::indexes::{collections_store, COLLECTION_ITEMS_STORE, FEE_TIERS_STORE, CREATOR_FEES_STORE, REFERRALS_STORE,
+REFERRAL_RANKS_STORE, PROMO_CODES_STORE, AUCTION_STATES_STORE, AUCTION_PURCHASES_STORE, MINTED_COUNTS_STORE, PHASE_MINTS_STORE, MODERATIONS_STORE,
+VERIFIED_CREATORS_STORE, FEATURED_COLLECTIONS_STORE, FEATURED_POSITIONS_STORE, PENDING_OWNERS_STORE,
+COLLECTION_MEMBERS_STORE, DELEGATED_MINTERS_STORE, COLLECTION_SIGNERS_STORE, CHANGE_PROPOSALS_STORE,
+PAUSE_FLAGS_STORE, MINT_PAUSES_STORE, BLOCKLIST_STORE, COLLECTION_BLOCKLISTS_STORE, VALIDATION_LIMITS_STORE,
+NAME_RESERVATIONS_STORE, PHASE_ALLOWLISTS_STORE};
+use crate::error::ContractError;
+use crate::checks::{check_if_collection_mintable, check_if_eligible_for_phase, check_if_collection_not_suspended,
+is_collection_suspended, check_if_minting_not_paused, check_if_not_blocked_by_collection,
+check_if_mint_not_throttled, check_if_treasuries_not_blocked};
+use crate::auction_ins::{is_auction_ended, clearing_price, refund_of};
 use cw_storage_plus::Bound;
-use crate::ins::collection_id;
+use crate::ins::{collection_id, collection_key, name_key};
+use pix0_contract_common::funcs::get_contract_info;
 
 pub const DEFAULT_LIMIT : u32 = 10;
 
 pub const MAX_LIMIT : u32 = 20;
 
-pub fn get_collection(deps: Deps, owner : Addr, name : String, symbol : String  ) -> StdResult<CollectionResponse>{
+// the collection is returned with its effective status at the block time, as in the list queries
+pub fn get_collection(deps: Deps, _env : Env, owner : Addr, name : String, symbol : String  ) -> StdResult<CollectionResponse>{
 
-    Ok (CollectionResponse { collection : internal_get_collection(deps, owner, name, symbol) })
+    let mint_pause = internal_get_mint_pause(deps, 
+    &(owner.clone(), collection_id(name.clone(), symbol.clone())), _env.block.time)?;
+
+    let collection = internal_get_collection(deps, owner.clone(), name, symbol)
+    .map(|c| Collection { status : c.effective_status(_env.block.time), ..c });
+
+    Ok (CollectionResponse { verified : internal_is_verified(deps, &Some(owner)),
+        collection : collection, mint_pause : mint_pause })
 }
 
 
@@ -41,7 +68,16 @@ pub (crate) fn internal_get_collection(deps: Deps, owner : Addr, name : String,
 }
 
 
-pub fn get_collections(deps : Deps , 
+// the suspended collections are hidden from the listings
+fn is_listed(deps : Deps, collection : &Collection) -> bool {
+
+    collection.owner.clone().map(|o| !is_collection_suspended(deps, 
+        &(o, collection_id(collection.name.clone(), collection.symbol.clone()))))
+    .unwrap_or(false)
+}
+
+
+pub fn get_collections(deps : Deps , _env : Env,
 owner : Addr,     
 start_after: Option<String>, limit: Option<u32>) 
 ->StdResult<CollectionsResponse> {
@@ -57,19 +93,13 @@ start_after: Option<String>, limit: Option<u32>)
     .idx.collections
     .prefix(owner)
     .range(deps.storage, start, None, Order::Ascending)
+    .filter(|col| col.is_err() || is_listed(deps, &col.as_ref().unwrap().1))
     .take(limit)
     .map(|col| {
         
         let (_k, c) = col?;
 
-        Ok(
-            Collection { owner : c.owner, name : c.name, 
-            treasuries : c.treasuries,
-            attributes : c.attributes,
-            prices : c.prices, status: c.status, royalties: c.royalties,
-            symbol : c.symbol, description: c.description, 
-            date_created: c.date_created, date_updated: c.date_updated }
-        )
+        Ok(c)
     }).collect();
 
     let mut collections : Vec<Collection> = vec![];
@@ -79,14 +109,17 @@ start_after: Option<String>, limit: Option<u32>)
         collections.sort_by(|a, b| b.date_updated.cmp(&a.date_updated));
     }
 
+    let mint_pauses = internal_get_mint_pauses(deps, &collections, _env.block.time)?;
+
     Ok(CollectionsResponse {
         collections: collections,
+        mint_pauses : Some(mint_pauses),
     })
 }
 
 
 
-pub fn get_all_collections(deps : Deps, start_after: Option<String>, limit: Option<u32>) 
+pub fn get_all_collections(deps : Deps, _env : Env, start_after: Option<String>, limit: Option<u32>) 
     ->StdResult<CollectionsResponse> {
         
     
@@ -99,19 +132,13 @@ pub fn get_all_collections(deps : Deps, start_after: Option<String>, limit: Opti
    
     collections_store().idx.collections
     .range(deps.storage, start, None, Order::Ascending)
+    .filter(|col| col.is_err() || is_listed(deps, &col.as_ref().unwrap().1))
     .take(limit)
     .map(|col| {
         
         let (_k, c) = col?;
 
-        Ok(
-            Collection { owner : c.owner, name : c.name, 
-                treasuries : c.treasuries,
-                attributes : c.attributes, royalties: c.royalties,
-                prices : c.prices, status: c.status, 
-                symbol : c.symbol, description: c.description, 
-                date_created: c.date_created, date_updated: c.date_updated }
-        )
+        Ok(c)
     }).collect();
 
     let mut collections : Vec<Collection> = vec![];
@@ -121,18 +148,26 @@ pub fn get_all_collections(deps : Deps, start_after: Option<String>, limit: Opti
         collections.sort_by(|a, b| b.date_updated.cmp(&a.date_updated));
     }
     
+    let mint_pauses = internal_get_mint_pauses(deps, &collections, _env.block.time)?;
+
     Ok(CollectionsResponse {
-        collections: collections
+        collections: collections,
+        mint_pauses : Some(mint_pauses),
     })
 }
 
 
-pub fn get_active_collections(deps : Deps,
+pub fn get_active_collections(deps : Deps, _env : Env,
     keyword : Option<String>,  
     category : Option<String>, 
-    start: Option<u32>, limit: Option<u32>) 
+    start: Option<u32>, limit: Option<u32>, 
+    verified_only : Option<bool>) 
     ->StdResult<CollectionsWithParamsResponse> {    
    
+    let verified_only = verified_only.unwrap_or(false);
+
+    // the collections are filtered while iterating, 
+    // only the matching ones are kept for sorting and paging
     let all_colls : StdResult<Vec<Collection>> = 
     
     collections_store().idx.collections
@@ -140,15 +175,15 @@ pub fn get_active_collections(deps : Deps,
     .map(|col| {
         
         let (_k, c) = col?;
-        Ok (
-            Collection { owner : c.owner, name : c.name, 
-            treasuries : c.treasuries,
-            attributes : c.attributes, royalties: c.royalties,
-            prices : c.prices, status: c.status, 
-            symbol : c.symbol, description: c.description, 
-            date_created: c.date_created, date_updated: c.date_updated }
-        )
-    }).collect();
+
+        let status = c.effective_status(_env.block.time);
+
+        Ok(Collection { status : status, ..c })
+    })
+    .filter(|col| col.is_err() || is_active_match(col.as_ref().unwrap(), &keyword, &category))
+    .filter(|col| col.is_err() || is_listed(deps, col.as_ref().unwrap()))
+    .filter(|col| col.is_err() || !verified_only || internal_is_verified(deps, &col.as_ref().unwrap().owner))
+    .collect();
 
 
     if all_colls.is_err() {
@@ -161,14 +196,24 @@ pub fn get_active_collections(deps : Deps,
 
     all_colls.sort_by(|a, b| b.date_created.cmp(&a.date_created));
     
-    
-    let res : (Vec<Collection>,usize) = filter_collection_result(all_colls, keyword, category, start, limit);
+    let total = all_colls.len();
+
+    let collections = all_colls.into_iter()
+    .skip(start.unwrap_or(0) as usize)
+    .take(limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize)
+    .collect::<Vec<Collection>>();
+
+    let verified_owners = internal_get_verified_owners(deps, &collections);
+
+    let mint_pauses = internal_get_mint_pauses(deps, &collections, _env.block.time)?;
 
     Ok(CollectionsWithParamsResponse {
-        collections: res.0,
-        total : Some(res.1.try_into().unwrap_or(0)),
+        collections: collections,
+        total : Some(total.try_into().unwrap_or(0)),
         start : start,
-        limit : limit
+        limit : limit,
+        verified_owners : Some(verified_owners),
+        mint_pauses : Some(mint_pauses),
     })
     
 }
@@ -195,60 +240,14 @@ fn contains_keyword(collection : Collection, keyword : String) -> bool {
 }
 
 
-fn filter_collection_result(all_colls : Vec<Collection>, 
-    keyword : Option<String>, 
-    category : Option<String>, 
-    start : Option<u32>,
-    limit: Option<u32>) -> (Vec<Collection>,usize){
-
-    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
-
-    let skip = start.unwrap_or(0) as usize ;
-    
-    let res = filter_collection_result_all(all_colls, keyword, category);
-
-    (res.clone()
-    .into_iter()
-    .skip(skip)
-    .take(limit)
-    .collect::<Vec<Collection>>(), res.len())
-}
-
-fn filter_collection_result_all(all_colls : Vec<Collection>, 
-    keyword : Option<String>, 
-    category : Option<String>) -> Vec<Collection>{
-
-   
-    if keyword.is_some() && category.is_some(){
-
-        let kw = keyword.unwrap();
-
-        all_colls.into_iter().filter(|c| 
-            c.status == Some(COLLECTION_STATUS_ACTIVATED) 
-            && contains_keyword(c.clone(), kw.clone()) 
-            && is_category_of(c.clone(), category.clone().unwrap())
-            )
-            .collect::<Vec<Collection>>()
-    }
-
-    else if keyword.is_some(){
-
-        let kw = keyword.unwrap();
-
-        all_colls.into_iter().filter(|c| 
-            c.status == Some(COLLECTION_STATUS_ACTIVATED) 
-            && contains_keyword(c.clone(), kw.clone()) )
-            .collect::<Vec<Collection>>()
-    }
-    else {
-
-        all_colls.into_iter().filter(|c| c.status 
-            == Some(COLLECTION_STATUS_ACTIVATED))
-          .collect::<Vec<Collection>>()
-            
-    }
-
+fn is_active_match(collection : &Collection, 
+    keyword : &Option<String>, 
+    category : &Option<String>) -> bool {
 
+    collection.status == Some(COLLECTION_STATUS_ACTIVATED) 
+    && (keyword.is_none() || contains_keyword(collection.clone(), keyword.clone().unwrap())) 
+    // the category only narrows down a keyword search
+    && (keyword.is_none() || category.is_none() || is_category_of(collection.clone(), category.clone().unwrap()))
 }
 
 
@@ -400,3 +399,880 @@ pub fn get_item(deps : Deps ,
 }
 
 
+
+
+
+pub (crate) fn internal_get_contract_fee(deps : Deps, fee_name : &str) -> Option<Coin> {
+
+    let info = get_contract_info(deps).ok()?;
+
+    info.contract_info?.fees?
+    .into_iter()
+    .find(|f| f.name == fee_name)
+    .map(|f| f.value)
+}
+
+
+fn multiply_coin(coin : &Coin, quantity : u32) -> Result<Coin, ContractError> {
+
+    let amount = coin.amount.checked_mul(Uint128::from(quantity))
+    .map_err(|e| StdError::from(e))?;
+
+    Ok(Coin { amount : amount, denom : coin.denom.clone() })
+}
+
+
+pub (crate) fn add_to_coins(coins : &mut Vec<Coin>, coin : Coin) -> Result<(), ContractError> {
+
+    if let Some(c) = coins.iter_mut().find(|c| c.denom == coin.denom) {
+        c.amount = c.amount.checked_add(coin.amount).map_err(|e| StdError::from(e))?;
+    }
+    else {
+        coins.push(coin);
+    }
+
+    Ok(())
+}
+
+
+/*
+Runs the same checks as minting without writing to the storage,
+the response is filled up as far as the checks go
+ */
+fn internal_simulate_mint(deps : Deps, _env : &Env, response : &mut SimulateMintResponse,
+    owner : Addr, collection_name : String, collection_symbol : String, 
+    price_type : Option<u8>, sender : Addr) -> Result<(), ContractError> {
+
+    let collection = internal_get_collection(deps, owner.clone(), 
+    collection_name.clone(), collection_symbol.clone());
+
+    if collection.is_none() {
+        return Err(ContractError::CollectionNotFound { text: "Collection is NOT found!".to_string()});
+    }
+
+    let collection = collection.unwrap();
+
+    check_if_collection_not_suspended(deps, &collection)?;
+
+    check_if_not_blocked_by_collection(deps, &collection, &sender)?;
+
+    check_if_treasuries_not_blocked(deps, &collection, &collection.treasuries)?;
+
+    check_if_minting_not_paused(deps, &collection, _env.block.time)?;
+
+    check_if_mint_not_throttled(deps, _env, &collection, &sender)?;
+
+    let minted = internal_get_minted_count(deps, owner.clone(), collection_name.clone(), 
+    collection_symbol.clone());
+
+    // with a bonding curve each mint of the quantity has its own price
+    for i in 0..response.quantity {
+
+        let price = check_if_collection_mintable(&collection, price_type, _env.block.time, 
+        minted + i as u64)?;
+
+        if response.price.is_none() {
+            response.price = Some(price.clone());
+        }
+
+        for (wallet, amount) in collection.treasuries_to_payments(price.clone())? {
+
+            if let Some(p) = response.payouts.iter_mut()
+            .find(|p| p.wallet == wallet && p.amount.denom == amount.denom) {
+                p.amount.amount = p.amount.amount.checked_add(amount.amount).map_err(|e| StdError::from(e))?;
+            }
+            else {
+                response.payouts.push(TreasuryPayout { wallet : wallet, amount : amount });
+            }
+        }
+
+        add_to_coins(&mut response.total_required, price)?;
+    }
+
+    // checked after the price as in mint_item, so the same error is reported
+    let phase = collection.active_phase(_env.block.time);
+
+    if phase.is_some() {
+        check_if_eligible_for_phase(deps, &collection, &phase.unwrap(), &sender, response.quantity)?;
+    }
+
+    if response.minting_fee.is_some() {
+        add_to_coins(&mut response.total_required, 
+        multiply_coin(&response.minting_fee.clone().unwrap(), response.quantity)?)?;
+    }
+
+    let items_count = internal_get_items_count(deps, owner, collection_name, collection_symbol);
+
+    if items_count < response.quantity as usize {
+        return Err(ContractError::FailedToFindNft { text : 
+            format!("Only {} item(s) left for minting, requested :{}", items_count, response.quantity)});
+    }
+
+    for required in response.total_required.iter() {
+
+        let balance = deps.querier.query_balance(sender.clone(), required.denom.clone())?;
+
+        if balance.amount < required.amount {
+            return Err(ContractError::InsufficientFund {
+                text: format!("Insufficient fund: balance:{}, required: {}!", balance, required)});
+        }
+    }
+
+    Ok(())
+}
+
+
+pub fn simulate_mint(deps : Deps, _env : Env,
+    owner : Addr, collection_name : String,  
+    collection_symbol : String, price_type : Option<u8>,
+    sender : Addr, quantity : Option<u32>) -> StdResult<SimulateMintResponse> {
+
+    let mut response = SimulateMintResponse {
+        quantity : quantity.unwrap_or(1).max(1),
+        price : None,
+        minting_fee : internal_get_effective_fee(deps, &owner, &FeeType::NftMinting),
+        total_required : vec![],
+        payouts : vec![],
+        error : None, 
+    };
+
+    let res = internal_simulate_mint(deps, &_env, &mut response, owner, 
+    collection_name, collection_symbol, price_type, sender);
+
+    if res.is_err() {
+        response.error = Some(res.err().unwrap().to_string());
+    }
+
+    Ok(response)
+}
+
+
+
+fn to_fee_quote(operation : FeeType, fee : Option<Coin>, quantity : Option<u32>) -> StdResult<FeeQuoteResponse> {
+
+    let quantity = quantity.unwrap_or(1).max(1);
+
+    let mut total : Option<Coin> = None;
+
+    if fee.is_some() {
+
+        let f = fee.clone().unwrap();
+        
+        total = Some(Coin { amount : f.amount.checked_mul(Uint128::from(quantity))?, denom : f.denom });
+    }
+
+    Ok(FeeQuoteResponse {
+        fee_name : operation.name().to_string(),
+        operation : operation,
+        quantity : quantity,
+        fee : fee,
+        total : total,
+    })
+}
+
+
+pub fn get_fee_quote(deps : Deps, operation : FeeType, quantity : Option<u32>) -> StdResult<FeeQuoteResponse> {
+
+    let fee = internal_get_contract_fee(deps, operation.name());
+
+    to_fee_quote(operation, fee, quantity)
+}
+
+
+pub fn get_fee_schedule(deps : Deps) -> StdResult<FeeScheduleResponse> {
+
+    let fees : StdResult<Vec<FeeQuoteResponse>> = FeeType::all()
+    .into_iter()
+    .map(|f| get_fee_quote(deps, f, None))
+    .collect();
+
+    Ok(FeeScheduleResponse { fees : fees? })
+}
+
+
+pub (crate) fn internal_get_contract_admins(deps : Deps) -> Vec<Addr> {
+
+    let info = get_contract_info(deps);
+
+    match info {
+
+        Ok(i) => i.contract_info.map(|c| c.allowed_admins).unwrap_or(vec![]),
+
+        Err(_) => vec![],
+    }
+}
+
+
+pub (crate) fn internal_get_contract_treasuries(deps : Deps) -> Vec<Addr> {
+
+    let info = get_contract_info(deps);
+
+    match info {
+
+        Ok(i) => i.contract_info.map(|c| c.treasuries).unwrap_or(vec![]),
+
+        Err(_) => vec![],
+    }
+}
+
+
+/*
+The fee charged to the creator, the override of the creator 
+is used if there is any, otherwise the discount of the creator's 
+tier is applied to the contract fee
+ */
+pub (crate) fn internal_get_effective_fee(deps : Deps, creator : &Addr, fee_type : &FeeType) -> Option<Coin> {
+
+    let fee = internal_get_contract_fee(deps, fee_type.name());
+
+    let creator_fees = CREATOR_FEES_STORE.may_load(deps.storage, creator.clone()).unwrap_or(None);
+
+    if creator_fees.is_none() {
+        return fee;
+    }
+
+    let creator_fees = creator_fees.unwrap();
+
+    let over = creator_fees.overrides.unwrap_or(vec![])
+    .into_iter()
+    .find(|o| o.fee_type == *fee_type);
+
+    if over.is_some() {
+        return Some(over.unwrap().value);
+    }
+
+    let tier = creator_fees.tier
+    .and_then(|t| FEE_TIERS_STORE.may_load(deps.storage, t).unwrap_or(None));
+
+    let discount = tier.and_then(|t| t.discounts.into_iter().find(|d| d.fee_type == *fee_type));
+
+    if discount.is_some() && fee.is_some() {
+
+        let pct = discount.unwrap().percentage.min(FEE_DISCOUNT_MAX);
+        let f = fee.unwrap();
+
+        return Some(Coin { amount : f.amount.multiply_ratio((FEE_DISCOUNT_MAX - pct) as u128, 
+            FEE_DISCOUNT_MAX as u128), denom : f.denom });
+    }
+
+    fee
+}
+
+
+pub fn get_effective_fees(deps : Deps, address : Addr) -> StdResult<EffectiveFeesResponse> {
+
+    let fees : StdResult<Vec<FeeQuoteResponse>> = FeeType::all()
+    .into_iter()
+    .map(|f| {
+        let fee = internal_get_effective_fee(deps, &address, &f);
+        to_fee_quote(f, fee, None)
+    })
+    .collect();
+
+    let creator_fees = CREATOR_FEES_STORE.may_load(deps.storage, address.clone())?;
+
+    Ok(EffectiveFeesResponse { 
+        address : address,
+        tier : creator_fees.and_then(|c| c.tier),
+        fees : fees? 
+    })
+}
+
+
+pub fn get_fee_tiers(deps : Deps) -> StdResult<FeeTiersResponse> {
+
+    let tiers : StdResult<Vec<FeeTier>> = FEE_TIERS_STORE
+    .range(deps.storage, None, None, Order::Ascending)
+    .map(|t| {
+        let (_k, t) = t?;
+        Ok(t)
+    }).collect();
+
+    Ok(FeeTiersResponse { tiers : tiers? })
+}
+
+
+
+pub fn get_referral_stats(deps : Deps, referrer : Addr) -> StdResult<ReferralStatsResponse> {
+
+    Ok(ReferralStatsResponse { stats : REFERRALS_STORE.may_load(deps.storage, referrer)? })
+}
+
+
+/*
+Referrers are ranked by their number of referred mints, 
+the next page starts after the last referrer of the previous page
+ */
+pub fn get_referral_leaderboard(deps : Deps, start_after : Option<Addr>, limit : Option<u32>) 
+-> StdResult<ReferralLeaderboardResponse> {
+
+    let take = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let mut max = None;
+
+    if start_after.is_some() {
+
+        let after = start_after.clone().unwrap();
+
+        let mints = REFERRALS_STORE.may_load(deps.storage, after.clone())?.map(|s| s.mints).unwrap_or(0);
+
+        max = Some(Bound::exclusive((mints, after)));
+    }
+
+    let referrers : StdResult<Vec<ReferralStats>> = REFERRAL_RANKS_STORE
+    .keys(deps.storage, None, max, Order::Descending)
+    .take(take)
+    .map(|k| {
+        let (_mints, referrer) = k?;
+        REFERRALS_STORE.load(deps.storage, referrer)
+    }).collect();
+
+    Ok(ReferralLeaderboardResponse {
+        referrers : referrers?,
+        start_after : start_after,
+        limit : limit,
+    })
+}
+
+
+
+pub fn get_promo_code(deps : Deps, _env : Env, owner : Addr, collection_name : String, 
+    collection_symbol : String, code_hash : String) -> StdResult<PromoCodeResponse> {
+
+    let promo_code = PROMO_CODES_STORE.may_load(deps.storage, (owner, 
+    collection_id(collection_name, collection_symbol), code_hash.to_lowercase()))?;
+
+    let expired = promo_code.clone()
+    .and_then(|p| p.expiry)
+    .map(|e| e <= _env.block.time)
+    .unwrap_or(false);
+
+    let remaining_uses = promo_code.clone()
+    .and_then(|p| p.max_uses.map(|m| m.saturating_sub(p.used)));
+
+    Ok(PromoCodeResponse {
+        promo_code : promo_code,
+        expired : expired,
+        remaining_uses : remaining_uses,
+    })
+}
+
+
+
+pub fn get_current_price(deps : Deps, _env : Env, owner : Addr, collection_name : String, 
+    collection_symbol : String, price_type : Option<u8>) -> StdResult<CurrentPriceResponse> {
+
+    let collection = internal_get_collection(deps, owner.clone(), collection_name, collection_symbol);
+
+    if collection.is_none() {
+        return Ok(CurrentPriceResponse { price : None, auction_active : false });
+    }
+
+    let collection = collection.unwrap();
+
+    if collection.mint_phases.is_some() {
+
+        return Ok(CurrentPriceResponse { 
+            price : collection.active_phase(_env.block.time).map(|p| p.price), 
+            auction_active : false,
+        });
+    }
+
+    if collection.bonding_curve.is_some() {
+
+        let minted = internal_get_minted_count(deps, owner, 
+        collection.name.clone(), collection.symbol.clone());
+
+        return Ok(CurrentPriceResponse { 
+            price : collection.bonding_curve.unwrap().price_at(minted).ok(), 
+            auction_active : false,
+        });
+    }
+
+    if collection.dutch_auction.is_some() {
+
+        let auction = collection.dutch_auction.unwrap();
+
+        let time = _env.block.time;
+
+        return Ok(CurrentPriceResponse { 
+            price : Some(auction.price_at(time)), 
+            auction_active : time >= auction.start_time && time < auction.end_time,
+        });
+    }
+
+    Ok(CurrentPriceResponse { 
+        price : collection.price_by_type(price_type.unwrap_or(PRICE_TYPE_STANDARD)), 
+        auction_active : false,
+    })
+}
+
+
+pub fn get_auction_status(deps : Deps, _env : Env, owner : Addr, collection_name : String, 
+    collection_symbol : String, buyer : Option<Addr>) -> StdResult<AuctionStatusResponse> {
+
+    let mut response = AuctionStatusResponse { auction : None, state : None, current_price : None, 
+        ended : false, clearing_price : None, purchase : None, refund : None };
+
+    let collection = internal_get_collection(deps, owner.clone(), collection_name.clone(), 
+    collection_symbol.clone());
+
+    if collection.is_none() || collection.clone().unwrap().dutch_auction.is_none() {
+        return Ok(response);
+    }
+
+    let collection = collection.unwrap();
+
+    let auction = collection.dutch_auction.clone().unwrap();
+
+    let _key = (owner, collection_id(collection_name, collection_symbol));
+
+    response.state = AUCTION_STATES_STORE.may_load(deps.storage, _key.clone())?;
+
+    response.current_price = Some(auction.price_at(_env.block.time));
+
+    response.ended = is_auction_ended(deps, &_env, &_key, &collection, &auction);
+
+    if response.ended {
+        response.clearing_price = Some(clearing_price(&response.state, &auction));
+    }
+
+    if buyer.is_some() {
+
+        response.purchase = AUCTION_PURCHASES_STORE.may_load(deps.storage, 
+        (_key.0, _key.1, buyer.unwrap()))?;
+
+        if response.purchase.is_some() && response.clearing_price.is_some() {
+
+            let purchase = response.purchase.clone().unwrap();
+
+            if !purchase.refund_claimed {
+                response.refund = Some(Coin { 
+                    amount : refund_of(&purchase, &response.clearing_price.clone().unwrap()), 
+                    denom : auction.floor_price.denom.clone() });
+            }
+        }
+    }
+
+    response.auction = Some(auction);
+
+    Ok(response)
+}
+
+
+pub fn internal_get_minted_count(deps : Deps, owner : Addr, collection_name : String, 
+    collection_symbol : String) -> u64 {
+
+    let _key = (owner, collection_id(collection_name, collection_symbol));
+
+    MINTED_COUNTS_STORE.may_load(deps.storage, _key).unwrap_or(None).unwrap_or(0)
+}
+
+
+pub fn get_bonding_curve_quote(deps : Deps, owner : Addr, collection_name : String, 
+    collection_symbol : String, quantity : Option<u32>) -> StdResult<BondingCurveQuoteResponse> {
+
+    let minted = internal_get_minted_count(deps, owner.clone(), collection_name.clone(), 
+    collection_symbol.clone());
+
+    let mut response = BondingCurveQuoteResponse { curve : None, minted : minted, 
+        prices : vec![], total : None, error : None };
+
+    let collection = internal_get_collection(deps, owner, collection_name, collection_symbol);
+
+    if collection.is_none() || collection.clone().unwrap().bonding_curve.is_none() {
+        return Ok(response);
+    }
+
+    let curve = collection.unwrap().bonding_curve.unwrap();
+
+    let mut total = Coin { amount : Uint128::zero(), denom : curve.base_price.denom.clone() };
+
+    for i in 0..quantity.unwrap_or(1).max(1) {
+
+        let price = curve.price_at(minted + i as u64)
+        .and_then(|p| total.amount.checked_add(p.amount).map(|t| (p, t)));
+
+        match price {
+
+            Ok((p, t)) => {
+                total.amount = t;
+                response.prices.push(p);
+            },
+
+            Err(e) => {
+                response.error = Some(format!("Bonding curve price overflows at supply {} : {}!", 
+                minted + i as u64, e));
+                break;
+            }
+        }
+    }
+
+    if response.error.is_none() {
+        response.total = Some(total);
+    }
+
+    response.curve = Some(curve);
+
+    Ok(response)
+}
+
+
+pub fn get_mint_phases(deps : Deps, owner : Addr, collection_name : String, 
+    collection_symbol : String) -> StdResult<MintPhasesResponse> {
+
+    let collection = internal_get_collection(deps, owner, collection_name, collection_symbol);
+
+    let mut phases = collection.and_then(|c| c.mint_phases).unwrap_or(vec![]);
+
+    phases.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+
+    Ok(MintPhasesResponse { phases : phases })
+}
+
+
+pub fn get_active_phase(deps : Deps, _env : Env, owner : Addr, collection_name : String, 
+    collection_symbol : String, wallet : Option<Addr>) -> StdResult<ActivePhaseResponse> {
+
+    let mut response = ActivePhaseResponse { phase : None, minted_by_wallet : None, eligible : None };
+
+    let collection = internal_get_collection(deps, owner.clone(), collection_name.clone(), 
+    collection_symbol.clone());
+
+    if collection.is_none() {
+        return Ok(response);
+    }
+
+    let collection = collection.unwrap();
+
+    response.phase = collection.active_phase(_env.block.time);
+
+    if response.phase.is_some() && wallet.is_some() {
+
+        let phase = response.phase.clone().unwrap();
+
+        let wallet = wallet.unwrap();
+
+        let _key = (owner, collection_id(collection_name, collection_symbol), wallet.clone());
+
+        response.minted_by_wallet = Some(PHASE_MINTS_STORE.may_load(deps.storage, _key)?
+        .unwrap_or(vec![])
+        .into_iter()
+        .find(|m| m.phase == phase.name)
+        .map(|m| m.count)
+        .unwrap_or(0));
+
+        response.eligible = Some(check_if_eligible_for_phase(deps, &collection, &phase, &wallet, 1).is_ok());
+    }
+
+    Ok(response)
+}
+
+
+/*
+The collections pending review, the earliest 
+submitted first, for the contract admins
+ */
+pub fn get_review_queue(deps : Deps, _env : Env, start : Option<u32>, limit : Option<u32>) 
+-> StdResult<CollectionsWithParamsResponse> {
+
+    let pending = collections_store().idx.statuses.prefix(COLLECTION_STATUS_PENDING_REVIEW);
+
+    let total = pending.keys(deps.storage, None, None, Order::Ascending).count();
+
+    let collections : StdResult<Vec<Collection>> = pending
+    .range(deps.storage, None, None, Order::Ascending)
+    .skip(start.unwrap_or(0) as usize)
+    .take(limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize)
+    .map(|col| {
+        let (_k, c) = col?;
+        Ok(c)
+    }).collect();
+
+    let collections = collections?;
+
+    let verified_owners = internal_get_verified_owners(deps, &collections);
+
+    let mint_pauses = internal_get_mint_pauses(deps, &collections, _env.block.time)?;
+
+    Ok(CollectionsWithParamsResponse {
+        collections : collections,
+        total : Some(total.try_into().unwrap_or(0)),
+        start : start,
+        limit : limit,
+        verified_owners : Some(verified_owners),
+        mint_pauses : Some(mint_pauses),
+    })
+}
+
+
+pub fn get_moderation(deps : Deps, owner : Addr, collection_name : String, 
+    collection_symbol : String) -> StdResult<ModerationResponse> {
+
+    let _key = (owner, collection_id(collection_name, collection_symbol));
+
+    Ok(ModerationResponse { moderation : MODERATIONS_STORE.may_load(deps.storage, _key)? })
+}
+
+
+pub (crate) fn internal_is_verified(deps : Deps, creator : &Option<Addr>) -> bool {
+
+    creator.is_some() && VERIFIED_CREATORS_STORE.has(deps.storage, creator.clone().unwrap())
+}
+
+
+fn internal_get_verified_owners(deps : Deps, collections : &Vec<Collection>) -> Vec<Addr> {
+
+    let mut owners : Vec<Addr> = vec![];
+
+    for c in collections.iter() {
+
+        if internal_is_verified(deps, &c.owner) && !owners.contains(&c.owner.clone().unwrap()) {
+            owners.push(c.owner.clone().unwrap());
+        }
+    }
+
+    owners
+}
+
+
+/*
+The minting pause of a collection in effect at the time, 
+a pause past its resume_at is no longer reported
+ */
+pub (crate) fn internal_get_mint_pause(deps : Deps, _key : &(Addr, String), time : Timestamp) 
+-> StdResult<Option<MintPause>> {
+
+    let pause = MINT_PAUSES_STORE.may_load(deps.storage, _key.clone())?;
+
+    Ok(pause.filter(|p| p.is_active_at(time)))
+}
+
+
+fn internal_get_mint_pauses(deps : Deps, collections : &Vec<Collection>, time : Timestamp) 
+-> StdResult<Vec<CollectionMintPause>> {
+
+    let mut pauses : Vec<CollectionMintPause> = vec![];
+
+    for c in collections.iter() {
+
+        // the listed collections always have an owner
+        if let Ok(_key) = collection_key(c) {
+
+            if let Some(p) = internal_get_mint_pause(deps, &_key, time)? {
+
+                pauses.push(CollectionMintPause { owner : _key.0, name : c.name.clone(), 
+                    symbol : c.symbol.clone(), mint_pause : p });
+            }
+        }
+    }
+
+    Ok(pauses)
+}
+
+
+pub fn get_verified_creator(deps : Deps, creator : Addr) -> StdResult<VerifiedCreatorResponse> {
+
+    Ok(VerifiedCreatorResponse { verified_creator : VERIFIED_CREATORS_STORE.may_load(deps.storage, creator)? })
+}
+
+
+/*
+The featured collections by position, leaving out the 
+expired ones and the suspended collections
+ */
+pub fn get_featured_collections(deps : Deps, _env : Env, start : Option<u32>, limit : Option<u32>) 
+-> StdResult<FeaturedCollectionsResponse> {
+
+    let featured : StdResult<Vec<FeaturedCollection>> = FEATURED_POSITIONS_STORE
+    .keys(deps.storage, None, None, Order::Ascending)
+    .map(|k| {
+        let (_position, owner, coll_id) = k?;
+        FEATURED_COLLECTIONS_STORE.load(deps.storage, (owner, coll_id))
+    })
+    .filter(|f| f.is_err() || !f.as_ref().unwrap().is_expired(_env.block.time))
+    .filter(|f| f.is_err() || !is_collection_suspended(deps, &(f.as_ref().unwrap().owner.clone(), 
+        collection_id(f.as_ref().unwrap().collection_name.clone(), f.as_ref().unwrap().collection_symbol.clone()))))
+    .skip(start.unwrap_or(0) as usize)
+    .take(limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize)
+    .collect();
+
+    let featured : StdResult<Vec<FeaturedCollectionInfo>> = featured?.into_iter().map(|f| Ok(FeaturedCollectionInfo {
+        collection : internal_get_collection(deps, f.owner.clone(), f.collection_name.clone(), 
+        f.collection_symbol.clone()),
+        verified : internal_is_verified(deps, &Some(f.owner.clone())),
+        mint_pause : internal_get_mint_pause(deps, &(f.owner.clone(), 
+        collection_id(f.collection_name.clone(), f.collection_symbol.clone())), _env.block.time)?,
+        featured : f,
+    })).collect();
+
+    Ok(FeaturedCollectionsResponse { featured : featured? })
+}
+
+
+pub fn get_pending_owner(deps : Deps, owner : Addr, collection_name : String, 
+    collection_symbol : String) -> StdResult<PendingOwnerResponse> {
+
+    let _key = (owner, collection_id(collection_name, collection_symbol));
+
+    Ok(PendingOwnerResponse { pending_owner : PENDING_OWNERS_STORE.may_load(deps.storage, _key)? })
+}
+
+
+pub fn get_collection_members(deps : Deps, owner : Addr, collection_name : String, 
+    collection_symbol : String) -> StdResult<CollectionMembersResponse> {
+
+    let members : StdResult<Vec<CollectionMember>> = COLLECTION_MEMBERS_STORE
+    .prefix((owner, collection_id(collection_name, collection_symbol)))
+    .range(deps.storage, None, None, Order::Ascending)
+    .map(|m| {
+        let (address, roles) = m?;
+        Ok(CollectionMember { address : address, roles : roles })
+    }).collect();
+
+    Ok(CollectionMembersResponse { members : members? })
+}
+
+
+pub fn get_delegated_minters(deps : Deps, owner : Addr, collection_name : String, 
+    collection_symbol : String) -> StdResult<DelegatedMintersResponse> {
+
+    let minters : StdResult<Vec<DelegatedMinter>> = DELEGATED_MINTERS_STORE
+    .prefix((owner, collection_id(collection_name, collection_symbol)))
+    .range(deps.storage, None, None, Order::Ascending)
+    .map(|m| {
+        let (_k, m) = m?;
+        Ok(m)
+    }).collect();
+
+    Ok(DelegatedMintersResponse { minters : minters? })
+}
+
+
+pub fn get_collection_signers(deps : Deps, owner : Addr, collection_name : String, 
+    collection_symbol : String) -> StdResult<CollectionSignersResponse> {
+
+    let _key = (owner, collection_id(collection_name, collection_symbol));
+
+    let signers = COLLECTION_SIGNERS_STORE.may_load(deps.storage, _key.clone())?;
+
+    let proposals : StdResult<Vec<ChangeProposal>> = CHANGE_PROPOSALS_STORE
+    .prefix(_key)
+    .range(deps.storage, None, None, Order::Ascending)
+    .map(|p| {
+        let (_k, p) = p?;
+        Ok(p)
+    }).collect();
+
+    Ok(CollectionSignersResponse { signers : signers, proposals : proposals? })
+}
+
+
+pub fn get_pause_status(deps : Deps) -> StdResult<PauseStatusResponse> {
+
+    let paused : StdResult<Vec<PauseFlag>> = PAUSE_FLAGS_STORE
+    .range(deps.storage, None, None, Order::Ascending)
+    .map(|p| {
+        let (_k, p) = p?;
+        Ok(p)
+    }).collect();
+
+    Ok(PauseStatusResponse { paused : paused? })
+}
+
+
+/*
+A name and symbol is available when no collection of any creator uses it
+and it is not reserved, or is reserved by the sender. Both are 
+compared case-insensitively
+ */
+pub (crate) fn internal_get_name_availability(deps : Deps, name : String, symbol : String, 
+    sender : Option<Addr>, time : Timestamp) -> NameAvailabilityResponse {
+
+    let _key = name_key(&name, &symbol);
+
+    let collection = collections_store().idx.name_symbols.item(deps.storage, _key.clone()).unwrap_or(None);
+
+    let reservation = NAME_RESERVATIONS_STORE.may_load(deps.storage, _key).unwrap_or(None)
+    .filter(|r| r.is_active_at(time));
+
+    let reserved_by_other = reservation.is_some() && 
+    Some(reservation.clone().unwrap().reserved_by) != sender;
+
+    NameAvailabilityResponse {
+        available : collection.is_none() && !reserved_by_other,
+        taken : collection.is_some(),
+        reservation : reservation,
+    }
+}
+
+
+pub fn is_name_available(deps : Deps, _env : Env, name : String, symbol : String, 
+    sender : Option<Addr>) -> StdResult<NameAvailabilityResponse> {
+
+    Ok(internal_get_name_availability(deps, name, symbol, sender, _env.block.time))
+}
+
+
+pub (crate) fn internal_get_validation_limits(deps : Deps) -> ValidationLimits {
+
+    VALIDATION_LIMITS_STORE.may_load(deps.storage).unwrap_or(None).unwrap_or_default()
+}
+
+
+pub fn get_validation_limits(deps : Deps) -> StdResult<ValidationLimits> {
+
+    Ok(internal_get_validation_limits(deps))
+}
+
+
+pub fn get_blocked_addresses(deps : Deps, start_after : Option<Addr>, limit : Option<u32>) 
+-> StdResult<BlockedAddressesResponse> {
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let addresses : StdResult<Vec<BlockedAddress>> = BLOCKLIST_STORE
+    .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+    .take(limit)
+    .map(|b| {
+        let (_k, b) = b?;
+        Ok(b)
+    }).collect();
+
+    Ok(BlockedAddressesResponse { addresses : addresses? })
+}
+
+
+pub fn get_collection_blocked_addresses(deps : Deps, owner : Addr, collection_name : String, 
+    collection_symbol : String, start_after : Option<Addr>, limit : Option<u32>) 
+-> StdResult<BlockedAddressesResponse> {
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let addresses : StdResult<Vec<BlockedAddress>> = COLLECTION_BLOCKLISTS_STORE
+    .prefix((owner, collection_id(collection_name, collection_symbol)))
+    .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+    .take(limit)
+    .map(|b| {
+        let (_k, b) = b?;
+        Ok(b)
+    }).collect();
+
+    Ok(BlockedAddressesResponse { addresses : addresses? })
+}
+
+
+pub fn get_phase_allowlist(deps : Deps, owner : Addr, collection_name : String, 
+    collection_symbol : String, phase : String, start_after : Option<Addr>, limit : Option<u32>) 
+-> StdResult<PhaseAllowlistResponse> {
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let addresses : StdResult<Vec<Addr>> = PHASE_ALLOWLISTS_STORE
+    .prefix(((owner, collection_id(collection_name, collection_symbol)), phase))
+    .keys(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+    .take(limit)
+    .collect();
+
+    Ok(PhaseAllowlistResponse { addresses : addresses? })
+}
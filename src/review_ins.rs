@@ -0,0 +1,94 @@
+use cosmwasm_std::{DepsMut, Env, Response, MessageInfo, Addr};
+use crate::state::{Collection, CollectionReview, COLLECTION_STATUS_DRAFT, COLLECTION_STATUS_PENDING_REVIEW, 
+REVIEW_STATUS_APPROVED, REVIEW_STATUS_REJECTED};
+use crate::indexes::collections_store;
+use crate::error::ContractError;
+use crate::ins::{collection_id, common_response, pending_review, STATUS_OK};
+use crate::query::internal_get_collection;
+use crate::checks::*;
+
+
+fn collection_for_review(deps : &DepsMut, owner : Addr, collection_name : String, 
+    collection_symbol : String) -> Result<Collection, ContractError> {
+
+    let collection = internal_get_collection(deps.as_ref(), owner, collection_name, collection_symbol);
+
+    if collection.is_none() {
+        return Err(ContractError::CollectionNotFound { text: "Collection is NOT found!".to_string()});
+    }
+
+    Ok(collection.unwrap())
+}
+
+
+pub fn submit_for_review(deps : DepsMut, _env : Env, info : MessageInfo, 
+    collection_name : String, collection_symbol : String) -> Result<Response, ContractError> {
+
+    let owner = info.sender.clone();
+
+    let mut collection = collection_for_review(&deps, owner.clone(), collection_name.clone(), 
+    collection_symbol.clone())?;
+
+    check_if_status_transition_allowed(collection.status, COLLECTION_STATUS_PENDING_REVIEW)?;
+
+    collection.status = Some(COLLECTION_STATUS_PENDING_REVIEW);
+    collection.review = Some(pending_review(_env.block.time));
+    collection.date_updated = Some(_env.block.time);
+
+    let _key = (owner, collection_id(collection_name, collection_symbol));
+
+    collections_store().save(deps.storage, _key.clone(), &collection)?;
+
+    common_response(format!("{}-{}",_key.0, _key.1).as_str(), "submit_for_review", STATUS_OK, None, None)
+}
+
+
+/*
+Records the review by a contract admin and moves the collection 
+back to draft, an approved collection can then be activated by
+its creator
+ */
+fn review_collection(deps : DepsMut, _env : Env, info : MessageInfo, owner : Addr, 
+    collection_name : String, collection_symbol : String, review_status : u8, 
+    reason : Option<String>, method : &str) -> Result<Response, ContractError> {
+
+    check_if_contract_admin(deps.as_ref(), &info.sender)?;
+
+    let mut collection = collection_for_review(&deps, owner.clone(), collection_name.clone(), 
+    collection_symbol.clone())?;
+
+    if collection.status != Some(COLLECTION_STATUS_PENDING_REVIEW) {
+        return Err(ContractError::InvalidCollectionStatus { text: 
+            "Collection is NOT pending review!".to_string()});
+    }
+
+    collection.status = Some(COLLECTION_STATUS_DRAFT);
+    collection.review = Some(CollectionReview { status : review_status, reviewer : Some(info.sender), 
+        reason : reason, date_submitted : collection.review.clone().and_then(|r| r.date_submitted), 
+        date_reviewed : Some(_env.block.time) });
+    collection.date_updated = Some(_env.block.time);
+
+    let _key = (owner, collection_id(collection_name, collection_symbol));
+
+    collections_store().save(deps.storage, _key.clone(), &collection)?;
+
+    common_response(format!("{}-{}",_key.0, _key.1).as_str(), method, STATUS_OK, None, None)
+}
+
+
+pub fn approve_collection(deps : DepsMut, _env : Env, info : MessageInfo, owner : Addr, 
+    collection_name : String, collection_symbol : String, reason : Option<String>) 
+-> Result<Response, ContractError> {
+
+    review_collection(deps, _env, info, owner, collection_name, collection_symbol, 
+    REVIEW_STATUS_APPROVED, reason, "approve_collection")
+}
+
+
+pub fn reject_collection(deps : DepsMut, _env : Env, info : MessageInfo, owner : Addr, 
+    collection_name : String, collection_symbol : String, reason : String) 
+-> Result<Response, ContractError> {
+
+    review_collection(deps, _env, info, owner, collection_name, collection_symbol, 
+    REVIEW_STATUS_REJECTED, Some(reason), "reject_collection")
+}
@@ -0,0 +1,284 @@
+use cosmwasm_std::{DepsMut, Env, Response, MessageInfo, Addr, Order, StdResult};
+use crate::state::{Collection, CollectionSigners, ChangeProposal, CollectionAction};
+use crate::indexes::{COLLECTION_SIGNERS_STORE, CHANGE_PROPOSALS_STORE, LAST_PROPOSAL_IDS_STORE, PENDING_OWNERS_STORE};
+use crate::error::ContractError;
+use crate::ins::{collection_id, common_response, internal_update_collection, internal_remove_collection, STATUS_OK};
+use crate::ownership_ins::internal_propose_collection_owner;
+use crate::delegate_ins::internal_authorize_minter;
+use crate::promo_ins::internal_add_promo_codes;
+use crate::query::internal_get_collection;
+use crate::checks::*;
+
+
+/*
+The owner sets up the signers of a collection once, any later
+change of the signers has to be approved by the signers, and 
+an ownership transfer proposed before has to be proposed again
+ */
+pub fn set_collection_signers(deps : DepsMut, info : MessageInfo, collection_name : String, 
+    collection_symbol : String, signers : CollectionSigners) -> Result<Response, ContractError> {
+
+    let owner = info.sender.clone();
+
+    let collection = internal_get_collection(deps.as_ref(), owner.clone(), 
+    collection_name.clone(), collection_symbol.clone());
+
+    if collection.is_none() {
+        return Err(ContractError::CollectionNotFound { text: "Collection is NOT found!".to_string()});
+    }
+
+    let _key = (owner, collection_id(collection_name, collection_symbol));
+
+    if COLLECTION_SIGNERS_STORE.has(deps.storage, _key.clone()) {
+        return Err(ContractError::ApprovalRequired { text: 
+            "Changes of signers must be approved by the signers!".to_string()});
+    }
+
+    if signers.signers.is_empty() {
+        return Err(ContractError::InvalidSigners { text: "Signers must NOT be empty!".to_string()});
+    }
+
+    check_if_signers_valid(&signers)?;
+
+    COLLECTION_SIGNERS_STORE.save(deps.storage, _key.clone(), &signers)?;
+
+    PENDING_OWNERS_STORE.remove(deps.storage, _key.clone());
+
+    common_response(format!("{}-{}",_key.0, _key.1).as_str(), "set_collection_signers", STATUS_OK, None, None)
+}
+
+
+pub fn propose_collection_change(deps : DepsMut, _env : Env, info : MessageInfo, owner : Addr,
+    collection_name : String, collection_symbol : String, changes : Option<Collection>, 
+    action : Option<CollectionAction>, signers : Option<CollectionSigners>) -> Result<Response, ContractError> {
+
+    let _key = (owner, collection_id(collection_name.clone(), collection_symbol.clone()));
+
+    let current = load_signers(&deps, &_key, &info.sender)?;
+
+    if changes.is_none() && action.is_none() && signers.is_none() {
+        return Err(ContractError::CustomErrorMesg { message : "Nothing to change!".to_string()});
+    }
+
+    if changes.is_some() {
+
+        let c = changes.clone().unwrap();
+
+        if c.name != collection_name || c.symbol != collection_symbol {
+            return Err(ContractError::CustomErrorMesg { message : 
+                "Changes are NOT for the collection!".to_string()});
+        }
+
+        // the other changes do not need the signers, so are NOT proposed
+        if !is_change_for_signers(&c) {
+            return Err(ContractError::CustomErrorMesg { message : 
+                "Only treasuries, royalties, prices, referral share, status, mint phases, auction, bonding curve, launch and close time changes are proposed!".to_string()});
+        }
+
+        are_treasuries_valid(&c.treasuries)?;
+        are_royalties_valid(&c.royalties)?;
+        check_if_referral_share_valid(c.referral_share)?;
+        check_if_collection_status_valid(c.status)?;
+        check_if_dutch_auction_valid(&c.dutch_auction)?;
+    }
+
+    if let Some(CollectionAction::AddPromoCodes { codes }) = &action {
+        for code in codes.iter() {
+            check_if_promo_code_valid(code)?;
+        }
+    }
+
+    if signers.is_some() {
+        check_if_signers_valid(&signers.clone().unwrap())?;
+    }
+
+    let id = next_proposal_id(&deps, &_key)?;
+
+    LAST_PROPOSAL_IDS_STORE.save(deps.storage, _key.clone(), &id)?;
+
+    // the proposer approves the proposal
+    let proposal = ChangeProposal {
+        id : id,
+        proposer : info.sender.clone(),
+        changes : changes,
+        action : action,
+        signers : signers,
+        approvals : vec![info.sender.clone()],
+        date_proposed : _env.block.time,
+    };
+
+    CHANGE_PROPOSALS_STORE.save(deps.storage, (_key.0.clone(), _key.1.clone(), id), &proposal)?;
+
+    let key = format!("{}-{}={}",_key.0, _key.1, id);
+
+    if proposal.approvals.len() as u32 >= current.threshold {
+        return apply_change_proposal(deps, _env, &_key, collection_name, collection_symbol, proposal, &key);
+    }
+
+    common_response(key.as_str(), "propose_collection_change", STATUS_OK, None, None)
+}
+
+
+pub fn approve_collection_change(deps : DepsMut, _env : Env, info : MessageInfo, owner : Addr,
+    collection_name : String, collection_symbol : String, proposal_id : u64) -> Result<Response, ContractError> {
+
+    let _key = (owner, collection_id(collection_name.clone(), collection_symbol.clone()));
+
+    let current = load_signers(&deps, &_key, &info.sender)?;
+
+    let proposal = CHANGE_PROPOSALS_STORE.may_load(deps.storage, 
+    (_key.0.clone(), _key.1.clone(), proposal_id))?;
+
+    if proposal.is_none() {
+        return Err(ContractError::CustomErrorMesg { message : 
+            format!("Proposal {} is NOT found!", proposal_id)});
+    }
+
+    let mut proposal = proposal.unwrap();
+
+    if proposal.approvals.contains(&info.sender) {
+        return Err(ContractError::CustomErrorMesg { message : 
+            format!("{} has already approved the proposal!", info.sender)});
+    }
+
+    proposal.approvals.push(info.sender.clone());
+
+    // only the approvals of the current signers count
+    let approved = proposal.approvals.iter().filter(|a| current.signers.contains(a)).count() as u32;
+
+    let key = format!("{}-{}={}",_key.0, _key.1, proposal_id);
+
+    if approved >= current.threshold {
+        return apply_change_proposal(deps, _env, &_key, collection_name, collection_symbol, proposal, &key);
+    }
+
+    CHANGE_PROPOSALS_STORE.save(deps.storage, (_key.0.clone(), _key.1.clone(), proposal_id), &proposal)?;
+
+    common_response(key.as_str(), "approve_collection_change", STATUS_OK, None, None)
+}
+
+
+pub fn cancel_collection_change(deps : DepsMut, info : MessageInfo, owner : Addr,
+    collection_name : String, collection_symbol : String, proposal_id : u64) -> Result<Response, ContractError> {
+
+    let _key = (owner, collection_id(collection_name, collection_symbol));
+
+    let proposal = CHANGE_PROPOSALS_STORE.may_load(deps.storage, 
+    (_key.0.clone(), _key.1.clone(), proposal_id))?;
+
+    if proposal.is_none() || proposal.unwrap().proposer != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    CHANGE_PROPOSALS_STORE.remove(deps.storage, (_key.0.clone(), _key.1.clone(), proposal_id));
+
+    common_response(format!("{}-{}={}",_key.0, _key.1, proposal_id).as_str(), "cancel_collection_change", 
+    STATUS_OK, None, None)
+}
+
+
+/*
+The proposal ids of a collection only go up, the collections with proposals 
+from before the counter start after their highest pending proposal id
+ */
+fn next_proposal_id(deps : &DepsMut, _key : &(Addr, String)) -> StdResult<u64> {
+
+    let last = match LAST_PROPOSAL_IDS_STORE.may_load(deps.storage, _key.clone())? {
+
+        Some(id) => id,
+
+        None => CHANGE_PROPOSALS_STORE
+        .prefix(_key.clone())
+        .keys(deps.storage, None, None, Order::Descending)
+        .next().transpose()?
+        .unwrap_or(0),
+    };
+
+    Ok(last + 1)
+}
+
+
+fn load_signers(deps : &DepsMut, _key : &(Addr, String), sender : &Addr) -> Result<CollectionSigners, ContractError> {
+
+    let signers = COLLECTION_SIGNERS_STORE.may_load(deps.storage, _key.clone())?;
+
+    if signers.is_none() {
+        return Err(ContractError::CustomErrorMesg { message : 
+            "Collection does NOT have signers!".to_string()});
+    }
+
+    let signers = signers.unwrap();
+
+    if !signers.signers.contains(sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    Ok(signers)
+}
+
+
+/*
+Applies the approved changes and action as the owner of the collection 
+and drops the proposal, the pending proposals are dropped too when
+the signers are changed
+ */
+fn apply_change_proposal(mut deps : DepsMut, _env : Env, _key : &(Addr, String), 
+    collection_name : String, collection_symbol : String,
+    proposal : ChangeProposal, key : &str) -> Result<Response, ContractError> {
+
+    CHANGE_PROPOSALS_STORE.remove(deps.storage, (_key.0.clone(), _key.1.clone(), proposal.id));
+
+    let owner_info = MessageInfo { sender : _key.0.clone(), funds : vec![] };
+
+    if proposal.changes.is_some() {
+
+        let changes = proposal.changes.unwrap();
+
+        internal_update_collection(deps.branch(), _env.clone(), owner_info.clone(), _key.0.clone(), changes)?;
+    }
+
+    if proposal.action.is_some() {
+
+        match proposal.action.unwrap() {
+
+            CollectionAction::TransferOwner { new_owner, update_treasury } => 
+            internal_propose_collection_owner(deps.branch(), _env.clone(), owner_info, collection_name, 
+                collection_symbol, new_owner, update_treasury)?,
+
+            // the signers and the other records go with the collection
+            CollectionAction::Remove {} => 
+            return internal_remove_collection(collection_name, collection_symbol, deps, owner_info),
+
+            CollectionAction::AuthorizeMinter { minter, price_type, max_mints } => 
+            internal_authorize_minter(deps.branch(), _env.clone(), owner_info, collection_name, 
+                collection_symbol, minter, price_type, max_mints)?,
+
+            CollectionAction::AddPromoCodes { codes } => 
+            internal_add_promo_codes(deps.branch(), _env.clone(), owner_info, collection_name, 
+                collection_symbol, codes)?,
+        };
+    }
+
+    if proposal.signers.is_some() {
+
+        let signers = proposal.signers.unwrap();
+
+        if signers.signers.is_empty() {
+            COLLECTION_SIGNERS_STORE.remove(deps.storage, _key.clone());
+        }
+        else {
+            COLLECTION_SIGNERS_STORE.save(deps.storage, _key.clone(), &signers)?;
+        }
+
+        let pending : StdResult<Vec<u64>> = CHANGE_PROPOSALS_STORE
+        .prefix(_key.clone())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect();
+
+        for id in pending? {
+            CHANGE_PROPOSALS_STORE.remove(deps.storage, (_key.0.clone(), _key.1.clone(), id));
+        }
+    }
+
+    common_response(key, "apply_collection_change", STATUS_OK, None, None)
+}
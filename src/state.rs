@@ -1,7 +1,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use cosmwasm_std::{Addr, Timestamp, Coin};
-use pix0_contract_common::state::PaymentByPercentage;
+use cosmwasm_std::{Addr, Timestamp, Coin, Uint128, OverflowError, StdError, StdResult};
+use crate::utils::split_by_percentage;
 use pix0_market_handlers::state::Trait;
 use pix0_market_handlers::state::Royalty;
 use pix0_market_handlers::state::SimpleCollectionInfo;
@@ -25,12 +25,740 @@ pub struct PriceType {
     pub date_end : Option<Timestamp>, 
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeType {
+
+    CreateCollection,
+
+    CreateItem,
+
+    NftMinting,
+
+    SimpleNftMinting,
+
+    NameReservation,
+}
+
+impl FeeType {
+
+    pub fn all() -> Vec<FeeType> {
+
+        vec![FeeType::CreateCollection, FeeType::CreateItem, 
+        FeeType::NftMinting, FeeType::SimpleNftMinting, FeeType::NameReservation]
+    }
+
+    // the fee name as stored in the contract info
+    pub fn name(&self) -> &'static str {
+
+        match self {
+            FeeType::CreateCollection => "CREATE_COLLECTION_FEE",
+            FeeType::CreateItem => "CREATE_ITEM_FEE",
+            FeeType::NftMinting => "NFT_MINTING_FEE",
+            FeeType::SimpleNftMinting => "SIMPLE_NFT_MINTING_FEE",
+            FeeType::NameReservation => "NAME_RESERVATION_FEE",
+        }
+    }
+
+    pub fn from_name(name : &str) -> Option<FeeType> {
+
+        Self::all().into_iter().find(|f| f.name() == name)
+    }
+}
+
+
+// a discount of FEE_DISCOUNT_MAX waives the fee
+pub const FEE_DISCOUNT_MAX : Percentage = 10000;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeDiscount {
+
+    pub fee_type : FeeType,
+
+    pub percentage : Percentage,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeTier {
+
+    pub name : String,
+
+    pub discounts : Vec<FeeDiscount>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeOverride {
+
+    pub fee_type : FeeType,
+
+    pub value : Coin,
+}
+
+
+// the fee setting of a creator, an override takes precedence 
+// over the discount of the tier for the same fee type
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CreatorFees {
+
+    pub creator : Addr,
+
+    pub tier : Option<String>,
+
+    pub overrides : Option<Vec<FeeOverride>>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DutchAuction {
+
+    pub start_price : Coin,
+
+    pub floor_price : Coin,
+
+    // the price drops by decay_amount every decay_interval seconds
+    pub decay_amount : Uint128,
+
+    pub decay_interval : u64,
+
+    pub start_time : Timestamp,
+
+    pub end_time : Timestamp,
+
+    // when true, the amount paid above the floor price is held by the 
+    // contract until the auction ends, so that early buyers can claim 
+    // back the difference to the clearing price
+    pub refund_to_clearing_price : Option<bool>,
+}
+
+impl DutchAuction {
+
+    pub fn price_at(&self, time : Timestamp) -> Coin {
+
+        let elapsed = time.seconds().saturating_sub(self.start_time.seconds());
+
+        let steps = elapsed / self.decay_interval.max(1);
+
+        let decayed = self.decay_amount.checked_mul(Uint128::from(steps))
+        .unwrap_or(Uint128::MAX);
+
+        Coin { amount : self.start_price.amount.saturating_sub(decayed).max(self.floor_price.amount), 
+            denom : self.start_price.denom.clone() }
+    }
+
+    pub fn is_refundable(&self) -> bool {
+
+        self.refund_to_clearing_price.unwrap_or(false)
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AuctionState {
+
+    pub minted : u32,
+
+    pub last_price : Option<Coin>,
+
+    pub proceeds_claimed : bool,
+
+    // set the first time the auction ends, so adding 
+    // items afterwards does not reopen it
+    pub ended_at : Option<Timestamp>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AuctionPurchase {
+
+    pub buyer : Addr,
+
+    pub count : u32,
+
+    // the total paid for all the mints
+    pub paid : Uint128,
+
+    // the part of paid held by the contract until the auction ends
+    pub escrowed : Uint128,
+
+    pub refund_claimed : bool,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintPhase {
+
+    pub name : String,
+
+    pub price : Coin,
+
+    pub start_time : Timestamp,
+
+    // only the last phase may be left open-ended
+    pub end_time : Option<Timestamp>,
+
+    // when true, only the wallets on the allowlist of the phase can mint
+    pub allowlist_only : Option<bool>,
+
+    pub max_per_wallet : Option<u32>,
+}
+
+impl MintPhase {
+
+    pub fn is_active_at(&self, time : Timestamp) -> bool {
+
+        time >= self.start_time && (self.end_time.is_none() || time < self.end_time.unwrap())
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PhaseMint {
+
+    pub phase : String,
+
+    pub count : u32,
+}
+
+
+pub const CURVE_TYPE_LINEAR : u8 = 1;
+
+pub const CURVE_TYPE_EXPONENTIAL : u8 = 2;
+
+// fixed point scale used for the exponential growth factor
+const CURVE_SCALE : u128 = 1_000_000_000;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BondingCurve {
+
+    pub curve_type : u8,
+
+    // the price of the first mint
+    pub base_price : Coin,
+
+    // linear : the price increases by increment on every mint
+    pub increment : Option<Uint128>,
+
+    // exponential : the price grows by this percentage on every mint
+    pub growth_percentage : Option<Percentage>,
+}
+
+impl BondingCurve {
+
+    /*
+    Returns the price of the next mint when minted items 
+    have already been minted, or an error on overflow
+     */
+    pub fn price_at(&self, minted : u64) -> Result<Coin, OverflowError> {
+
+        let amount = if self.curve_type == CURVE_TYPE_EXPONENTIAL {
+
+            let growth = TREASURY_PERCENTAGE_TOTAL as u128 + self.growth_percentage.unwrap_or(0) as u128;
+
+            let factor = Uint128::from(CURVE_SCALE).checked_mul(Uint128::from(growth))? 
+            / Uint128::from(TREASURY_PERCENTAGE_TOTAL);
+
+            let scale = Uint128::from(CURVE_SCALE);
+
+            // exponentiation by squaring in fixed point
+            let mut result = scale;
+            let mut base = factor;
+            let mut exp = minted;
+
+            while exp > 0 {
+
+                if exp & 1 == 1 {
+                    result = result.checked_mul(base)? / scale;
+                }
+
+                exp >>= 1;
+
+                if exp > 0 {
+                    base = base.checked_mul(base)? / scale;
+                }
+            }
+
+            self.base_price.amount.checked_mul(result)? / scale
+        }
+        else {
+
+            self.base_price.amount.checked_add(
+                self.increment.unwrap_or(Uint128::zero()).checked_mul(Uint128::from(minted))?)?
+        };
+
+        Ok(Coin { amount : amount, denom : self.base_price.denom.clone() })
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PauseGroup {
+
+    // the collections, their settings and the settings of the contract
+    Collections,
+
+    // create, update and remove items
+    Items,
+
+    // minting, the minting pauses of the collections and the auction claims
+    Minting,
+
+    // transfers, sends and burns of NFTs
+    Transfers,
+
+    SellOffers,
+}
+
+impl PauseGroup {
+
+    pub fn name(&self) -> &'static str {
+
+        match self {
+            PauseGroup::Collections => "collections",
+            PauseGroup::Items => "items",
+            PauseGroup::Minting => "minting",
+            PauseGroup::Transfers => "transfers",
+            PauseGroup::SellOffers => "sell_offers",
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PauseFlag {
+
+    pub group : PauseGroup,
+
+    pub reason : Option<String>,
+
+    pub paused_by : Addr,
+
+    pub date_paused : Timestamp,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CollectionRole {
+
+    // items and metadata
+    Editor,
+
+    // status, prices and the mint schedule
+    Manager,
+
+    // treasuries, royalties and referral share
+    Finance,
+}
+
+impl CollectionRole {
+
+    pub fn name(&self) -> &'static str {
+
+        match self {
+            CollectionRole::Editor => "editor",
+            CollectionRole::Manager => "manager",
+            CollectionRole::Finance => "finance",
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CollectionMember {
+
+    pub address : Addr,
+
+    pub roles : Vec<CollectionRole>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CollectionSigners {
+
+    pub signers : Vec<Addr>,
+
+    // the number of signers to approve a change
+    pub threshold : u32,
+}
+
+
+/*
+The actions of the owner on a collection with signers, 
+which are proposed to and approved by the signers
+ */
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CollectionAction {
+
+    TransferOwner { new_owner : Addr, update_treasury : Option<bool> },
+
+    Remove {},
+
+    AuthorizeMinter { minter : Addr, price_type : Option<u8>, max_mints : Option<u32> },
+
+    AddPromoCodes { codes : Vec<PromoCode> },
+}
+
+
+/*
+A change of the treasuries, royalties, prices, referral share or status 
+of a collection, an action of its owner, or a change of its signers, 
+proposed by one of the signers and applied once the threshold of 
+signers has approved it
+ */
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ChangeProposal {
+
+    pub id : u64,
+
+    pub proposer : Addr,
+
+    pub changes : Option<Collection>,
+
+    pub action : Option<CollectionAction>,
+
+    // the new signers, an empty list turns off the approvals
+    pub signers : Option<CollectionSigners>,
+
+    pub approvals : Vec<Addr>,
+
+    pub date_proposed : Timestamp,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DelegatedMinter {
+
+    pub minter : Addr,
+
+    // the price type charged off-chain, free when none
+    pub price_type : Option<u8>,
+
+    pub max_mints : Option<u32>,
+
+    pub minted : u32,
+
+    // the total value of the mints to be settled off-chain
+    pub value : Vec<Coin>,
+
+    pub revoked : bool,
+
+    pub date_authorized : Timestamp,
+}
+
+
+// the names are reserved for a week, so nobody else can take them meanwhile
+pub const NAME_RESERVATION_DURATION : u64 = 7 * 24 * 60 * 60;
+
+// the time after a reservation ends during which its reserver can NOT reserve 
+// the name again, so a name can not be held by renewing it back to back
+pub const NAME_RESERVATION_COOLDOWN : u64 = 7 * 24 * 60 * 60;
+
+// the active reservations a creator can hold at once
+pub const MAX_ACTIVE_NAME_RESERVATIONS : usize = 3;
+
+// a name and symbol reserved by a creator before creating the collection
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NameReservation {
+
+    pub name : String,
+
+    pub symbol : String,
+
+    pub reserved_by : Addr,
+
+    pub expires_at : Timestamp,
+}
+
+impl NameReservation {
+
+    pub fn is_active_at(&self, time : Timestamp) -> bool {
+
+        time < self.expires_at
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingOwner {
+
+    pub new_owner : Addr,
+
+    // when true, the treasuries paying the current owner are 
+    // moved to the new owner
+    pub update_treasury : bool,
+
+    pub date_proposed : Timestamp,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VerifiedCreator {
+
+    pub creator : Addr,
+
+    pub verified_by : Addr,
+
+    pub note : Option<String>,
+
+    pub date_verified : Timestamp,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeaturedCollection {
+
+    pub owner : Addr,
+
+    pub collection_name : String,
+
+    pub collection_symbol : String,
+
+    // featured collections are listed by position, the lowest first
+    pub position : u32,
+
+    pub expiry : Option<Timestamp>,
+}
+
+impl FeaturedCollection {
+
+    pub fn is_expired(&self, time : Timestamp) -> bool {
+
+        self.expiry.is_some() && time >= self.expiry.unwrap()
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ModerationAction {
+
+    Suspend,
+
+    Unsuspend,
+
+    Flag,
+
+    Unflag,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BlockedAddress {
+
+    pub address : Addr,
+
+    pub reason : Option<String>,
+
+    pub blocked_by : Addr,
+
+    pub date_blocked : Timestamp,
+}
+
+
+// limits against bots minting from fresh wallets in the first blocks
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintThrottle {
+
+    // the max mints of the collection in a block, across all senders
+    pub max_mints_per_block : Option<u32>,
+
+    // the blocks a sender waits between two mints
+    pub min_blocks_between_mints : Option<u64>,
+
+    // when true, contracts can NOT mint
+    pub no_contract_minters : Option<bool>,
+}
+
+// about a week of blocks, a longer wait would lock the minters out
+pub const MINT_THROTTLE_MAX_BLOCKS : u64 = 100_000;
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BlockMints {
+
+    pub height : u64,
+
+    pub count : u32,
+}
+
+
+// the limits of the fields of collections and items, set by the admins
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ValidationLimits {
+
+    pub max_name_length : u32,
+
+    pub max_symbol_length : u32,
+
+    pub max_description_length : u32,
+
+    // the max length of the attribute and trait values and of the links
+    pub max_value_length : u32,
+
+    pub max_attributes : u32,
+
+    pub max_links : u32,
+
+    pub max_traits : u32,
+}
+
+impl Default for ValidationLimits {
+
+    fn default() -> Self {
+
+        ValidationLimits {
+            max_name_length : 64,
+            max_symbol_length : 16,
+            max_description_length : 2000,
+            max_value_length : 1024,
+            max_attributes : 32,
+            max_links : 10,
+            max_traits : 50,
+        }
+    }
+}
+
+
+// a temporary pause of minting by the creator, which
+// leaves the status of the collection unchanged
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintPause {
+
+    pub reason : Option<String>,
+
+    // minting resumes by itself at this time when set
+    pub resume_at : Option<Timestamp>,
+
+    pub paused_by : Addr,
+
+    pub date_paused : Timestamp,
+}
+
+impl MintPause {
+
+    pub fn is_active_at(&self, time : Timestamp) -> bool {
+
+        self.resume_at.is_none() || time < self.resume_at.unwrap()
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ModerationRecord {
+
+    pub action : ModerationAction,
+
+    pub reason : Option<String>,
+
+    pub moderator : Addr,
+
+    pub date_moderated : Timestamp,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Moderation {
+
+    pub suspended : bool,
+
+    pub flagged : bool,
+
+    // the moderation history, the latest last, 
+    // only the latest MODERATION_RECORDS_MAX are kept
+    pub records : Vec<ModerationRecord>,
+}
+
+pub const MODERATION_RECORDS_MAX : usize = 20;
+
+// the items and records re-keyed at most when a collection moves to a new owner
+pub const COLLECTION_MOVE_MAX_RECORDS : usize = 500;
+
+
+pub const REVIEW_STATUS_PENDING : u8 = 0;
+
+pub const REVIEW_STATUS_APPROVED : u8 = 1;
+
+pub const REVIEW_STATUS_REJECTED : u8 = 2;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CollectionReview {
+
+    pub status : u8,
+
+    pub reviewer : Option<Addr>,
+
+    pub reason : Option<String>,
+
+    pub date_submitted : Option<Timestamp>,
+
+    pub date_reviewed : Option<Timestamp>,
+}
+
+
 pub const COLLECTION_STATUS_DRAFT : u8 = 0;
 
 pub const COLLECTION_STATUS_ACTIVATED : u8 = 1;
 
+// only found on the collections stored before the paused status, 
+// which are migrated to paused, it is no longer accepted in messages
 pub const COLLECTION_STATUS_DEACTIVATED : u8 = 2;
 
+pub const COLLECTION_STATUS_PENDING_REVIEW : u8 = 3;
+
+pub const COLLECTION_STATUS_SOLD_OUT : u8 = 4;
+
+pub const COLLECTION_STATUS_CLOSED : u8 = 5;
+
+pub const COLLECTION_STATUS_ARCHIVED : u8 = 6;
+
+pub const COLLECTION_STATUS_PAUSED : u8 = 7;
+
+// the statuses a collection can be created with
+pub const INITIAL_COLLECTION_STATUSES : [u8; 3] = [COLLECTION_STATUS_DRAFT, 
+COLLECTION_STATUS_PENDING_REVIEW, COLLECTION_STATUS_ACTIVATED];
+
+
+pub fn collection_status_name(status : u8) -> &'static str {
+
+    match status {
+        COLLECTION_STATUS_DRAFT => "draft",
+        COLLECTION_STATUS_ACTIVATED => "activated",
+        COLLECTION_STATUS_PAUSED => "paused",
+        COLLECTION_STATUS_DEACTIVATED => "deactivated",
+        COLLECTION_STATUS_PENDING_REVIEW => "pending review",
+        COLLECTION_STATUS_SOLD_OUT => "sold out",
+        COLLECTION_STATUS_CLOSED => "closed",
+        COLLECTION_STATUS_ARCHIVED => "archived",
+        _ => "unknown",
+    }
+}
+
+
+/*
+The statuses a collection can move to from the given status,
+staying at the same status is always allowed
+ */
+pub fn allowed_status_transitions(from : u8) -> Vec<u8> {
+
+    match from {
+        COLLECTION_STATUS_DRAFT => vec![COLLECTION_STATUS_PENDING_REVIEW, COLLECTION_STATUS_ACTIVATED, 
+            COLLECTION_STATUS_ARCHIVED],
+        COLLECTION_STATUS_PENDING_REVIEW => vec![COLLECTION_STATUS_DRAFT, COLLECTION_STATUS_ACTIVATED],
+        COLLECTION_STATUS_ACTIVATED => vec![COLLECTION_STATUS_PAUSED, COLLECTION_STATUS_SOLD_OUT, 
+            COLLECTION_STATUS_CLOSED],
+        COLLECTION_STATUS_PAUSED | COLLECTION_STATUS_DEACTIVATED => vec![COLLECTION_STATUS_ACTIVATED, 
+            COLLECTION_STATUS_CLOSED],
+        COLLECTION_STATUS_SOLD_OUT => vec![COLLECTION_STATUS_ACTIVATED, COLLECTION_STATUS_CLOSED, 
+            COLLECTION_STATUS_ARCHIVED],
+        COLLECTION_STATUS_CLOSED => vec![COLLECTION_STATUS_ARCHIVED],
+        _ => vec![],
+    }
+}
+
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Attribute {
@@ -40,7 +768,7 @@ pub struct Attribute {
     pub value : String, 
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
 pub struct Collection {
    
     pub owner : Option<Addr>,
@@ -65,41 +793,149 @@ pub struct Collection {
 
     pub date_updated : Option<Timestamp>,
 
+    // the share of the mint price paid to the referrer
+    pub referral_share : Option<Percentage>,
+
+    // when set, the mint price is the current auction price 
+    // instead of the price by price type
+    pub dutch_auction : Option<DutchAuction>,
+
+    // when set, the mint price is derived from the number 
+    // of items minted so far
+    pub bonding_curve : Option<BondingCurve>,
+
+    // when set, the price is taken from the phase active at the 
+    // time of minting and only the standard price type is accepted
+    pub mint_phases : Option<Vec<MintPhase>>,
+
+    // when set, the collection is only activated from launch_at 
+    // and until close_at, without the status being flipped
+    pub launch_at : Option<Timestamp>,
+
+    pub close_at : Option<Timestamp>,
+
+    // the outcome of the launchpad review, only set by the contract
+    pub review : Option<CollectionReview>,
+
+    pub mint_throttle : Option<MintThrottle>,
+
 }
 
 
 impl Collection {
 
-    pub fn treasuries(&self) -> Vec<Treasury> {
+    pub fn treasuries(&self) -> StdResult<Vec<Treasury>> {
 
         if self.treasuries.is_some() {
 
             let t = self.treasuries.clone().unwrap();
-            return t;
+            return Ok(t);
         }
-        if self.owner.is_some() {
-            return vec![Treasury{wallet: self.owner.clone().unwrap().clone(), percentage :100, name : None}];
+
+        if self.owner.is_none() {
+            return Err(StdError::generic_err("The collection has neither treasuries nor an owner to be paid!"));
         }
-        else {
 
-            return vec![Treasury{wallet: Addr::unchecked("unknown"), percentage :0, name : None}];
-            
+        Ok(vec![Treasury{wallet: self.owner.clone().unwrap(), 
+            percentage : TREASURY_PERCENTAGE_TOTAL, name : None, primary : Some(true)}])
+    }
+
+
+    /*
+    The rounding remainder of a split goes to the primary treasury,
+    which is the first treasury flagged as primary, or the first 
+    treasury in the list when none is flagged.
+     */
+    pub fn primary_treasury_index(treasuries : &Vec<Treasury>) -> usize {
+
+        treasuries.iter()
+        .position(|t| t.primary == Some(true))
+        .unwrap_or(0)
+    }
+
+
+    pub fn treasuries_to_payments(&self, price : Coin) -> StdResult<Vec<(Addr, Coin)>>{
+
+        let treas = self.treasuries()?;
+
+        let percentages : Vec<Percentage> = treas.iter().map(|t| t.percentage).collect();
+
+        let amounts : Vec<Uint128> = split_by_percentage(price.amount, &percentages, 
+        Self::primary_treasury_index(&treas));
+
+        Ok(treas.into_iter()
+        .zip(amounts.into_iter())
+        .map(|(t, a)| (t.wallet, Coin { amount : a, denom : price.denom.clone() }))
+        .collect())
+    }
+}
+
+impl Collection {
+
+    /*
+    The status at the given time with the launch window applied, 
+    an activated collection is a draft before launch_at, an approved
+    draft collection is activated inside the window, and any collection 
+    not yet archived is closed from close_at
+     */
+    pub fn effective_status(&self, time : Timestamp) -> Option<u8> {
+
+        if self.close_at.is_some() && time >= self.close_at.unwrap() 
+        && self.status != Some(COLLECTION_STATUS_ARCHIVED) {
+            return Some(COLLECTION_STATUS_CLOSED);
+        }
+
+        if self.launch_at.is_some() {
+
+            if time < self.launch_at.unwrap() {
+
+                if self.status == Some(COLLECTION_STATUS_ACTIVATED) {
+                    return Some(COLLECTION_STATUS_DRAFT);
+                }
+
+                return self.status;
+            }
+
+            if self.status == Some(COLLECTION_STATUS_DRAFT) && self.is_approved() {
+                return Some(COLLECTION_STATUS_ACTIVATED);
+            }
         }
+
+        self.status
     }
 
+    pub fn is_approved(&self) -> bool {
+
+        self.review.is_some() && self.review.clone().unwrap().status == REVIEW_STATUS_APPROVED
+    }
 
-    pub fn treasuries_to_payments(&self) -> Vec<PaymentByPercentage>{
+    /*
+    Whether the content the reviewers approve differs from the 
+    other collection, the status, the launch window and the 
+    throttle are not part of the reviewed content
+     */
+    pub fn is_content_changed(&self, other : &Collection) -> bool {
+
+        self.description != other.description ||
+        self.treasuries != other.treasuries ||
+        self.royalties != other.royalties ||
+        self.prices != other.prices ||
+        self.attributes != other.attributes ||
+        self.referral_share != other.referral_share ||
+        self.dutch_auction != other.dutch_auction ||
+        self.bonding_curve != other.bonding_curve ||
+        self.mint_phases != other.mint_phases
+    }
 
-        let treas = self.treasuries();
-        let mut payments : Vec<PaymentByPercentage> = Vec::new();
+    pub fn active_phase(&self, time : Timestamp) -> Option<MintPhase> {
 
-        treas.iter()
-        .for_each(|t| {
-            payments.push( PaymentByPercentage { wallet: t.clone().wallet, 
-                percentage: t.percentage });    
-        });
+        if self.mint_phases.is_none() {
+            return None;
+        }
 
-        payments
+        self.mint_phases.clone().unwrap()
+        .into_iter()
+        .find(|p| p.is_active_at(time))
     }
 }
 
@@ -117,14 +953,9 @@ impl Collection {
 
             let prc = self.prices.clone().unwrap();
 
-            let prcs : Vec<PriceType> = prc.into_iter()
-            .filter(|p| p.price_type  ==  _type)
-            .collect();
-
-            let prc_type = prcs.first().unwrap();
-
-            Some(prc_type.clone().value)
-
+            prc.into_iter()
+            .find(|p| p.price_type  ==  _type)
+            .map(|p| p.value)
 
         }   
         else {
@@ -178,14 +1009,86 @@ impl Collection {
 }
 
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReferralStats {
+
+    pub referrer : Addr,
+
+    pub mints : u64,
+
+    pub earnings : Vec<Coin>,
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PromoDiscount {
+
+    // percentage off the price
+    Percentage(Percentage),
+
+    // fixed amount off the price
+    Fixed(Coin),
+}
+
+impl PromoDiscount {
+
+    // the discounted price, none if the fixed discount is in another denom
+    pub fn apply(&self, price : &Coin) -> Option<Coin> {
+
+        match self {
+
+            PromoDiscount::Percentage(p) => {
+                let off = price.amount.multiply_ratio((*p).min(TREASURY_PERCENTAGE_TOTAL) as u128, 
+                TREASURY_PERCENTAGE_TOTAL as u128);
+                Some(Coin { amount : price.amount - off, denom : price.denom.clone() })
+            },
+
+            PromoDiscount::Fixed(c) => {
+                if c.denom != price.denom {
+                    return None;
+                }
+                Some(Coin { amount : price.amount.saturating_sub(c.amount), denom : price.denom.clone() })
+            },
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PromoCode {
+
+    // hex encoded sha256 hash of the plaintext code
+    pub code_hash : String,
+
+    pub discount : PromoDiscount,
+
+    pub max_uses : Option<u32>,
+
+    pub used : u32,
+
+    pub expiry : Option<Timestamp>,
+}
+
+
+/*
+All the percentages of the contract are 2-decimal, which means 
+1250 is 12.50 percent and TREASURY_PERCENTAGE_TOTAL is 100 percent
+ */
+pub type Percentage = u16;
+
+pub const TREASURY_PERCENTAGE_TOTAL : Percentage = 10000;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Treasury {
 
     pub wallet : Addr, 
 
-    pub percentage : u8, 
+    pub percentage : Percentage, 
 
     pub name : Option<String>,
+
+    pub primary : Option<bool>,
 }
 
 
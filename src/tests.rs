@@ -5,17 +5,19 @@ mod tests {
     // use crate::users::user_resp::*;
     // use std::mem::size_of;
     use crate::state::*;
-    use cosmwasm_std::testing::{mock_env, mock_info, mock_dependencies_with_balance};
-    use cosmwasm_std::{coins, Addr, Deps, from_binary, Coin, Uint128, BankMsg};
+    use cosmwasm_std::testing::{mock_env, mock_info, mock_dependencies_with_balance, MockApi, MockStorage, MockQuerier};
+    use cosmwasm_std::{coins, Addr, Deps, from_binary, Coin, Uint128, BankMsg, OwnedDeps, MessageInfo};
     use crate::msg::*;
     use pix0_market_handlers::nft_ins::Extension;
+    use pix0_market_handlers::state::Trait;
     use crate::contract::*;
     use crate::ins::*;
-    use crate::query::collection_category;
+    use crate::query::{collection_category, get_collection, MAX_LIMIT};
     use pix0_contract_common::state::{Fee, ContractInfoResponse, PaymentByPercentage};
     use pix0_contract_common::msg::InstantiateMsg;
     use pix0_contract_common::funcs::{pay_by_percentage_checked, try_paying_contract_treasuries};
     use pix0_contract_common::utils::RandomNumGen;
+    use crate::utils::{split_by_percentage, promo_code_hash};
 
     const DEFAULT_PRICE_DENOM : &str = "uconst";
    
@@ -68,14 +70,16 @@ mod tests {
             date_start : None, date_end : None, 
         }];
 
-        let treasuries : Vec<Treasury> = vec![Treasury {
-            wallet : Addr::unchecked("archway1nxqd7h869sj9pn0xyq0lqqqxjqx6vt550z4aj7".to_string()),
-            percentage : 70,
+        let treasuries : Vec<TreasuryMsg> = vec![TreasuryMsg {
+            wallet : "archway1nxqd7h869sj9pn0xyq0lqqqxjqx6vt550z4aj7".to_string(),
+            percentage : 7000,
             name : None,
-        }, Treasury {
-            wallet : Addr::unchecked("archway122w9rr76aac9pmke9qq6ya5l8245qr44h8jvtm".to_string()),
-            percentage : 30,
+            primary : None,
+        }, TreasuryMsg {
+            wallet : "archway122w9rr76aac9pmke9qq6ya5l8245qr44h8jvtm".to_string(),
+            percentage : 3000,
             name : None,
+            primary : None,
         }];
 
 
@@ -85,7 +89,7 @@ mod tests {
         }];
         
         let create_collection = ExecuteMsg::CreateCollection { collection:
-            Collection {
+            CollectionMsg {
                 name : collection_name.clone(),
                 symbol : collection_symb.clone(),
                 description : Some("Test collection 1111111".to_string()),
@@ -96,7 +100,8 @@ mod tests {
                 royalties : None, 
                 date_created : None,
                 date_updated : None, 
-                owner : Some(Addr::unchecked(owner)), 
+                owner : Some(owner.to_string()), 
+                ..Default::default()
             }
         };
 
@@ -113,8 +118,8 @@ mod tests {
            let links = vec![Link{link_type: LINK_TYPE_IMAGE_URL, 
                value:format!("https://rm.img/img_000{}.png",x) }];
 
-           let itm = Item {
-               collection_owner :  Addr::unchecked(owner.to_string()),
+           let itm = ItemMsg {
+               collection_owner :  owner.to_string(),
                collection_name : collection_name.clone(),
                collection_symbol : collection_symb.clone(),
                name : format!("Item #00{}",(x+1)),
@@ -145,7 +150,7 @@ mod tests {
         let r = mint_item(deps.as_mut(), mock_env(), info.clone(),
          seed, Addr::unchecked(owner), 
         collection_name.clone(), collection_symb.clone(), Some(price_type), 
-        Some("https://some.metadata/x199x.json".to_string()), None );
+        Some("https://some.metadata/x199x.json".to_string()), None, None, None );
 
         println!("Minted.item:seed::{}::res:{:?}",  seed,  r);
 
@@ -157,7 +162,7 @@ mod tests {
         let r =  mint_item_by_name(deps.as_mut(), mock_env(), info.clone(),  
         format!("Item #00{}",2), Addr::unchecked(owner.clone()), collection_name.clone(), 
         collection_symb.clone(), Some(price_type), 
-        Some("https://some.metadata/x208y.json".to_string()), None );
+        Some("https://some.metadata/x208y.json".to_string()), None, None, None );
        
 
         println!("Minted.item:seed::{}:res:{:?}", seed, r);
@@ -245,7 +250,7 @@ mod tests {
 
        
         let msg = QueryMsg::GetItemsCount { owner:
-            owner.clone(), collection_name: collection_name.clone(), 
+            owner.to_string(), collection_name: collection_name.clone(), 
             collection_symbol: collection_symbol.clone() };
 
         let res = query(*deps, mock_env(), msg).expect("failed to unwrap!!");
@@ -384,6 +389,104 @@ mod tests {
     }
 
 
+    // cargo test test_split_by_percentage_sums_to_price -- --show-output
+    #[test]
+    fn test_split_by_percentage_sums_to_price(){
+
+        let mut rng = RandomNumGen::new(8812);
+
+        for _ in 0..2000 {
+
+            let price = Uint128::from(rng.generate_range(0, u64::MAX - 1) as u128 * 
+            rng.generate_range(1, 1000) as u128 + rng.generate_range(0, 7) as u128);
+
+            // random split of 10000 into 1 to 10 treasuries
+            let count = rng.generate_range(1, 10) as usize;
+            let mut percentages : Vec<u16> = Vec::new();
+            let mut left : u16 = TREASURY_PERCENTAGE_TOTAL;
+
+            for i in 0..count {
+                if i == count - 1 || left == 0 {
+                    percentages.push(left);
+                    left = 0;
+                }
+                else {
+                    let p = rng.generate_range(0, left as u64) as u16;
+                    percentages.push(p);
+                    left -= p;
+                }
+            }
+
+            let primary = rng.generate_range(0, count as u64) as usize % count;
+
+            let amounts = split_by_percentage(price, &percentages, primary);
+
+            let total = amounts.iter().fold(Uint128::zero(), |acc, a| acc + *a);
+
+            assert_eq!(total, price);
+
+            // only the primary treasury may receive the remainder
+            for (i, a) in amounts.iter().enumerate() {
+                if i != primary {
+                    assert_eq!(*a, price.multiply_ratio(percentages[i] as u128, 
+                    TREASURY_PERCENTAGE_TOTAL as u128));
+                }
+            }
+        }
+    }
+
+
+    // cargo test test_collection_treasuries_to_payments -- --show-output
+    #[test]
+    fn test_collection_treasuries_to_payments(){
+
+        let collection = Collection {
+            name : "Split Test".to_string(),
+            symbol : "SPLT".to_string(),
+            description : None,
+            treasuries : Some(vec![Treasury {
+                wallet : Addr::unchecked("archway1nxqd7h869sj9pn0xyq0lqqqxjqx6vt550z4aj7"),
+                percentage : 3333, name : None, primary : None,
+            }, Treasury {
+                wallet : Addr::unchecked("archway122w9rr76aac9pmke9qq6ya5l8245qr44h8jvtm"),
+                percentage : 3334, name : None, primary : Some(true),
+            }, Treasury {
+                wallet : Addr::unchecked("archway1upspu5660q39adv768z8ffk44ta6lzd4nfw2zw"),
+                percentage : 3333, name : None, primary : None,
+            }]),
+            attributes : None,
+            status : Some(COLLECTION_STATUS_ACTIVATED),
+            prices : None,
+            royalties : None, 
+            date_created : None,
+            date_updated : None,
+            owner : Some(Addr::unchecked("archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky")),
+            ..Default::default()
+        };
+
+        let payments = collection.treasuries_to_payments(Coin { amount : Uint128::from(100u64), 
+        denom : DEFAULT_PRICE_DENOM.to_string()}).unwrap();
+
+        let amounts : Vec<Uint128> = payments.iter().map(|p| p.1.amount).collect();
+
+        // 33 + 33 + 33 = 99, the remaining 1 goes to the primary treasury
+        assert_eq!(amounts, vec![Uint128::from(33u64), Uint128::from(34u64), Uint128::from(33u64)]);
+
+        // without treasuries and an owner there is no one to pay
+        let mut no_owner = collection.clone();
+        no_owner.treasuries = None;
+        no_owner.owner = None;
+
+        assert!(no_owner.treasuries_to_payments(Coin { amount : Uint128::from(100u64), 
+        denom : DEFAULT_PRICE_DENOM.to_string()}).is_err());
+
+        assert!(crate::migrations::is_version_before("0.8.8", "0.9.0"));
+        assert!(crate::migrations::is_version_before("0.8.10", "0.9.0"));
+        assert!(!crate::migrations::is_version_before("0.9.0", "0.9.0"));
+        assert!(!crate::migrations::is_version_before("0.10.0", "0.9.0"));
+    }
+
+
     fn extract_amount_from_bank_msg(msg: &BankMsg) -> Option<Uint128> {
         match msg {
             BankMsg::Send { amount, .. } => {
@@ -497,18 +600,20 @@ mod tests {
                 date_start : None, date_end : None, 
             }];
 
-            let treasuries : Vec<Treasury> = vec![Treasury {
-                wallet : Addr::unchecked("archway1nxqd7h869sj9pn0xyq0lqqqxjqx6vt550z4aj7".to_string()),
-                percentage : 70,
+            let treasuries : Vec<TreasuryMsg> = vec![TreasuryMsg {
+                wallet : "archway1nxqd7h869sj9pn0xyq0lqqqxjqx6vt550z4aj7".to_string(),
+                percentage : 7000,
                 name : None,
-            }, Treasury {
-                wallet : Addr::unchecked("archway122w9rr76aac9pmke9qq6ya5l8245qr44h8jvtm".to_string()),
-                percentage : 30,
+                primary : None,
+            }, TreasuryMsg {
+                wallet : "archway122w9rr76aac9pmke9qq6ya5l8245qr44h8jvtm".to_string(),
+                percentage : 3000,
                 name : None,
+                primary : None,
             }];
             
             let create_collection = ExecuteMsg::CreateCollection { collection:
-                Collection {
+                CollectionMsg {
                     name : format!("Test Collection 00{}", i),
                     symbol : format!("TC{}",i),
                     description : Some(format!("Description of test collection 00{}",i )),
@@ -519,7 +624,8 @@ mod tests {
                     royalties : None, 
                     date_created : None,
                     date_updated : None, 
-                    owner : Some(Addr::unchecked(owner)), 
+                    owner : Some(owner.to_string()), 
+                    ..Default::default()
                 }
             };
 
@@ -537,7 +643,8 @@ mod tests {
             keyword : Some("0032".to_string()),
             category : Some("art".to_string()),
             start : Some(21), //Some("Test Collection 0025".to_string()),
-            limit : Some(20)
+            limit : Some(20),
+            verified_only : None,
         };
 
         let res = query(deps.as_ref(), mock_env(), msg).expect("failed to unwrap!!");
@@ -552,4 +659,2069 @@ mod tests {
         println!("Return.collections.count::{}", result.collections.len());
         println!("Total.collections.count::{:?}:{:?}", result.total, result.start);
     }
-}
\ No newline at end of file
+
+
+    fn setup_test_collection(owner : &str, collection_name : &str, collection_symb : &str, 
+        items_count : u32) -> (OwnedDeps<MockStorage, MockApi, MockQuerier>, MessageInfo) {
+
+        let mut deps = mock_dependencies_with_balance(&coins(2, DEFAULT_PRICE_DENOM));
+        let info = mock_info(owner, &coins(134000, DEFAULT_PRICE_DENOM));
+
+        let admin =  Addr::unchecked(owner.to_string());
+
+        let ins = InstantiateMsg {
+            allowed_admins : Some(vec![admin.clone()]),
+            treasuries : Some(vec![admin]),
+            contracts : None, 
+            fees : Some(vec![ 
+                Fee {name : "CREATE_COLLECTION_FEE".to_string(),
+                value : Coin { amount : Uint128::from(1500u64), denom : "uconst".to_string()}},
+                Fee {name : "CREATE_ITEM_FEE".to_string(),
+                value : Coin { amount : Uint128::from(3500u64), denom : "uconst".to_string()}},
+                Fee {name : "NFT_MINTING_FEE".to_string(),
+                value : Coin { amount : Uint128::from(6400u64), denom : "uconst".to_string()}},
+            ]) ,
+            log_last_payment : Some(true)
+        };
+
+        instantiate(deps.as_mut(), mock_env(), info.clone(), ins).expect("failed to instantiate!!");
+
+        let create_collection = ExecuteMsg::CreateCollection { collection:
+            CollectionMsg {
+                name : collection_name.to_string(),
+                symbol : collection_symb.to_string(),
+                description : None,
+                treasuries : Some(vec![TreasuryMsg {
+                    wallet : "archway1nxqd7h869sj9pn0xyq0lqqqxjqx6vt550z4aj7".to_string(),
+                    percentage : 7000, name : None, primary : None,
+                }, TreasuryMsg {
+                    wallet : "archway122w9rr76aac9pmke9qq6ya5l8245qr44h8jvtm".to_string(),
+                    percentage : 3000, name : None, primary : None,
+                }]),
+                attributes : None, 
+                status : Some(COLLECTION_STATUS_ACTIVATED),
+                prices : Some(vec![PriceType {
+                    price_type : PRICE_TYPE_STANDARD,
+                    value : Coin {amount :Uint128::from(123900u64), denom : DEFAULT_PRICE_DENOM.to_string()},
+                    date_start : None, date_end : None, 
+                }]),
+                royalties : None, 
+                date_created : None,
+                date_updated : None, 
+                owner : Some(owner.to_string()), 
+                ..Default::default()
+            }
+        };
+
+        execute(deps.as_mut(), mock_env(), info.clone(), create_collection).expect("failed to create collection!!");
+
+        for x in 0..items_count {
+
+            let create_item = ExecuteMsg::CreateItem { item : ItemMsg {
+                collection_owner :  owner.to_string(),
+                collection_name : collection_name.to_string(),
+                collection_symbol : collection_symb.to_string(),
+                name : format!("Item #00{}",(x+1)),
+                traits : Vec::new(),
+                links : vec![Link{link_type: LINK_TYPE_IMAGE_URL, 
+                value:format!("https://rm.img/img_000{}.png",x) }],
+                description : None,
+                background_color : None,
+                date_created : None,
+                date_updated : None, 
+            }};
+
+            execute(deps.as_mut(), mock_env(), info.clone(), create_item).expect("failed to create item!!");
+        }
+
+        (deps, info)
+    }
+
+
+    // cargo test test_simulate_mint -- --show-output
+    #[test]
+    fn test_simulate_mint(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let sender : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+
+        let (mut deps, info) = setup_test_collection(owner, "Simulate Collection", "SIMC", 3);
+
+        let msg = QueryMsg::SimulateMint { owner : owner.to_string(), 
+            collection_name : "Simulate Collection".to_string(), 
+            collection_symbol : "SIMC".to_string(), 
+            price_type : None, sender : sender.to_string(), quantity : Some(2) };
+
+        let res = query(deps.as_ref(), mock_env(), msg).expect("failed to unwrap!!");
+
+        let result : SimulateMintResponse = from_binary(&res).unwrap();
+
+        println!("Simulated.mint::{:?}", result);
+
+        assert_eq!(result.price, Some(Coin { amount : Uint128::from(123900u64), 
+        denom : DEFAULT_PRICE_DENOM.to_string()}));
+
+        let paid = result.payouts.iter().fold(Uint128::zero(), |acc, p| acc + p.amount.amount);
+        assert_eq!(paid, Uint128::from(247800u64));
+
+        // the sender holds no fund in the mock querier
+        assert_eq!(result.error, Some("InsufficientFund".to_string()));
+
+        let msg = QueryMsg::SimulateMint { owner : owner.to_string(), 
+            collection_name : "Simulate Collection".to_string(), 
+            collection_symbol : "SIMC".to_string(), 
+            price_type : None, sender : sender.to_string(), quantity : Some(4) };
+
+        let res = query(deps.as_ref(), mock_env(), msg).expect("failed to unwrap!!");
+
+        let result : SimulateMintResponse = from_binary(&res).unwrap();
+
+        assert_eq!(result.error, Some("FailedToFindNft".to_string()));
+
+        // a blocked treasury fails the mint, so the simulation too
+        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::BlockAddresses { 
+            addresses : vec!["archway1nxqd7h869sj9pn0xyq0lqqqxjqx6vt550z4aj7".to_string()], reason : None })
+        .expect("failed to block addresses!!");
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::SimulateMint { owner : owner.to_string(), 
+            collection_name : "Simulate Collection".to_string(), collection_symbol : "SIMC".to_string(), 
+            price_type : None, sender : sender.to_string(), quantity : None }).expect("failed to unwrap!!");
+        let result : SimulateMintResponse = from_binary(&res).unwrap();
+        assert_eq!(result.error, Some("AddressBlocked".to_string()));
+    }
+
+
+    // cargo test test_fee_quote_and_unknown_fee -- --show-output
+    #[test]
+    fn test_fee_quote_and_unknown_fee(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+
+        let (mut deps, info) = setup_test_collection(owner, "Fee Collection", "FEEC", 0);
+
+        let msg = QueryMsg::FeeQuote { operation : FeeType::NftMinting, quantity : Some(3) };
+
+        let res = query(deps.as_ref(), mock_env(), msg).expect("failed to unwrap!!");
+
+        let result : FeeQuoteResponse = from_binary(&res).unwrap();
+
+        assert_eq!(result.fee_name, "NFT_MINTING_FEE".to_string());
+        assert_eq!(result.total, Some(Coin { amount : Uint128::from(19200u64), denom : "uconst".to_string()}));
+
+        let msg = QueryMsg::GetFeeSchedule {};
+
+        let res = query(deps.as_ref(), mock_env(), msg).expect("failed to unwrap!!");
+
+        let result : FeeScheduleResponse = from_binary(&res).unwrap();
+
+        assert_eq!(result.fees.len(), FeeType::all().len());
+
+        let update = ExecuteMsg::UpdateContractInfo { 
+            fees : Some(vec![Fee {name : "NFT_MINTNG_FEE".to_string(),
+            value : Coin { amount : Uint128::from(100u64), denom : "uconst".to_string()}}]),
+            treasuries : None, contracts : None, log_last_payment : None,
+        };
+
+        let res = execute(deps.as_mut(), mock_env(), info, update);
+
+        assert!(matches!(res, Err(crate::ContractError::UnknownFeeName { .. })));
+    }
+
+
+    // cargo test test_fee_tiers -- --show-output
+    #[test]
+    fn test_fee_tiers(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let creator : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+
+        let (mut deps, info) = setup_test_collection(owner, "Tier Collection", "TIER", 0);
+
+        let tier = FeeTier { name : "verified".to_string(), discounts : vec![
+            FeeDiscount { fee_type : FeeType::CreateCollection, percentage : 5000 },
+            FeeDiscount { fee_type : FeeType::NftMinting, percentage : FEE_DISCOUNT_MAX },
+        ]};
+
+        // only the contract admins can set the fee tiers
+        let res = execute(deps.as_mut(), mock_env(), mock_info(creator, &[]), 
+        ExecuteMsg::SetFeeTier { tier : tier.clone() });
+        assert!(matches!(res, Err(crate::ContractError::Unauthorized {})));
+
+        execute(deps.as_mut(), mock_env(), info.clone(), 
+        ExecuteMsg::SetFeeTier { tier : tier }).expect("failed to set fee tier!!");
+
+        // an override must be in the denom of the contract fee
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), 
+        ExecuteMsg::SetCreatorFees { creator : creator.to_string(), tier : None,
+        overrides : Some(vec![FeeOverride { fee_type : FeeType::CreateCollection, 
+            value : Coin { amount : Uint128::from(1u64), denom : "ufake".to_string()}}]) });
+        assert!(matches!(res, Err(crate::ContractError::InvalidInput { .. })));
+
+        execute(deps.as_mut(), mock_env(), info.clone(), 
+        ExecuteMsg::SetCreatorFees { creator : creator.to_string(), tier : Some("verified".to_string()),
+        overrides : Some(vec![FeeOverride { fee_type : FeeType::CreateItem, 
+            value : Coin { amount : Uint128::from(100u64), denom : "uconst".to_string()}}]) })
+        .expect("failed to set creator fees!!");
+
+        let msg = QueryMsg::GetEffectiveFees { address : creator.to_string() };
+
+        let res = query(deps.as_ref(), mock_env(), msg).expect("failed to unwrap!!");
+
+        let result : EffectiveFeesResponse = from_binary(&res).unwrap();
+
+        println!("Effective.fees::{:?}", result);
+
+        let fee_of = |f : FeeType| result.fees.iter().find(|q| q.operation == f).unwrap().fee.clone();
+
+        assert_eq!(fee_of(FeeType::CreateCollection), Some(Coin { amount : Uint128::from(750u64), denom : "uconst".to_string()}));
+        assert_eq!(fee_of(FeeType::CreateItem), Some(Coin { amount : Uint128::from(100u64), denom : "uconst".to_string()}));
+        assert_eq!(fee_of(FeeType::NftMinting), Some(Coin { amount : Uint128::zero(), denom : "uconst".to_string()}));
+
+        // the reduced fee is paid out in full across the contract treasuries
+        let res = execute(deps.as_mut(), mock_env(), mock_info(creator, &coins(750, DEFAULT_PRICE_DENOM)), 
+            ExecuteMsg::CreateCollection { collection : CollectionMsg { name : "Discounted Collection".to_string(), 
+            symbol : "DSCC".to_string(), ..Default::default() } }).expect("failed to create collection!!");
+
+        let paid = res.messages.iter().fold(Uint128::zero(), |acc, m| match &m.msg {
+            cosmwasm_std::CosmosMsg::Bank(BankMsg::Send { amount, .. }) => acc + amount[0].amount,
+            _ => acc,
+        });
+        assert_eq!(paid, Uint128::from(750u64));
+    }
+
+
+    // cargo test test_referral_rewards -- --show-output
+    #[test]
+    fn test_referral_rewards(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let buyer : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+        let referrer : &str = "archway1upspu5660q39adv768z8ffk44ta6lzd4nfw2zw";
+
+        let (mut deps, info) = setup_test_collection(owner, "Referral Collection", "REFC", 3);
+
+        let update_collection = ExecuteMsg::UpdateCollection { owner : None, collection:
+            CollectionMsg {
+                name : "Referral Collection".to_string(),
+                symbol : "REFC".to_string(),
+                description : None,
+                treasuries : None,
+                attributes : None, 
+                status : None,
+                prices : None,
+                royalties : None, 
+                referral_share : Some(1000),
+                date_created : None,
+                date_updated : None, 
+                owner : None, 
+                ..Default::default()
+            }
+        };
+
+        execute(deps.as_mut(), mock_env(), info, update_collection).expect("failed to update collection!!");
+
+        let mint = ExecuteMsg::MintItem { seed : "42".to_string(), owner : owner.to_string(), 
+            collection_name : "Referral Collection".to_string(), collection_symbol : "REFC".to_string(), 
+            price_type : None, token_uri : None, token_id : None, referrer : Some(referrer.to_string()),
+            promo_code : None };
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(buyer, &coins(200000, DEFAULT_PRICE_DENOM)), 
+        mint).expect("failed to mint!!");
+
+        let paid_to_referrer = res.messages.iter().any(|m| m.msg == cosmwasm_std::CosmosMsg::Bank(BankMsg::Send {
+            to_address : referrer.to_string(), amount : coins(12390, DEFAULT_PRICE_DENOM) }));
+
+        assert!(paid_to_referrer);
+
+        let msg = QueryMsg::GetReferralLeaderboard { start_after : None, limit : None };
+
+        let res = query(deps.as_ref(), mock_env(), msg).expect("failed to unwrap!!");
+
+        let result : ReferralLeaderboardResponse = from_binary(&res).unwrap();
+
+        assert_eq!(result.referrers, vec![ReferralStats { referrer : Addr::unchecked(referrer), 
+            mints : 1, earnings : coins(12390, DEFAULT_PRICE_DENOM) }]);
+
+        // the referrer ranks first, the owner referred by the buyer comes next
+        let mint = ExecuteMsg::MintItem { seed : "7".to_string(), owner : owner.to_string(), 
+            collection_name : "Referral Collection".to_string(), collection_symbol : "REFC".to_string(), 
+            price_type : None, token_uri : None, token_id : None, referrer : Some(buyer.to_string()),
+            promo_code : None };
+
+        execute(deps.as_mut(), mock_env(), mock_info(owner, &coins(200000, DEFAULT_PRICE_DENOM)), 
+        mint).expect("failed to mint!!");
+
+        let msg = QueryMsg::GetReferralLeaderboard { start_after : Some(referrer.to_string()), limit : None };
+
+        let result : ReferralLeaderboardResponse = from_binary(&query(deps.as_ref(), mock_env(), msg)
+        .expect("failed to unwrap!!")).unwrap();
+
+        assert_eq!(result.referrers.len(), 1);
+        assert_eq!(result.referrers[0].referrer, Addr::unchecked(buyer));
+
+        // a minter cannot refer itself
+        let mint = ExecuteMsg::MintItem { seed : "9".to_string(), owner : owner.to_string(), 
+            collection_name : "Referral Collection".to_string(), collection_symbol : "REFC".to_string(), 
+            price_type : None, token_uri : None, token_id : None, referrer : Some(buyer.to_string()),
+            promo_code : None };
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(buyer, &coins(200000, DEFAULT_PRICE_DENOM)), mint);
+        assert!(matches!(res, Err(crate::ContractError::InvalidInput { .. })));
+    }
+
+
+    // cargo test test_promo_codes -- --show-output
+    #[test]
+    fn test_promo_codes(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let buyer : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+
+        let (mut deps, info) = setup_test_collection(owner, "Promo Collection", "PRMC", 3);
+
+        let add_codes = ExecuteMsg::AddPromoCodes { collection_name : "Promo Collection".to_string(), 
+            collection_symbol : "PRMC".to_string(), codes : vec![PromoCode {
+                code_hash : promo_code_hash("HALFOFF"),
+                discount : PromoDiscount::Percentage(5000),
+                max_uses : Some(1),
+                used : 0,
+                expiry : None,
+            }]};
+
+        execute(deps.as_mut(), mock_env(), info.clone(), add_codes.clone()).expect("failed to add promo codes!!");
+
+        // adding the same code again would reset its uses
+        let res = execute(deps.as_mut(), mock_env(), info, add_codes);
+        assert!(matches!(res, Err(crate::ContractError::InvalidPromoCode { .. })));
+
+        let mint = |code : &str| ExecuteMsg::MintItem { seed : "42".to_string(), owner : owner.to_string(), 
+            collection_name : "Promo Collection".to_string(), collection_symbol : "PRMC".to_string(), 
+            price_type : None, token_uri : None, token_id : None, referrer : None, 
+            promo_code : Some(code.to_string()) };
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(buyer, &coins(70000, DEFAULT_PRICE_DENOM)), 
+        mint("WRONG"));
+        assert!(matches!(res, Err(crate::ContractError::InvalidPromoCode { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(buyer, &coins(70000, DEFAULT_PRICE_DENOM)), 
+        mint("HALFOFF")).expect("failed to mint with promo code!!");
+
+        let paid_to_treasuries = res.messages.iter().filter_map(|m| match &m.msg {
+            cosmwasm_std::CosmosMsg::Bank(b) => extract_amount_from_bank_msg(b),
+            _ => None,
+        }).fold(Uint128::zero(), |acc, a| acc + a);
+
+        // half of 123900 plus the minting fee of 6400
+        assert_eq!(paid_to_treasuries, Uint128::from(68350u64));
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(buyer, &coins(70000, DEFAULT_PRICE_DENOM)), 
+        mint("HALFOFF"));
+        assert!(matches!(res, Err(crate::ContractError::PromoCodeUsedUp { .. })));
+
+        let msg = QueryMsg::GetPromoCode { owner : owner.to_string(), 
+            collection_name : "Promo Collection".to_string(), collection_symbol : "PRMC".to_string(), 
+            code_hash : promo_code_hash("HALFOFF") };
+
+        let res = query(deps.as_ref(), mock_env(), msg).expect("failed to unwrap!!");
+
+        let result : PromoCodeResponse = from_binary(&res).unwrap();
+
+        assert_eq!(result.promo_code.unwrap().used, 1);
+        assert_eq!(result.remaining_uses, Some(0));
+    }
+
+
+    // cargo test test_dutch_auction_refund -- --show-output
+    #[test]
+    fn test_dutch_auction_refund(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let buyer1 : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+        let buyer2 : &str = "archway1upspu5660q39adv768z8ffk44ta6lzd4nfw2zw";
+
+        let (mut deps, info) = setup_test_collection(owner, "Auction Collection", "AUCC", 4);
+
+        let start = mock_env().block.time;
+
+        let mut collection = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(owner), 
+        "Auction Collection".to_string(), "AUCC".to_string()).unwrap().collection.unwrap();
+
+        collection.dutch_auction = Some(DutchAuction {
+            start_price : Coin { amount : Uint128::from(100000u64), denom : DEFAULT_PRICE_DENOM.to_string() },
+            floor_price : Coin { amount : Uint128::from(40000u64), denom : DEFAULT_PRICE_DENOM.to_string() },
+            decay_amount : Uint128::from(10000u64),
+            decay_interval : 100,
+            start_time : start,
+            end_time : start.plus_seconds(1000),
+            refund_to_clearing_price : Some(true),
+        });
+
+        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::UpdateCollection { owner : None, collection : collection.into() })
+        .expect("failed to update collection!!");
+
+        let mint = || ExecuteMsg::MintItem { seed : "42".to_string(), owner : owner.to_string(), 
+            collection_name : "Auction Collection".to_string(), collection_symbol : "AUCC".to_string(), 
+            price_type : None, token_uri : None, token_id : None, referrer : None, promo_code : None };
+
+        execute(deps.as_mut(), mock_env(), mock_info(buyer1, &coins(106400, DEFAULT_PRICE_DENOM)), mint())
+        .expect("failed to mint at start price!!");
+
+        let mut later = mock_env();
+        later.block.time = start.plus_seconds(350);
+
+        let res = query(deps.as_ref(), later.clone(), QueryMsg::GetCurrentPrice { owner : owner.to_string(), 
+            collection_name : "Auction Collection".to_string(), collection_symbol : "AUCC".to_string(), 
+            price_type : None }).expect("failed to unwrap!!");
+
+        let result : CurrentPriceResponse = from_binary(&res).unwrap();
+        assert_eq!(result.price.unwrap().amount, Uint128::from(70000u64));
+        assert!(result.auction_active);
+
+        execute(deps.as_mut(), later.clone(), mock_info(buyer2, &coins(76400, DEFAULT_PRICE_DENOM)), mint())
+        .expect("failed to mint at decayed price!!");
+
+        // a delegated mint counts as an auction purchase of its recipient, with nothing paid
+        execute(deps.as_mut(), mock_env(), mock_info(owner, &[]), ExecuteMsg::AuthorizeMinter { 
+            collection_name : "Auction Collection".to_string(), collection_symbol : "AUCC".to_string(), 
+            minter : owner.to_string(), price_type : None, max_mints : None })
+        .expect("failed to authorize minter!!");
+
+        execute(deps.as_mut(), later.clone(), mock_info(owner, &coins(6400, DEFAULT_PRICE_DENOM)), ExecuteMsg::MintFor { 
+            seed : "7".to_string(), owner : owner.to_string(), collection_name : "Auction Collection".to_string(), 
+            collection_symbol : "AUCC".to_string(), recipient : buyer2.to_string(), token_uri : None, token_id : None })
+        .expect("failed to mint for buyer!!");
+
+        let purchase = crate::indexes::AUCTION_PURCHASES_STORE.load(deps.as_ref().storage, 
+            (Addr::unchecked(owner), collection_id("Auction Collection".to_string(), "AUCC".to_string()), 
+            Addr::unchecked(buyer2))).expect("failed to load purchase!!");
+        assert_eq!(purchase.count, 2);
+        assert_eq!(purchase.paid, Uint128::from(70000u64));
+
+        let res = execute(deps.as_mut(), later, mock_info(buyer1, &[]), ExecuteMsg::ClaimAuctionRefund { 
+            owner : owner.to_string(), collection_name : "Auction Collection".to_string(), 
+            collection_symbol : "AUCC".to_string() });
+        assert!(matches!(res, Err(crate::ContractError::AuctionNotEnded { .. })));
+
+        let mut ended = mock_env();
+        ended.block.time = start.plus_seconds(1001);
+
+        let res = query(deps.as_ref(), ended.clone(), QueryMsg::GetAuctionStatus { owner : owner.to_string(), 
+            collection_name : "Auction Collection".to_string(), collection_symbol : "AUCC".to_string(), 
+            buyer : Some(buyer1.to_string()) }).expect("failed to unwrap!!");
+
+        let result : AuctionStatusResponse = from_binary(&res).unwrap();
+        assert!(result.ended);
+        assert_eq!(result.clearing_price.unwrap().amount, Uint128::from(70000u64));
+        assert_eq!(result.refund.unwrap().amount, Uint128::from(30000u64));
+
+        let mut collection = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(owner), 
+        "Auction Collection".to_string(), "AUCC".to_string()).unwrap().collection.unwrap();
+
+        collection.status = Some(COLLECTION_STATUS_PAUSED);
+
+        let pause_collection = ExecuteMsg::UpdateCollection { owner : None, collection : collection.into() };
+
+        execute(deps.as_mut(), ended.clone(), mock_info(owner, &[]), pause_collection).expect("failed to pause collection!!");
+
+        let remove_collection = ExecuteMsg::RemoveCollection { name : "Auction Collection".to_string(), 
+            symbol : "AUCC".to_string() };
+
+        // the refunds and proceeds held by the contract must be claimed first
+        let res = execute(deps.as_mut(), ended.clone(), mock_info(owner, &[]), remove_collection.clone());
+        assert!(matches!(res, Err(crate::ContractError::InvalidCollectionStatus { ref text }) if text.contains("auction")));
+
+        let res = execute(deps.as_mut(), ended.clone(), mock_info(buyer1, &[]), ExecuteMsg::ClaimAuctionRefund { 
+            owner : owner.to_string(), collection_name : "Auction Collection".to_string(), 
+            collection_symbol : "AUCC".to_string() }).expect("failed to claim refund!!");
+
+        let refunded = res.messages.iter().filter_map(|m| match &m.msg {
+            cosmwasm_std::CosmosMsg::Bank(b) => extract_amount_from_bank_msg(b),
+            _ => None,
+        }).fold(Uint128::zero(), |acc, a| acc + a);
+
+        assert_eq!(refunded, Uint128::from(30000u64));
+
+        let res = execute(deps.as_mut(), ended, mock_info(owner, &[]), ExecuteMsg::ClaimAuctionProceeds { 
+            collection_name : "Auction Collection".to_string(), collection_symbol : "AUCC".to_string() })
+        .expect("failed to claim proceeds!!");
+
+        let proceeds = res.messages.iter().filter_map(|m| match &m.msg {
+            cosmwasm_std::CosmosMsg::Bank(b) => extract_amount_from_bank_msg(b),
+            _ => None,
+        }).fold(Uint128::zero(), |acc, a| acc + a);
+
+        // (100000 - 40000 - 30000) + (70000 - 40000) held above the floor price
+        assert_eq!(proceeds, Uint128::from(60000u64));
+
+        // the other buyer paid the clearing price, so nothing is left to claim
+        execute(deps.as_mut(), ended, mock_info(owner, &[]), remove_collection).expect("failed to remove collection!!");
+    }
+
+
+    // cargo test test_bonding_curve_prices -- --show-output
+    #[test]
+    fn test_bonding_curve_prices(){
+
+        let linear = BondingCurve { curve_type : CURVE_TYPE_LINEAR, 
+            base_price : Coin { amount : Uint128::from(1000u64), denom : DEFAULT_PRICE_DENOM.to_string() },
+            increment : Some(Uint128::from(250u64)), growth_percentage : None };
+
+        assert_eq!(linear.price_at(0).unwrap().amount, Uint128::from(1000u64));
+        assert_eq!(linear.price_at(4).unwrap().amount, Uint128::from(2000u64));
+
+        let exponential = BondingCurve { curve_type : CURVE_TYPE_EXPONENTIAL, 
+            base_price : Coin { amount : Uint128::from(1000000u64), denom : DEFAULT_PRICE_DENOM.to_string() },
+            increment : None, growth_percentage : Some(1000) };
+
+        // 10% growth per mint
+        assert_eq!(exponential.price_at(0).unwrap().amount, Uint128::from(1000000u64));
+        assert_eq!(exponential.price_at(1).unwrap().amount, Uint128::from(1100000u64));
+        assert_eq!(exponential.price_at(2).unwrap().amount, Uint128::from(1210000u64));
+
+        // large supplies overflow instead of panicking or wrapping
+        assert!(exponential.price_at(u64::MAX).is_err());
+
+        let steep = BondingCurve { curve_type : CURVE_TYPE_LINEAR, 
+            base_price : Coin { amount : Uint128::MAX, denom : DEFAULT_PRICE_DENOM.to_string() },
+            increment : Some(Uint128::from(1u64)), growth_percentage : None };
+
+        assert!(steep.price_at(0).is_ok());
+        assert!(steep.price_at(1).is_err());
+    }
+
+
+    // cargo test test_bonding_curve_mint -- --show-output
+    #[test]
+    fn test_bonding_curve_mint(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let buyer : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+
+        let (mut deps, info) = setup_test_collection(owner, "Curve Collection", "CRVC", 3);
+
+        let mut collection = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(owner), 
+        "Curve Collection".to_string(), "CRVC".to_string()).unwrap().collection.unwrap();
+
+        collection.bonding_curve = Some(BondingCurve { curve_type : 9, 
+            base_price : Coin { amount : Uint128::from(10000u64), denom : DEFAULT_PRICE_DENOM.to_string() },
+            increment : Some(Uint128::from(5000u64)), growth_percentage : None });
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), 
+        ExecuteMsg::UpdateCollection { owner : None, collection : collection.clone().into() });
+        assert!(matches!(res, Err(crate::ContractError::InvalidBondingCurve { .. })));
+
+        collection.bonding_curve = Some(BondingCurve { curve_type : CURVE_TYPE_LINEAR, 
+            base_price : Coin { amount : Uint128::from(10000u64), denom : DEFAULT_PRICE_DENOM.to_string() },
+            increment : Some(Uint128::from(5000u64)), growth_percentage : None });
+
+        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::UpdateCollection { owner : None, collection : collection.into() })
+        .expect("failed to update collection!!");
+
+        let mint = || ExecuteMsg::MintItem { seed : "42".to_string(), owner : owner.to_string(), 
+            collection_name : "Curve Collection".to_string(), collection_symbol : "CRVC".to_string(), 
+            price_type : None, token_uri : None, token_id : None, referrer : None, promo_code : None };
+
+        execute(deps.as_mut(), mock_env(), mock_info(buyer, &coins(16400, DEFAULT_PRICE_DENOM)), mint())
+        .expect("failed to mint at base price!!");
+
+        // the second mint is priced at 15000 
+        let res = execute(deps.as_mut(), mock_env(), mock_info(buyer, &coins(12000, DEFAULT_PRICE_DENOM)), mint());
+        assert!(matches!(res, Err(crate::ContractError::InsufficientFund { .. })));
+
+        let msg = QueryMsg::GetBondingCurveQuote { owner : owner.to_string(), 
+            collection_name : "Curve Collection".to_string(), collection_symbol : "CRVC".to_string(), 
+            quantity : Some(2) };
+
+        let res = query(deps.as_ref(), mock_env(), msg).expect("failed to unwrap!!");
+
+        let result : BondingCurveQuoteResponse = from_binary(&res).unwrap();
+
+        assert_eq!(result.minted, 1);
+        assert_eq!(result.prices.iter().map(|p| p.amount).collect::<Vec<Uint128>>(), 
+        vec![Uint128::from(15000u64), Uint128::from(20000u64)]);
+        assert_eq!(result.total.unwrap().amount, Uint128::from(35000u64));
+    }
+
+
+    // cargo test test_mint_phases -- --show-output
+    #[test]
+    fn test_mint_phases(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let og : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+        let other : &str = "archway1upspu5660q39adv768z8ffk44ta6lzd4nfw2zw";
+
+        let (mut deps, info) = setup_test_collection(owner, "Phased Collection", "PHSC", 3);
+
+        let start = mock_env().block.time;
+
+        let phase = |name : &str, amount : u64, from : u64, to : Option<u64>, allowlist_only : Option<bool>, 
+            max : Option<u32>| MintPhase { name : name.to_string(), 
+            price : Coin { amount : Uint128::from(amount), denom : DEFAULT_PRICE_DENOM.to_string() },
+            start_time : start.plus_seconds(from), end_time : to.map(|t| start.plus_seconds(t)),
+            allowlist_only : allowlist_only, max_per_wallet : max };
+
+        let mut collection = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(owner), 
+        "Phased Collection".to_string(), "PHSC".to_string()).unwrap().collection.unwrap();
+
+        collection.mint_phases = Some(vec![
+            phase("OG", 50000, 0, Some(200), Some(true), Some(1)),
+            phase("PUBLIC", 123900, 100, None, None, None),
+        ]);
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), 
+        ExecuteMsg::UpdateCollection { owner : None, collection : collection.clone().into() });
+        assert!(matches!(res, Err(crate::ContractError::InvalidMintPhases { .. })));
+
+        collection.mint_phases = Some(vec![
+            phase("PUBLIC", 123900, 100, None, None, None),
+            phase("OG", 50000, 0, Some(100), Some(true), Some(1)),
+        ]);
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::UpdateCollection { owner : None, collection : collection.into() })
+        .expect("failed to update collection!!");
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(other, &[]), ExecuteMsg::AddToPhaseAllowlist { 
+            owner : owner.to_string(), collection_name : "Phased Collection".to_string(), 
+            collection_symbol : "PHSC".to_string(), phase : "OG".to_string(), addresses : vec![other.to_string()] });
+        assert!(res.is_err());
+
+        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::AddToPhaseAllowlist { 
+            owner : owner.to_string(), collection_name : "Phased Collection".to_string(), 
+            collection_symbol : "PHSC".to_string(), phase : "OG".to_string(), addresses : vec![og.to_string()] })
+        .expect("failed to add to phase allowlist!!");
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetPhaseAllowlist { owner : owner.to_string(), 
+            collection_name : "Phased Collection".to_string(), collection_symbol : "PHSC".to_string(), 
+            phase : "OG".to_string(), start_after : None, limit : None }).expect("failed to unwrap!!");
+
+        let result : PhaseAllowlistResponse = from_binary(&res).unwrap();
+        assert_eq!(result.addresses, vec![Addr::unchecked(og)]);
+
+        // the phase sets the price, so another price type is refused
+        let res = execute(deps.as_mut(), mock_env(), mock_info(og, &coins(60000, DEFAULT_PRICE_DENOM)), 
+        ExecuteMsg::MintItem { seed : "42".to_string(), owner : owner.to_string(), 
+            collection_name : "Phased Collection".to_string(), collection_symbol : "PHSC".to_string(), 
+            price_type : Some(PRICE_TYPE_WL), token_uri : None, token_id : None, referrer : None, promo_code : None });
+        assert!(matches!(res, Err(crate::ContractError::PriceTypeNotFound { .. })));
+
+        let mint = || ExecuteMsg::MintItem { seed : "42".to_string(), owner : owner.to_string(), 
+            collection_name : "Phased Collection".to_string(), collection_symbol : "PHSC".to_string(), 
+            price_type : Some(PRICE_TYPE_STANDARD), token_uri : None, token_id : None, referrer : None, promo_code : None };
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(other, &coins(60000, DEFAULT_PRICE_DENOM)), mint());
+        assert!(matches!(res, Err(crate::ContractError::NotEligibleForMintPhase { .. })));
+
+        execute(deps.as_mut(), mock_env(), mock_info(og, &coins(60000, DEFAULT_PRICE_DENOM)), mint())
+        .expect("failed to mint in OG phase!!");
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(og, &coins(60000, DEFAULT_PRICE_DENOM)), mint());
+        assert!(matches!(res, Err(crate::ContractError::MintPhaseWalletLimitReached { .. })));
+
+        let mut public = mock_env();
+        public.block.time = start.plus_seconds(150);
+
+        let res = query(deps.as_ref(), public.clone(), QueryMsg::GetActivePhase { owner : owner.to_string(), 
+            collection_name : "Phased Collection".to_string(), collection_symbol : "PHSC".to_string(), 
+            wallet : Some(other.to_string()) }).expect("failed to unwrap!!");
+
+        let result : ActivePhaseResponse = from_binary(&res).unwrap();
+        assert_eq!(result.phase.unwrap().name, "PUBLIC".to_string());
+        assert_eq!(result.eligible, Some(true));
+
+        let res = execute(deps.as_mut(), public.clone(), mock_info(other, &coins(60000, DEFAULT_PRICE_DENOM)), mint());
+        assert!(matches!(res, Err(crate::ContractError::InsufficientFund { .. })));
+
+        execute(deps.as_mut(), public, mock_info(other, &coins(130300, DEFAULT_PRICE_DENOM)), mint())
+        .expect("failed to mint in public phase!!");
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetMintPhases { owner : owner.to_string(), 
+            collection_name : "Phased Collection".to_string(), collection_symbol : "PHSC".to_string() })
+        .expect("failed to unwrap!!");
+
+        let result : MintPhasesResponse = from_binary(&res).unwrap();
+        assert_eq!(result.phases.iter().map(|p| p.name.clone()).collect::<Vec<String>>(), 
+        vec!["OG".to_string(), "PUBLIC".to_string()]);
+    }
+
+
+    // cargo test test_scheduled_launch -- --show-output
+    #[test]
+    fn test_scheduled_launch(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let buyer : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+
+        let (mut deps, info) = setup_test_collection(owner, "Scheduled Collection", "SCHC", 3);
+
+        let start = mock_env().block.time;
+
+        let mut collection = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(owner), 
+        "Scheduled Collection".to_string(), "SCHC".to_string()).unwrap().collection.unwrap();
+
+        collection.launch_at = Some(start.plus_seconds(200));
+        collection.close_at = Some(start.plus_seconds(100));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), 
+        ExecuteMsg::UpdateCollection { owner : None, collection : collection.clone().into() });
+        assert!(matches!(res, Err(crate::ContractError::InvalidLaunchWindow { .. })));
+
+        collection.launch_at = Some(start.plus_seconds(100));
+        collection.close_at = Some(start.plus_seconds(200));
+
+        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::UpdateCollection { owner : None, collection : collection.into() })
+        .expect("failed to update collection!!");
+
+        let active_count = |deps : Deps, time : u64| {
+            let mut env = mock_env();
+            env.block.time = start.plus_seconds(time);
+
+            let res = query(deps, env, QueryMsg::GetActiveCollections { keyword : None, category : None, 
+                start : None, limit : None, verified_only : None }).expect("failed to unwrap!!");
+
+            let result : CollectionsWithParamsResponse = from_binary(&res).unwrap();
+            result.collections.len()
+        };
+
+        assert_eq!(active_count(deps.as_ref(), 0), 0);
+        assert_eq!(active_count(deps.as_ref(), 150), 1);
+        assert_eq!(active_count(deps.as_ref(), 250), 0);
+
+        // the single collection query reports the same effective status
+        let status_at = |deps : Deps, time : u64| {
+            let mut env = mock_env();
+            env.block.time = start.plus_seconds(time);
+
+            get_collection(deps, env, Addr::unchecked(owner), "Scheduled Collection".to_string(), 
+            "SCHC".to_string()).unwrap().collection.unwrap().status
+        };
+
+        assert_eq!(status_at(deps.as_ref(), 0), Some(COLLECTION_STATUS_DRAFT));
+        assert_eq!(status_at(deps.as_ref(), 150), Some(COLLECTION_STATUS_ACTIVATED));
+        assert_eq!(status_at(deps.as_ref(), 250), Some(COLLECTION_STATUS_CLOSED));
+
+        let mint = || ExecuteMsg::MintItem { seed : "42".to_string(), owner : owner.to_string(), 
+            collection_name : "Scheduled Collection".to_string(), collection_symbol : "SCHC".to_string(), 
+            price_type : None, token_uri : None, token_id : None, referrer : None, promo_code : None };
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(buyer, &coins(130300, DEFAULT_PRICE_DENOM)), mint());
+        assert!(matches!(res, Err(crate::ContractError::NftStatusIsNotReadyForMinting { .. })));
+
+        let mut launched = mock_env();
+        launched.block.time = start.plus_seconds(150);
+
+        execute(deps.as_mut(), launched, mock_info(buyer, &coins(130300, DEFAULT_PRICE_DENOM)), mint())
+        .expect("failed to mint after launch!!");
+
+        let mut closed = mock_env();
+        closed.block.time = start.plus_seconds(200);
+
+        let res = execute(deps.as_mut(), closed, mock_info(buyer, &coins(130300, DEFAULT_PRICE_DENOM)), mint());
+        assert!(matches!(res, Err(crate::ContractError::NftStatusIsNotReadyForMinting { .. })));
+    }
+
+
+    // cargo test test_status_transitions -- --show-output
+    #[test]
+    fn test_status_transitions(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let buyer : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+
+        let (mut deps, info) = setup_test_collection(owner, "Lifecycle Collection", "LFCC", 1);
+
+        let collection = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(owner), 
+        "Lifecycle Collection".to_string(), "LFCC".to_string()).unwrap().collection.unwrap();
+
+        let with_status = |status : u8| ExecuteMsg::UpdateCollection { owner : None, collection : CollectionMsg { 
+            status : Some(status), ..collection.clone().into() } };
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), with_status(COLLECTION_STATUS_DRAFT));
+        match res {
+            Err(crate::ContractError::IllegalStatusTransition { text }) => 
+            assert_eq!(text, "Illegal status transition from activated to draft!".to_string()),
+            _ => panic!("expected an illegal status transition!!"),
+        }
+
+        execute(deps.as_mut(), mock_env(), info.clone(), with_status(COLLECTION_STATUS_PAUSED))
+        .expect("failed to pause collection!!");
+
+        execute(deps.as_mut(), mock_env(), info.clone(), with_status(COLLECTION_STATUS_ACTIVATED))
+        .expect("failed to re-activate collection!!");
+
+        execute(deps.as_mut(), mock_env(), mock_info(buyer, &coins(130300, DEFAULT_PRICE_DENOM)), 
+        ExecuteMsg::MintItem { seed : "42".to_string(), owner : owner.to_string(), 
+            collection_name : "Lifecycle Collection".to_string(), collection_symbol : "LFCC".to_string(), 
+            price_type : None, token_uri : None, token_id : None, referrer : None, promo_code : None })
+        .expect("failed to mint the last item!!");
+
+        let collection = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(owner), 
+        "Lifecycle Collection".to_string(), "LFCC".to_string()).unwrap().collection.unwrap();
+
+        assert_eq!(collection.status, Some(COLLECTION_STATUS_SOLD_OUT));
+
+        execute(deps.as_mut(), mock_env(), info.clone(), with_status(COLLECTION_STATUS_ARCHIVED))
+        .expect("failed to archive collection!!");
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), with_status(COLLECTION_STATUS_ACTIVATED));
+        assert!(matches!(res, Err(crate::ContractError::IllegalStatusTransition { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::CreateCollection { 
+            collection : CollectionMsg { symbol : "LFCA".to_string(), status : Some(COLLECTION_STATUS_ARCHIVED), 
+            ..collection.clone().into() } });
+        assert!(matches!(res, Err(crate::ContractError::InvalidCollectionStatus { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::CreateCollection { 
+            collection : CollectionMsg { symbol : "LFCD".to_string(), status : Some(COLLECTION_STATUS_DEACTIVATED), 
+            ..collection.clone().into() } });
+        assert!(matches!(res, Err(crate::ContractError::InvalidCollectionStatus { .. })));
+    }
+
+
+    // cargo test test_collection_review -- --show-output
+    #[test]
+    fn test_collection_review(){
+
+        let admin : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let creator : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+
+        let (mut deps, admin_info) = setup_test_collection(admin, "Admin Collection", "ADMC", 0);
+
+        let creator_info = mock_info(creator, &coins(134000, DEFAULT_PRICE_DENOM));
+
+        let collection = CollectionMsg { name : "Creator Collection".to_string(), symbol : "CRTC".to_string(),
+            owner : None, status : Some(COLLECTION_STATUS_DRAFT), ..get_collection(deps.as_ref(), mock_env(), 
+            Addr::unchecked(admin), "Admin Collection".to_string(), "ADMC".to_string()).unwrap().collection.unwrap().into() };
+
+        let res = execute(deps.as_mut(), mock_env(), creator_info.clone(), 
+        ExecuteMsg::CreateCollection { collection : CollectionMsg { status : Some(COLLECTION_STATUS_ACTIVATED), 
+            ..collection.clone() } });
+        assert!(matches!(res, Err(crate::ContractError::CollectionNotApproved { .. })));
+
+        execute(deps.as_mut(), mock_env(), creator_info.clone(), 
+        ExecuteMsg::CreateCollection { collection : collection.clone() }).expect("failed to create collection!!");
+
+        let submit = || ExecuteMsg::SubmitForReview { collection_name : "Creator Collection".to_string(), 
+            collection_symbol : "CRTC".to_string() };
+
+        execute(deps.as_mut(), mock_env(), creator_info.clone(), submit()).expect("failed to submit for review!!");
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetReviewQueue { start : None, limit : None })
+        .expect("failed to unwrap!!");
+        let result : CollectionsWithParamsResponse = from_binary(&res).unwrap();
+        assert_eq!(result.collections.len(), 1);
+        assert_eq!(result.collections[0].name, "Creator Collection".to_string());
+
+        let res = execute(deps.as_mut(), mock_env(), creator_info.clone(), ExecuteMsg::ApproveCollection { 
+            owner : creator.to_string(), collection_name : "Creator Collection".to_string(), 
+            collection_symbol : "CRTC".to_string(), reason : None });
+        assert!(matches!(res, Err(crate::ContractError::Unauthorized { .. })));
+
+        execute(deps.as_mut(), mock_env(), admin_info.clone(), ExecuteMsg::RejectCollection { 
+            owner : creator.to_string(), collection_name : "Creator Collection".to_string(), 
+            collection_symbol : "CRTC".to_string(), reason : "Missing artwork".to_string() })
+        .expect("failed to reject collection!!");
+
+        let activate = || ExecuteMsg::UpdateCollection { owner : None, collection : CollectionMsg { 
+            status : Some(COLLECTION_STATUS_ACTIVATED), ..collection.clone() } };
+
+        let res = execute(deps.as_mut(), mock_env(), creator_info.clone(), activate());
+        assert!(matches!(res, Err(crate::ContractError::CollectionNotApproved { .. })));
+
+        execute(deps.as_mut(), mock_env(), creator_info.clone(), submit()).expect("failed to resubmit for review!!");
+
+        execute(deps.as_mut(), mock_env(), admin_info, ExecuteMsg::ApproveCollection { 
+            owner : creator.to_string(), collection_name : "Creator Collection".to_string(), 
+            collection_symbol : "CRTC".to_string(), reason : Some("Looks good".to_string()) })
+        .expect("failed to approve collection!!");
+
+        execute(deps.as_mut(), mock_env(), creator_info.clone(), activate()).expect("failed to activate collection!!");
+
+        let collection = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(creator), 
+        "Creator Collection".to_string(), "CRTC".to_string()).unwrap().collection.unwrap();
+
+        assert_eq!(collection.status, Some(COLLECTION_STATUS_ACTIVATED));
+        assert_eq!(collection.review.clone().unwrap().reason, Some("Looks good".to_string()));
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetReviewQueue { start : None, limit : None })
+        .expect("failed to unwrap!!");
+        let result : CollectionsWithParamsResponse = from_binary(&res).unwrap();
+        assert_eq!(result.collections.len(), 0);
+
+        // changing the approved content sends the collection back to review
+        execute(deps.as_mut(), mock_env(), creator_info.clone(), ExecuteMsg::UpdateCollection { owner : None, 
+            collection : CollectionMsg { description : Some("Swapped artwork".to_string()), ..collection.clone().into() } })
+        .expect("failed to update collection!!");
+
+        let collection = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(creator), 
+        "Creator Collection".to_string(), "CRTC".to_string()).unwrap().collection.unwrap();
+
+        assert_eq!(collection.status, Some(COLLECTION_STATUS_PENDING_REVIEW));
+        assert_eq!(collection.review.clone().unwrap().status, REVIEW_STATUS_PENDING);
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetReviewQueue { start : None, limit : None })
+        .expect("failed to unwrap!!");
+        let result : CollectionsWithParamsResponse = from_binary(&res).unwrap();
+        assert_eq!(result.collections.len(), 1);
+
+        let res = execute(deps.as_mut(), mock_env(), creator_info, ExecuteMsg::UpdateCollection { owner : None, 
+            collection : CollectionMsg { status : Some(COLLECTION_STATUS_ACTIVATED), ..collection.into() } });
+        assert!(matches!(res, Err(crate::ContractError::CollectionNotApproved { .. })));
+    }
+
+
+    // cargo test test_moderate_collection -- --show-output
+    #[test]
+    fn test_moderate_collection(){
+
+        let admin : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let buyer : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+
+        let (mut deps, info) = setup_test_collection(admin, "Moderated Collection", "MODC", 2);
+
+        let moderate = |action : ModerationAction| ExecuteMsg::ModerateCollection { owner : admin.to_string(), 
+            name : "Moderated Collection".to_string(), symbol : "MODC".to_string(), action : action, 
+            reason : Some("Reported as infringing".to_string()) };
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(buyer, &[]), moderate(ModerationAction::Suspend));
+        assert!(matches!(res, Err(crate::ContractError::Unauthorized { .. })));
+
+        execute(deps.as_mut(), mock_env(), info.clone(), moderate(ModerationAction::Suspend))
+        .expect("failed to suspend collection!!");
+
+        let mint = || ExecuteMsg::MintItem { seed : "42".to_string(), owner : admin.to_string(), 
+            collection_name : "Moderated Collection".to_string(), collection_symbol : "MODC".to_string(), 
+            price_type : None, token_uri : None, token_id : None, referrer : None, promo_code : None };
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(buyer, &coins(130300, DEFAULT_PRICE_DENOM)), mint());
+        assert!(matches!(res, Err(crate::ContractError::CollectionSuspended { .. })));
+
+        let active_count = |deps : Deps| {
+            let res = query(deps, mock_env(), QueryMsg::GetActiveCollections { keyword : None, category : None, 
+                start : None, limit : None, verified_only : None }).expect("failed to unwrap!!");
+            let result : CollectionsWithParamsResponse = from_binary(&res).unwrap();
+            result.collections.len()
+        };
+
+        let all_count = |deps : Deps| {
+            let res = query(deps, mock_env(), QueryMsg::GetAllCollections { start_after : None, limit : None })
+            .expect("failed to unwrap!!");
+            let result : CollectionsResponse = from_binary(&res).unwrap();
+            result.collections.len()
+        };
+
+        assert_eq!(active_count(deps.as_ref()), 0);
+        assert_eq!(all_count(deps.as_ref()), 0);
+
+        execute(deps.as_mut(), mock_env(), info.clone(), moderate(ModerationAction::Unsuspend))
+        .expect("failed to unsuspend collection!!");
+
+        assert_eq!(active_count(deps.as_ref()), 1);
+        assert_eq!(all_count(deps.as_ref()), 1);
+
+        execute(deps.as_mut(), mock_env(), mock_info(buyer, &coins(130300, DEFAULT_PRICE_DENOM)), mint())
+        .expect("failed to mint after unsuspending!!");
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetModeration { owner : admin.to_string(), 
+            collection_name : "Moderated Collection".to_string(), collection_symbol : "MODC".to_string() })
+        .expect("failed to unwrap!!");
+
+        let result : ModerationResponse = from_binary(&res).unwrap();
+        let moderation = result.moderation.unwrap();
+
+        assert!(!moderation.suspended);
+        assert_eq!(moderation.records.len(), 2);
+
+        for _ in 0..MODERATION_RECORDS_MAX {
+            execute(deps.as_mut(), mock_env(), info.clone(), moderate(ModerationAction::Flag))
+            .expect("failed to flag collection!!");
+        }
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetModeration { owner : admin.to_string(), 
+            collection_name : "Moderated Collection".to_string(), collection_symbol : "MODC".to_string() })
+        .expect("failed to unwrap!!");
+
+        let result : ModerationResponse = from_binary(&res).unwrap();
+        let moderation = result.moderation.unwrap();
+
+        assert_eq!(moderation.records.len(), MODERATION_RECORDS_MAX);
+        assert_eq!(moderation.records[0].action, ModerationAction::Flag);
+    }
+
+
+    // cargo test test_verified_and_featured -- --show-output
+    #[test]
+    fn test_verified_and_featured(){
+
+        let admin : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let other : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+
+        let (mut deps, info) = setup_test_collection(admin, "Featured Collection", "FTRC", 1);
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(other, &[]), 
+        ExecuteMsg::VerifyCreator { creator : other.to_string(), note : None });
+        assert!(matches!(res, Err(crate::ContractError::Unauthorized { .. })));
+
+        let active = |deps : Deps, verified_only : bool| {
+            let res = query(deps, mock_env(), QueryMsg::GetActiveCollections { keyword : None, category : None, 
+                start : None, limit : None, verified_only : Some(verified_only) }).expect("failed to unwrap!!");
+            let result : CollectionsWithParamsResponse = from_binary(&res).unwrap();
+            result
+        };
+
+        assert_eq!(active(deps.as_ref(), true).collections.len(), 0);
+
+        execute(deps.as_mut(), mock_env(), info.clone(), 
+        ExecuteMsg::VerifyCreator { creator : admin.to_string(), note : Some("KYC done".to_string()) })
+        .expect("failed to verify creator!!");
+
+        let result = active(deps.as_ref(), true);
+        assert_eq!(result.collections.len(), 1);
+        assert_eq!(result.verified_owners, Some(vec![Addr::unchecked(admin)]));
+
+        let result = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(admin), 
+        "Featured Collection".to_string(), "FTRC".to_string()).unwrap();
+        assert!(result.verified);
+
+        let expiry = mock_env().block.time.plus_seconds(100);
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::FeatureCollection { 
+            owner : admin.to_string(), collection_name : "Featured Collection".to_string(), 
+            collection_symbol : "FTRC".to_string(), position : 1, expiry : Some(expiry) })
+        .expect("failed to feature collection!!");
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetFeaturedCollections { start : None, limit : None }).expect("failed to unwrap!!");
+        let result : FeaturedCollectionsResponse = from_binary(&res).unwrap();
+        assert_eq!(result.featured.len(), 1);
+        assert!(result.featured[0].verified);
+        assert_eq!(result.featured[0].collection.clone().unwrap().symbol, "FTRC".to_string());
+
+        let mut expired = mock_env();
+        expired.block.time = expiry;
+
+        let res = query(deps.as_ref(), expired, QueryMsg::GetFeaturedCollections { start : None, limit : None }).expect("failed to unwrap!!");
+        let result : FeaturedCollectionsResponse = from_binary(&res).unwrap();
+        assert_eq!(result.featured.len(), 0);
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetFeaturedCollections { start : Some(1), limit : None })
+        .expect("failed to unwrap!!");
+        let result : FeaturedCollectionsResponse = from_binary(&res).unwrap();
+        assert_eq!(result.featured.len(), 0);
+
+        // a removed collection is no longer featured
+        let collection = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(admin), 
+        "Featured Collection".to_string(), "FTRC".to_string()).unwrap().collection.unwrap();
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::UpdateCollection { owner : None, collection : CollectionMsg { 
+            status : Some(COLLECTION_STATUS_PAUSED), ..collection.into() } }).expect("failed to pause collection!!");
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::ModerateCollection { owner : admin.to_string(), 
+            name : "Featured Collection".to_string(), symbol : "FTRC".to_string(), action : ModerationAction::Flag, 
+            reason : None }).expect("failed to flag collection!!");
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::RemoveCollection { 
+            name : "Featured Collection".to_string(), symbol : "FTRC".to_string() })
+        .expect("failed to remove collection!!");
+
+        // the other records of a removed collection go with it
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetModeration { owner : admin.to_string(), 
+            collection_name : "Featured Collection".to_string(), collection_symbol : "FTRC".to_string() })
+        .expect("failed to unwrap!!");
+        let result : ModerationResponse = from_binary(&res).unwrap();
+        assert!(result.moderation.is_none());
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetFeaturedCollections { start : None, limit : None })
+        .expect("failed to unwrap!!");
+        let result : FeaturedCollectionsResponse = from_binary(&res).unwrap();
+        assert_eq!(result.featured.len(), 0);
+
+        let res = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::UnfeatureCollection { 
+            owner : admin.to_string(), collection_name : "Featured Collection".to_string(), 
+            collection_symbol : "FTRC".to_string() });
+        assert!(res.is_err());
+    }
+
+
+    // cargo test test_transfer_collection_owner -- --show-output
+    #[test]
+    fn test_transfer_collection_owner(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let new_owner : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+        let other : &str = "archway1upspu5660q39adv768z8ffk44ta6lzd4nfw2zw";
+
+        let (mut deps, info) = setup_test_collection(owner, "Transferred Collection", "TRFC", 3);
+
+        // a token minted before the transfer records the old owner
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::MintItem { seed : "1289".to_string(), 
+            owner : owner.to_string(), collection_name : "Transferred Collection".to_string(), 
+            collection_symbol : "TRFC".to_string(), price_type : None, token_uri : None, 
+            token_id : Some("TRFC-1".to_string()), referrer : None, promo_code : None })
+        .expect("failed to mint!!");
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::ProposeCollectionOwner { 
+            collection_name : "Transferred Collection".to_string(), collection_symbol : "TRFC".to_string(), 
+            new_owner : new_owner.to_string(), update_treasury : None })
+        .expect("failed to propose new owner!!");
+
+        let accept = || ExecuteMsg::AcceptCollectionOwner { owner : owner.to_string(), 
+            collection_name : "Transferred Collection".to_string(), collection_symbol : "TRFC".to_string() };
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(other, &[]), accept());
+        assert!(matches!(res, Err(crate::ContractError::Unauthorized { .. })));
+
+        execute(deps.as_mut(), mock_env(), mock_info(new_owner, &[]), accept())
+        .expect("failed to accept collection!!");
+
+        let old = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(owner), 
+        "Transferred Collection".to_string(), "TRFC".to_string()).unwrap();
+        assert!(old.collection.is_none());
+
+        let moved = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(new_owner), 
+        "Transferred Collection".to_string(), "TRFC".to_string()).unwrap().collection.unwrap();
+        assert_eq!(moved.owner, Some(Addr::unchecked(new_owner)));
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetItemsCount { owner : new_owner.to_string(), 
+            collection_name : "Transferred Collection".to_string(), collection_symbol : "TRFC".to_string() })
+        .expect("failed to unwrap!!");
+        let result : ItemCountResponse = from_binary(&res).unwrap();
+        assert_eq!(result.count, 2);
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetItemsCount { owner : owner.to_string(), 
+            collection_name : "Transferred Collection".to_string(), collection_symbol : "TRFC".to_string() })
+        .expect("failed to unwrap!!");
+        let result : ItemCountResponse = from_binary(&res).unwrap();
+        assert_eq!(result.count, 0);
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(new_owner, &[]), accept());
+        assert!(matches!(res, Err(crate::ContractError::Unauthorized { .. })));
+
+        // the blocklist of the new owner applies to the tokens minted before
+        execute(deps.as_mut(), mock_env(), mock_info(new_owner, &[]), ExecuteMsg::BlockCollectionAddresses { 
+            owner : new_owner.to_string(), collection_name : "Transferred Collection".to_string(), 
+            collection_symbol : "TRFC".to_string(), addresses : vec![owner.to_string()], reason : None })
+        .expect("failed to block collection addresses!!");
+
+        let res = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::TransferNft { 
+            recipient : other.to_string(), token_id : "TRFC-1".to_string() });
+        assert!(matches!(res, Err(crate::ContractError::AddressBlocked { .. })));
+    }
+
+
+    // cargo test test_collection_roles -- --show-output
+    #[test]
+    fn test_collection_roles(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let editor : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+
+        let (mut deps, info) = setup_test_collection(owner, "Studio Collection", "STDC", 1);
+
+        let collection = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(owner), 
+        "Studio Collection".to_string(), "STDC".to_string()).unwrap().collection.unwrap();
+
+        let update_description = || ExecuteMsg::UpdateCollection { owner : Some(owner.to_string()), collection : CollectionMsg { 
+            name : collection.name.clone(), symbol : collection.symbol.clone(), owner : collection.owner.clone().map(|o| o.to_string()),
+            description : Some("Updated by the editor".to_string()), ..Default::default() } };
+
+        let editor_info = mock_info(editor, &coins(10000, DEFAULT_PRICE_DENOM));
+
+        let res = execute(deps.as_mut(), mock_env(), editor_info.clone(), update_description());
+        assert!(matches!(res, Err(crate::ContractError::MissingCollectionRole { .. })));
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::GrantCollectionRole { 
+            collection_name : "Studio Collection".to_string(), collection_symbol : "STDC".to_string(), 
+            member : editor.to_string(), role : CollectionRole::Editor })
+        .expect("failed to grant role!!");
+
+        execute(deps.as_mut(), mock_env(), editor_info.clone(), update_description())
+        .expect("failed to update description as editor!!");
+
+        // the owner in the collection does not pick the collection to update
+        let res = execute(deps.as_mut(), mock_env(), editor_info.clone(), ExecuteMsg::UpdateCollection { 
+            owner : None, collection : CollectionMsg { description : Some("Not by the owner".to_string()), 
+            ..collection.clone().into() } });
+        assert!(matches!(res, Err(crate::ContractError::CollectionNotFound { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::GrantCollectionRole { 
+            collection_name : "Studio Collection".to_string(), collection_symbol : "STDC".to_string(), 
+            member : owner.to_string(), role : CollectionRole::Editor });
+        assert!(res.is_err());
+
+        // editors can NOT change the treasuries
+        let res = execute(deps.as_mut(), mock_env(), editor_info.clone(), ExecuteMsg::UpdateCollection { owner : Some(owner.to_string()), 
+            collection : CollectionMsg { description : None, ..collection.clone().into() } });
+        assert!(matches!(res, Err(crate::ContractError::MissingCollectionRole { .. })));
+
+        execute(deps.as_mut(), mock_env(), editor_info.clone(), ExecuteMsg::CreateItem { item : ItemMsg {
+            collection_owner : owner.to_string(),
+            collection_name : "Studio Collection".to_string(),
+            collection_symbol : "STDC".to_string(),
+            name : "Item by editor".to_string(),
+            traits : Vec::new(),
+            links : vec![Link{link_type: LINK_TYPE_IMAGE_URL, value:"https://rm.img/img_editor.png".to_string() }],
+            description : None,
+            background_color : None,
+            date_created : None,
+            date_updated : None, 
+        }}).expect("failed to create item as editor!!");
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetItemsCount { owner : owner.to_string(), 
+            collection_name : "Studio Collection".to_string(), collection_symbol : "STDC".to_string() })
+        .expect("failed to unwrap!!");
+        let result : ItemCountResponse = from_binary(&res).unwrap();
+        assert_eq!(result.count, 2);
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetCollectionMembers { owner : owner.to_string(), 
+            collection_name : "Studio Collection".to_string(), collection_symbol : "STDC".to_string() })
+        .expect("failed to unwrap!!");
+        let result : CollectionMembersResponse = from_binary(&res).unwrap();
+        assert_eq!(result.members, vec![CollectionMember { address : Addr::unchecked(editor), 
+            roles : vec![CollectionRole::Editor] }]);
+
+        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::RevokeCollectionRole { 
+            collection_name : "Studio Collection".to_string(), collection_symbol : "STDC".to_string(), 
+            member : editor.to_string(), role : CollectionRole::Editor })
+        .expect("failed to revoke role!!");
+
+        let res = execute(deps.as_mut(), mock_env(), editor_info, ExecuteMsg::RemoveItem { 
+            owner : owner.to_string(), collection_name : "Studio Collection".to_string(), 
+            collection_symbol : "STDC".to_string(), item_name : "Item by editor".to_string() });
+        assert!(matches!(res, Err(crate::ContractError::MissingCollectionRole { .. })));
+    }
+
+
+    // cargo test test_delegated_minters -- --show-output
+    #[test]
+    fn test_delegated_minters(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let minter : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+        let recipient : &str = "archway1upspu5660q39adv768z8ffk44ta6lzd4nfw2zw";
+
+        let (mut deps, info) = setup_test_collection(owner, "Airdrop Collection", "ADRC", 3);
+
+        let mint_for = || ExecuteMsg::MintFor { seed : "1289".to_string(), owner : owner.to_string(), 
+            collection_name : "Airdrop Collection".to_string(), collection_symbol : "ADRC".to_string(), 
+            recipient : recipient.to_string(), token_uri : None, token_id : None };
+
+        let minter_info = mock_info(minter, &coins(6400, DEFAULT_PRICE_DENOM));
+
+        let res = execute(deps.as_mut(), mock_env(), minter_info.clone(), mint_for());
+        assert!(matches!(res, Err(crate::ContractError::NotDelegatedMinter { .. })));
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::AuthorizeMinter { 
+            collection_name : "Airdrop Collection".to_string(), collection_symbol : "ADRC".to_string(), 
+            minter : minter.to_string(), price_type : None, max_mints : Some(1) })
+        .expect("failed to authorize minter!!");
+
+        // the minter pays the contract minting fee only
+        let res = execute(deps.as_mut(), mock_env(), mock_info(minter, &[]), mint_for());
+        assert!(matches!(res, Err(crate::ContractError::InsufficientFund { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), minter_info.clone(), mint_for())
+        .expect("failed to mint for recipient!!");
+
+        let paid : u128 = res.messages.iter().map(|m| match &m.msg {
+            cosmwasm_std::CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                assert_eq!(to_address, owner);
+                amount.iter().map(|c| c.amount.u128()).sum()
+            },
+            _ => 0,
+        }).sum();
+        assert_eq!(paid, 6400);
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetItemsCount { owner : owner.to_string(), 
+            collection_name : "Airdrop Collection".to_string(), collection_symbol : "ADRC".to_string() })
+        .expect("failed to unwrap!!");
+        let result : ItemCountResponse = from_binary(&res).unwrap();
+        assert_eq!(result.count, 2);
+
+        let res = execute(deps.as_mut(), mock_env(), minter_info.clone(), mint_for());
+        assert!(matches!(res, Err(crate::ContractError::DelegatedMintLimitReached { .. })));
+
+        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::RevokeMinter { 
+            collection_name : "Airdrop Collection".to_string(), collection_symbol : "ADRC".to_string(), 
+            minter : minter.to_string() })
+        .expect("failed to revoke minter!!");
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetDelegatedMinters { owner : owner.to_string(), 
+            collection_name : "Airdrop Collection".to_string(), collection_symbol : "ADRC".to_string() })
+        .expect("failed to unwrap!!");
+        let result : DelegatedMintersResponse = from_binary(&res).unwrap();
+        assert_eq!(result.minters.len(), 1);
+        assert_eq!(result.minters[0].minted, 1);
+        assert!(result.minters[0].revoked);
+
+        let res = execute(deps.as_mut(), mock_env(), minter_info, mint_for());
+        assert!(matches!(res, Err(crate::ContractError::NotDelegatedMinter { .. })));
+    }
+
+
+    // cargo test test_collection_signers -- --show-output
+    #[test]
+    fn test_collection_signers(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let signer1 : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+        let signer2 : &str = "archway1upspu5660q39adv768z8ffk44ta6lzd4nfw2zw";
+
+        let (mut deps, info) = setup_test_collection(owner, "Team Collection", "TMC", 1);
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::SetCollectionSigners { 
+            collection_name : "Team Collection".to_string(), collection_symbol : "TMC".to_string(), 
+            signers : CollectionSignersMsg { signers : vec![owner.to_string(), signer1.to_string(), 
+            signer1.to_string()], threshold : 2 } });
+        assert!(matches!(res, Err(crate::ContractError::InvalidSigners { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::SetCollectionSigners { 
+            collection_name : "Team Collection".to_string(), collection_symbol : "TMC".to_string(), 
+            signers : CollectionSignersMsg { signers : vec![owner.to_string(), signer1.to_string()], 
+            threshold : 0 } });
+        assert!(matches!(res, Err(crate::ContractError::InvalidSigners { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::SetCollectionSigners { 
+            collection_name : "Team Collection".to_string(), collection_symbol : "TMC".to_string(), 
+            signers : CollectionSignersMsg { signers : vec![owner.to_string(), signer1.to_string()], 
+            threshold : 3 } });
+        assert!(matches!(res, Err(crate::ContractError::InvalidSigners { .. })));
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::SetCollectionSigners { 
+            collection_name : "Team Collection".to_string(), collection_symbol : "TMC".to_string(), 
+            signers : CollectionSignersMsg { signers : vec![owner.to_string(), signer1.to_string(), 
+            signer2.to_string()], threshold : 2 } })
+        .expect("failed to set signers!!");
+
+        let pause = CollectionMsg { name : "Team Collection".to_string(), symbol : "TMC".to_string(), 
+            owner : Some(owner.to_string()), status : Some(COLLECTION_STATUS_PAUSED), ..Default::default() };
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::UpdateCollection { owner : None, 
+            collection : pause.clone() });
+        assert!(matches!(res, Err(crate::ContractError::ApprovalRequired { .. })));
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::ProposeCollectionChange { 
+            owner : owner.to_string(), collection_name : "Team Collection".to_string(), 
+            collection_symbol : "TMC".to_string(), changes : Some(pause), action : None, signers : None })
+        .expect("failed to propose change!!");
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info("archway1notasigner", &[]), 
+            ExecuteMsg::ApproveCollectionChange { owner : owner.to_string(), 
+            collection_name : "Team Collection".to_string(), collection_symbol : "TMC".to_string(), 
+            proposal_id : 1 });
+        assert!(matches!(res, Err(crate::ContractError::Unauthorized { .. })));
+
+        let collection = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(owner), 
+        "Team Collection".to_string(), "TMC".to_string()).unwrap().collection.unwrap();
+        assert_eq!(collection.status, Some(COLLECTION_STATUS_ACTIVATED));
+
+        execute(deps.as_mut(), mock_env(), mock_info(signer1, &[]), ExecuteMsg::ApproveCollectionChange { 
+            owner : owner.to_string(), collection_name : "Team Collection".to_string(), 
+            collection_symbol : "TMC".to_string(), proposal_id : 1 })
+        .expect("failed to approve change!!");
+
+        let collection = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(owner), 
+        "Team Collection".to_string(), "TMC".to_string()).unwrap().collection.unwrap();
+        assert_eq!(collection.status, Some(COLLECTION_STATUS_PAUSED));
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetCollectionSigners { owner : owner.to_string(), 
+            collection_name : "Team Collection".to_string(), collection_symbol : "TMC".to_string() })
+        .expect("failed to unwrap!!");
+        let result : CollectionSignersResponse = from_binary(&res).unwrap();
+        assert_eq!(result.signers.unwrap().threshold, 2);
+        assert!(result.proposals.is_empty());
+
+        // the actions of the owner go through the signers too
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::RemoveCollection { 
+            name : "Team Collection".to_string(), symbol : "TMC".to_string() });
+        assert!(matches!(res, Err(crate::ContractError::ApprovalRequired { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::ProposeCollectionOwner { 
+            collection_name : "Team Collection".to_string(), collection_symbol : "TMC".to_string(), 
+            new_owner : signer1.to_string(), update_treasury : None });
+        assert!(matches!(res, Err(crate::ContractError::ApprovalRequired { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::AuthorizeMinter { 
+            collection_name : "Team Collection".to_string(), collection_symbol : "TMC".to_string(), 
+            minter : signer2.to_string(), price_type : None, max_mints : None });
+        assert!(matches!(res, Err(crate::ContractError::ApprovalRequired { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::AddPromoCodes { 
+            collection_name : "Team Collection".to_string(), collection_symbol : "TMC".to_string(), 
+            codes : vec![PromoCode { code_hash : promo_code_hash("TEAM"), discount : PromoDiscount::Percentage(5000), 
+            max_uses : None, used : 0, expiry : None }] });
+        assert!(matches!(res, Err(crate::ContractError::ApprovalRequired { .. })));
+
+        let prices = CollectionMsg { name : "Team Collection".to_string(), symbol : "TMC".to_string(), 
+            owner : Some(owner.to_string()), prices : Some(vec![]), referral_share : Some(100), ..Default::default() };
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::UpdateCollection { owner : None, 
+            collection : prices });
+        assert!(matches!(res, Err(crate::ContractError::ApprovalRequired { .. })));
+
+        // the price of a mint phase is a price change too
+        let phases = CollectionMsg { name : "Team Collection".to_string(), symbol : "TMC".to_string(), 
+            owner : Some(owner.to_string()), mint_phases : Some(vec![MintPhase { name : "PUBLIC".to_string(), 
+            price : Coin { amount : Uint128::from(1u64), denom : DEFAULT_PRICE_DENOM.to_string() },
+            start_time : mock_env().block.time, end_time : None, allowlist_only : None, max_per_wallet : None }]), 
+            ..Default::default() };
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::UpdateCollection { owner : None, 
+            collection : phases.clone() });
+        assert!(matches!(res, Err(crate::ContractError::ApprovalRequired { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::UpdateCollection { owner : None, 
+            collection : CollectionMsg { launch_at : Some(mock_env().block.time.plus_seconds(60)), 
+            mint_phases : None, ..phases.clone() } });
+        assert!(matches!(res, Err(crate::ContractError::ApprovalRequired { .. })));
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::ProposeCollectionChange { 
+            owner : owner.to_string(), collection_name : "Team Collection".to_string(), 
+            collection_symbol : "TMC".to_string(), changes : None, 
+            action : Some(ProposedAction::AuthorizeMinter { minter : signer2.to_string(), 
+            price_type : None, max_mints : Some(5) }), signers : None })
+        .expect("failed to propose action!!");
+
+        // the id of the applied proposal is NOT reused
+        let res = execute(deps.as_mut(), mock_env(), mock_info(signer1, &[]), ExecuteMsg::ApproveCollectionChange { 
+            owner : owner.to_string(), collection_name : "Team Collection".to_string(), 
+            collection_symbol : "TMC".to_string(), proposal_id : 1 });
+        assert!(matches!(res, Err(crate::ContractError::CustomErrorMesg { .. })));
+
+        execute(deps.as_mut(), mock_env(), mock_info(signer1, &[]), ExecuteMsg::ApproveCollectionChange { 
+            owner : owner.to_string(), collection_name : "Team Collection".to_string(), 
+            collection_symbol : "TMC".to_string(), proposal_id : 2 })
+        .expect("failed to approve action!!");
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetDelegatedMinters { owner : owner.to_string(), 
+            collection_name : "Team Collection".to_string(), collection_symbol : "TMC".to_string() })
+        .expect("failed to unwrap!!");
+        let result : DelegatedMintersResponse = from_binary(&res).unwrap();
+        assert_eq!(result.minters.len(), 1);
+        assert_eq!(result.minters[0].minter, Addr::unchecked(signer2));
+    }
+
+
+    // cargo test test_pause_operations -- --show-output
+    #[test]
+    fn test_pause_operations(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let other : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+
+        let (mut deps, info) = setup_test_collection(owner, "Paused Collection", "PSDC", 2);
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(other, &[]), ExecuteMsg::SetPaused { 
+            group : PauseGroup::Minting, paused : true, reason : None });
+        assert!(matches!(res, Err(crate::ContractError::Unauthorized { .. })));
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::SetPaused { 
+            group : PauseGroup::Minting, paused : true, reason : Some("Incident".to_string()) })
+        .expect("failed to pause minting!!");
+
+        let mint = || ExecuteMsg::MintItem { seed : "1289".to_string(), owner : owner.to_string(), 
+            collection_name : "Paused Collection".to_string(), collection_symbol : "PSDC".to_string(), 
+            price_type : None, token_uri : None, token_id : None, referrer : None, promo_code : None };
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), mint());
+        assert!(matches!(res, Err(crate::ContractError::Paused { .. })));
+
+        // the other operations are still allowed
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::RemoveItem { 
+            owner : owner.to_string(), collection_name : "Paused Collection".to_string(), 
+            collection_symbol : "PSDC".to_string(), item_name : "Item #001".to_string() })
+        .expect("failed to remove item!!");
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetPauseStatus {}).expect("failed to unwrap!!");
+        let result : PauseStatusResponse = from_binary(&res).unwrap();
+        assert_eq!(result.paused.len(), 1);
+        assert_eq!(result.paused[0].group, PauseGroup::Minting);
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::SetPaused { 
+            group : PauseGroup::Collections, paused : true, reason : None })
+        .expect("failed to pause collections!!");
+
+        // the owner actions on collections are paused too, not only their updates
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::ProposeCollectionOwner { 
+            collection_name : "Paused Collection".to_string(), collection_symbol : "PSDC".to_string(), 
+            new_owner : other.to_string(), update_treasury : None });
+        assert!(matches!(res, Err(crate::ContractError::Paused { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::AddPromoCodes { 
+            collection_name : "Paused Collection".to_string(), collection_symbol : "PSDC".to_string(), 
+            codes : vec![] });
+        assert!(matches!(res, Err(crate::ContractError::Paused { .. })));
+
+        // the admins still block addresses while the collections are paused
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::BlockAddresses { 
+            addresses : vec![other.to_string()], reason : Some("Incident".to_string()) })
+        .expect("failed to block addresses!!");
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::UnblockAddresses { 
+            addresses : vec![other.to_string()] })
+        .expect("failed to unblock addresses!!");
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::SetPaused { 
+            group : PauseGroup::Collections, paused : false, reason : None })
+        .expect("failed to resume collections!!");
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::SetPaused { 
+            group : PauseGroup::Minting, paused : false, reason : None })
+        .expect("failed to resume minting!!");
+
+        execute(deps.as_mut(), mock_env(), info, mint()).expect("failed to mint!!");
+    }
+
+
+    // cargo test test_pause_minting -- --show-output
+    #[test]
+    fn test_pause_minting(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+
+        let (mut deps, info) = setup_test_collection(owner, "Break Collection", "BRKC", 2);
+
+        let env = mock_env();
+
+        execute(deps.as_mut(), env.clone(), info.clone(), ExecuteMsg::PauseMinting { 
+            owner : owner.to_string(), collection_name : "Break Collection".to_string(), 
+            collection_symbol : "BRKC".to_string(), reason : Some("Reveal".to_string()), 
+            resume_at : Some(env.block.time.plus_seconds(3600)) })
+        .expect("failed to pause minting!!");
+
+        let mint = || ExecuteMsg::MintItem { seed : "1289".to_string(), owner : owner.to_string(), 
+            collection_name : "Break Collection".to_string(), collection_symbol : "BRKC".to_string(), 
+            price_type : None, token_uri : None, token_id : None, referrer : None, promo_code : None };
+
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), mint());
+        assert!(matches!(res, Err(crate::ContractError::MintingPaused { .. })));
+
+        // the status is unchanged and the pause is exposed
+        let result = get_collection(deps.as_ref(), env.clone(), Addr::unchecked(owner), 
+        "Break Collection".to_string(), "BRKC".to_string()).unwrap();
+        assert_eq!(result.collection.unwrap().status, Some(COLLECTION_STATUS_ACTIVATED));
+        assert_eq!(result.mint_pause.unwrap().reason, Some("Reveal".to_string()));
+
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::GetActiveCollections { keyword : None, 
+            category : None, start : None, limit : None, verified_only : None }).expect("failed to unwrap!!");
+        let result : CollectionsWithParamsResponse = from_binary(&res).unwrap();
+        assert_eq!(result.collections.len(), 1);
+        assert_eq!(result.mint_pauses.clone().unwrap().len(), 1);
+        assert_eq!(result.mint_pauses.unwrap()[0].symbol, "BRKC".to_string());
+
+        // minting resumes by itself
+        let mut later = env.clone();
+        later.block.time = env.block.time.plus_seconds(3600);
+
+        let result = get_collection(deps.as_ref(), later.clone(), Addr::unchecked(owner), 
+        "Break Collection".to_string(), "BRKC".to_string()).unwrap();
+        assert!(result.mint_pause.is_none());
+
+        let res = query(deps.as_ref(), later.clone(), QueryMsg::GetAllCollections { start_after : None, 
+            limit : None }).expect("failed to unwrap!!");
+        let result : CollectionsResponse = from_binary(&res).unwrap();
+        assert_eq!(result.mint_pauses, Some(vec![]));
+
+        execute(deps.as_mut(), later, info, mint()).expect("failed to mint after resume!!");
+    }
+
+
+    // cargo test test_blocklists -- --show-output
+    #[test]
+    fn test_blocklists(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let minter : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+        let other : &str = "archway1upspu5660q39adv768z8ffk44ta6lzd4nfw2zw";
+
+        let (mut deps, info) = setup_test_collection(owner, "Guarded Collection", "GRDC", 2);
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::BlockAddresses { 
+            addresses : vec![other.to_string()], reason : Some("Sanctioned".to_string()) })
+        .expect("failed to block addresses!!");
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(other, &coins(100000, DEFAULT_PRICE_DENOM)), 
+            ExecuteMsg::CreateCollection { collection : CollectionMsg { name : "Blocked Collection".to_string(), 
+            symbol : "BLKC".to_string(), ..Default::default() } });
+        assert!(matches!(res, Err(crate::ContractError::AddressBlocked { .. })));
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::BlockCollectionAddresses { 
+            owner : owner.to_string(), collection_name : "Guarded Collection".to_string(), 
+            collection_symbol : "GRDC".to_string(), addresses : vec![minter.to_string()], reason : None })
+        .expect("failed to block collection addresses!!");
+
+        let mint = || ExecuteMsg::MintItem { seed : "1289".to_string(), owner : owner.to_string(), 
+            collection_name : "Guarded Collection".to_string(), collection_symbol : "GRDC".to_string(), 
+            price_type : None, token_uri : None, token_id : None, referrer : None, promo_code : None };
+
+        let minter_info = mock_info(minter, &coins(134000, DEFAULT_PRICE_DENOM));
+
+        let res = execute(deps.as_mut(), mock_env(), minter_info.clone(), mint());
+        assert!(matches!(res, Err(crate::ContractError::AddressBlocked { .. })));
+
+        // nor is a blocked address paid as a referrer or a treasury
+        let res = execute(deps.as_mut(), mock_env(), mock_info(owner, &coins(134000, DEFAULT_PRICE_DENOM)), 
+            ExecuteMsg::MintItem { seed : "1289".to_string(), owner : owner.to_string(), 
+            collection_name : "Guarded Collection".to_string(), collection_symbol : "GRDC".to_string(), 
+            price_type : None, token_uri : None, token_id : None, referrer : Some(minter.to_string()), promo_code : None });
+        assert!(matches!(res, Err(crate::ContractError::AddressBlocked { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::UpdateCollection { owner : None, 
+            collection : CollectionMsg { name : "Guarded Collection".to_string(), symbol : "GRDC".to_string(), 
+            treasuries : Some(vec![TreasuryMsg { wallet : other.to_string(), 
+            percentage : TREASURY_PERCENTAGE_TOTAL, name : None, primary : Some(true) }]), ..Default::default() } });
+        assert!(matches!(res, Err(crate::ContractError::AddressBlocked { .. })));
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetCollectionBlockedAddresses { 
+            owner : owner.to_string(), collection_name : "Guarded Collection".to_string(), 
+            collection_symbol : "GRDC".to_string(), start_after : None, limit : None })
+        .expect("failed to unwrap!!");
+        let result : BlockedAddressesResponse = from_binary(&res).unwrap();
+        assert_eq!(result.addresses.len(), 1);
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetBlockedAddresses { 
+            start_after : Some(other.to_string()), limit : None }).expect("failed to unwrap!!");
+        let result : BlockedAddressesResponse = from_binary(&res).unwrap();
+        assert!(result.addresses.is_empty());
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::UnblockCollectionAddresses { 
+            owner : owner.to_string(), collection_name : "Guarded Collection".to_string(), 
+            collection_symbol : "GRDC".to_string(), addresses : vec![minter.to_string()] })
+        .expect("failed to unblock collection addresses!!");
+
+        execute(deps.as_mut(), mock_env(), minter_info.clone(), ExecuteMsg::MintItem { seed : "1289".to_string(), 
+            owner : owner.to_string(), collection_name : "Guarded Collection".to_string(), 
+            collection_symbol : "GRDC".to_string(), price_type : None, token_uri : None, 
+            token_id : Some("GRDC-1".to_string()), referrer : None, promo_code : None })
+        .expect("failed to mint!!");
+
+        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::BlockCollectionAddresses { 
+            owner : owner.to_string(), collection_name : "Guarded Collection".to_string(), 
+            collection_symbol : "GRDC".to_string(), addresses : vec![minter.to_string()], reason : None })
+        .expect("failed to block collection addresses!!");
+
+        // the tokens of the collection can NOT be moved by a blocked holder
+        let res = execute(deps.as_mut(), mock_env(), minter_info, ExecuteMsg::TransferNft { 
+            recipient : owner.to_string(), token_id : "GRDC-1".to_string() });
+        assert!(matches!(res, Err(crate::ContractError::AddressBlocked { .. })));
+    }
+
+
+    // cargo test test_mint_throttle -- --show-output
+    #[test]
+    fn test_mint_throttle(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let minter1 : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+        let minter2 : &str = "archway1upspu5660q39adv768z8ffk44ta6lzd4nfw2zw";
+
+        let (mut deps, info) = setup_test_collection(owner, "Throttled Collection", "THRC", 3);
+
+        let throttle = MintThrottle { max_mints_per_block : Some(1), min_blocks_between_mints : Some(5), 
+            no_contract_minters : Some(true) };
+
+        let update = |throttle : MintThrottle| ExecuteMsg::UpdateCollection { owner : None, collection : CollectionMsg { 
+            name : "Throttled Collection".to_string(), symbol : "THRC".to_string(), 
+            mint_throttle : Some(throttle), ..Default::default() } };
+
+        // a wait that would never end is refused
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), update(MintThrottle { max_mints_per_block : None, 
+            min_blocks_between_mints : Some(u64::MAX), no_contract_minters : None }));
+        assert!(matches!(res, Err(crate::ContractError::CustomErrorMesg { .. })));
+
+        execute(deps.as_mut(), mock_env(), info.clone(), update(throttle.clone()))
+        .expect("failed to set mint throttle!!");
+
+        let collection = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(owner), 
+        "Throttled Collection".to_string(), "THRC".to_string()).unwrap().collection.unwrap();
+        assert_eq!(collection.mint_throttle, Some(throttle));
+
+        let mint = || ExecuteMsg::MintItem { seed : "1289".to_string(), owner : owner.to_string(), 
+            collection_name : "Throttled Collection".to_string(), collection_symbol : "THRC".to_string(), 
+            price_type : None, token_uri : None, token_id : None, referrer : None, promo_code : None };
+
+        let env = mock_env();
+
+        execute(deps.as_mut(), env.clone(), mock_info(minter1, &coins(134000, DEFAULT_PRICE_DENOM)), mint())
+        .expect("failed to mint!!");
+
+        let res = execute(deps.as_mut(), env.clone(), mock_info(minter2, &coins(134000, DEFAULT_PRICE_DENOM)), mint());
+        assert!(matches!(res, Err(crate::ContractError::BlockMintLimitReached { .. })));
+
+        let mut next = env.clone();
+        next.block.height = env.block.height + 1;
+
+        let res = execute(deps.as_mut(), next.clone(), mock_info(minter1, &coins(134000, DEFAULT_PRICE_DENOM)), mint());
+        assert!(matches!(res, Err(crate::ContractError::MintTooSoon { .. })));
+
+        execute(deps.as_mut(), next, mock_info(minter2, &coins(134000, DEFAULT_PRICE_DENOM)), mint())
+        .expect("failed to mint in the next block!!");
+    }
+
+
+    // cargo test test_address_validation -- --show-output
+    #[test]
+    fn test_address_validation(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let minter : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+
+        let (mut deps, info) = setup_test_collection(owner, "Checked Collection", "CHKC", 2);
+
+        // treasuries are rejected up front
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::CreateCollection { 
+            collection : CollectionMsg { name : "Mixed Collection".to_string(), symbol : "MIXC".to_string(), 
+            treasuries : Some(vec![TreasuryMsg { wallet : "archway1CZ5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp".to_string(), 
+            percentage : 10000, name : None, primary : None }]), ..Default::default() } });
+        assert!(matches!(res, Err(crate::ContractError::Std(_))));
+
+        let mint = |owner : &str| ExecuteMsg::MintItem { seed : "1289".to_string(), owner : owner.to_string(), 
+            collection_name : "Checked Collection".to_string(), collection_symbol : "CHKC".to_string(), 
+            price_type : None, token_uri : None, token_id : None, referrer : None, promo_code : None };
+
+        let minter_info = mock_info(minter, &coins(134000, DEFAULT_PRICE_DENOM));
+
+        let res = execute(deps.as_mut(), mock_env(), minter_info.clone(), 
+            mint("archway14L92fdhae4htjtkyla73f262c39cngf2wc65ky"));
+        assert!(matches!(res, Err(crate::ContractError::Std(_))));
+
+        let res = execute(deps.as_mut(), mock_env(), minter_info.clone(), mint("a1"));
+        assert!(matches!(res, Err(crate::ContractError::Std(_))));
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetItemsCount { owner : "ar".to_string(), 
+            collection_name : "Checked Collection".to_string(), collection_symbol : "CHKC".to_string() });
+        assert!(res.is_err());
+
+        // an all uppercase address is canonicalized
+        execute(deps.as_mut(), mock_env(), minter_info, mint(&owner.to_uppercase()))
+        .expect("failed to mint with uppercase owner!!");
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetItemsCount { owner : owner.to_string(), 
+            collection_name : "Checked Collection".to_string(), collection_symbol : "CHKC".to_string() })
+        .expect("failed to unwrap!!");
+        let result : ItemCountResponse = from_binary(&res).unwrap();
+        assert_eq!(result.count, 1);
+    }
+
+
+    // cargo test test_input_validation -- --show-output
+    #[test]
+    fn test_input_validation(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+
+        let (mut deps, info) = setup_test_collection(owner, "Valid Collection", "VLDC", 1);
+
+        let collection = |name : &str, symbol : &str| ExecuteMsg::CreateCollection { 
+            collection : CollectionMsg { name : name.to_string(), symbol : symbol.to_string(), 
+            ..Default::default() } };
+
+        let long_name = "N".repeat(65);
+
+        for (name, symbol) in [("Bad <Collection>", "BADC"), (" Spaced", "SPCC"), ("", "EMPN"), 
+            (long_name.as_str(), "LNGN"), ("Dashed Symbol", "DS-C"), ("Spaced Symbol", "SP C"), 
+            ("Empty Symbol", ""), ("Long Symbol", "ABCDEFGHIJKLMNOPQ")] {
+            let res = execute(deps.as_mut(), mock_env(), info.clone(), collection(name, symbol));
+            assert!(matches!(res, Err(crate::ContractError::InvalidInput { .. })), "{} {} is accepted", name, symbol);
+        }
+
+        // the symbols of the legacy charset are still accepted
+        execute(deps.as_mut(), mock_env(), info.clone(), collection("Legacy Collection", "Coll.x_2"))
+        .expect("failed to create collection with a legacy symbol!!");
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::CreateCollection { 
+            collection : CollectionMsg { name : "Wordy Collection".to_string(), symbol : "WRDC".to_string(), 
+            description : Some("D".repeat(2001)), ..Default::default() } });
+        assert!(matches!(res, Err(crate::ContractError::InvalidInput { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::CreateCollection { 
+            collection : CollectionMsg { name : "Tagged Collection".to_string(), symbol : "TAGC".to_string(), 
+            attributes : Some((0..33).map(|i| Attribute { name : format!("tag{}", i), value : "x".to_string() })
+            .collect()), ..Default::default() } });
+        assert!(matches!(res, Err(crate::ContractError::InvalidInput { .. })));
+
+        let item = |links : Vec<Link>, background_color : Option<&str>| ExecuteMsg::CreateItem { item : ItemMsg {
+            collection_owner : owner.to_string(), collection_name : "Valid Collection".to_string(),
+            collection_symbol : "VLDC".to_string(), name : "Checked Item".to_string(), description : None,
+            links : links, traits : Vec::new(), background_color : background_color.map(|c| c.to_string()),
+            date_created : None, date_updated : None } };
+
+        let link = |value : &str| Link { link_type : LINK_TYPE_IMAGE_URL, value : value.to_string() };
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), item(vec![link("ftp://rm.img/a.png")], None));
+        assert!(matches!(res, Err(crate::ContractError::InvalidInput { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), item(vec![link("ipfs://")], None));
+        assert!(matches!(res, Err(crate::ContractError::InvalidInput { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), item(vec![], Some("#ZZ00AA")));
+        assert!(matches!(res, Err(crate::ContractError::InvalidInput { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), item(vec![], Some("#FFF")));
+        assert!(matches!(res, Err(crate::ContractError::InvalidInput { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::CreateItem { item : ItemMsg {
+            collection_owner : owner.to_string(), collection_name : "Valid Collection".to_string(),
+            collection_symbol : "VLDC".to_string(), name : "Traited Item".to_string(), description : None,
+            links : vec![], traits : vec![Trait { trait_type : "Mood".to_string(), display_type : None, 
+            value : "V".repeat(1025) }], background_color : None, date_created : None, date_updated : None } });
+        assert!(matches!(res, Err(crate::ContractError::InvalidInput { .. })));
+
+        // only the links holding a URL are checked against the schemes
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::CreateItem { item : ItemMsg {
+            collection_owner : owner.to_string(), collection_name : "Valid Collection".to_string(),
+            collection_symbol : "VLDC".to_string(), name : "Captioned Item".to_string(), description : None,
+            links : vec![Link { link_type : 9, value : "A caption, not a URL".to_string() }], traits : Vec::new(), 
+            background_color : None, date_created : None, date_updated : None } })
+        .expect("failed to create item with a non URL link!!");
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::SimpleMint { 
+            item : ItemMsg { collection_owner : owner.to_string(), collection_name : "Valid Collection".to_string(),
+            collection_symbol : "VLDC".to_string(), name : "Simple Item".to_string(), description : None,
+            links : vec![link("ftp://rm.img/a.png")], traits : Vec::new(), background_color : None,
+            date_created : None, date_updated : None }, token_uri : None, token_id : None });
+        assert!(matches!(res, Err(crate::ContractError::InvalidInput { .. })));
+
+        // the admin lowers the max links
+        let mut limits = ValidationLimits::default();
+        limits.max_links = 0;
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::SetValidationLimits { limits : limits.clone() });
+        assert!(matches!(res, Err(crate::ContractError::InvalidInput { .. })));
+
+        limits.max_links = 1;
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info("archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp", &[]), 
+            ExecuteMsg::SetValidationLimits { limits : limits.clone() });
+        assert!(matches!(res, Err(crate::ContractError::Unauthorized {})));
+
+        execute(deps.as_mut(), mock_env(), info.clone(), ExecuteMsg::SetValidationLimits { limits : limits.clone() })
+        .expect("failed to set validation limits!!");
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetValidationLimits {}).expect("failed to unwrap!!");
+        let result : ValidationLimits = from_binary(&res).unwrap();
+        assert_eq!(result, limits);
+
+        let res = execute(deps.as_mut(), mock_env(), info.clone(), 
+            item(vec![link("ipfs://QmImage"), link("https://rm.img/b.png")], None));
+        assert!(matches!(res, Err(crate::ContractError::InvalidInput { .. })));
+
+        execute(deps.as_mut(), mock_env(), info.clone(), item(vec![link("ipfs://QmImage")], Some("#FFaa00")))
+        .expect("failed to create item!!");
+    }
+
+
+    // cargo test test_collection_id_encoding -- --show-output
+    #[test]
+    fn test_collection_id_encoding(){
+
+        assert_ne!(collection_id("a-b".to_string(), "c".to_string()), collection_id("a".to_string(), "b-c".to_string()));
+        assert_ne!(collection_id("a\\".to_string(), "-b".to_string()), collection_id("a\\-".to_string(), "b".to_string()));
+        assert_eq!(collection_id("Test Collection".to_string(), "TC1".to_string()), "Test Collection-TC1");
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+
+        let (mut deps, _) = setup_test_collection(owner, "Plain Collection", "PLNC", 1);
+
+        // a collection and its item stored under the legacy id
+        let legacy_key = (Addr::unchecked(owner), "Dash-Collection-DSHC".to_string());
+
+        let mut collection = get_collection(deps.as_ref(), mock_env(), Addr::unchecked(owner), 
+            "Plain Collection".to_string(), "PLNC".to_string()).expect("failed to unwrap!!").collection.unwrap();
+        collection.name = "Dash-Collection".to_string();
+        collection.symbol = "DSHC".to_string();
+
+        crate::indexes::collections_store().save(deps.as_mut().storage, legacy_key.clone(), &collection)
+        .expect("failed to save collection!!");
+
+        let mut item = crate::query::internal_get_all_items(deps.as_ref(), Addr::unchecked(owner), 
+            "Plain Collection".to_string(), "PLNC".to_string())[0].clone();
+        item.collection_name = collection.name.clone();
+        item.collection_symbol = collection.symbol.clone();
+
+        crate::indexes::COLLECTION_ITEMS_STORE.save(deps.as_mut().storage, 
+            (legacy_key.0.clone(), legacy_key.1.clone(), item.name.clone()), &item)
+        .expect("failed to save item!!");
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info("archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp", &[]), 
+            ExecuteMsg::RekeyCollections { limit : None });
+        assert!(matches!(res, Err(crate::ContractError::Unauthorized { .. })));
+
+        // one collection a page, the re-keyed collection is checked again but left as is
+        let mut pages : Vec<(u32, bool)> = vec![];
+
+        while pages.last().map(|p| !p.1).unwrap_or(true) {
+            pages.push(crate::migrations::migrate_collection_ids(deps.as_mut(), 1).expect("failed to migrate!!"));
+        }
+
+        assert_eq!(pages, vec![(1, false), (0, false), (0, false), (0, true)]);
+
+        execute(deps.as_mut(), mock_env(), mock_info(owner, &[]), ExecuteMsg::RekeyCollections { limit : None })
+        .expect("failed to re-key collections!!");
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetItemsCount { owner : owner.to_string(), 
+            collection_name : "Dash-Collection".to_string(), collection_symbol : "DSHC".to_string() })
+        .expect("failed to unwrap!!");
+        let result : ItemCountResponse = from_binary(&res).unwrap();
+        assert_eq!(result.count, 1);
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetCollections { owner : owner.to_string(),
+            start_after : None, limit : None }).expect("failed to unwrap!!");
+        let result : CollectionsResponse = from_binary(&res).unwrap();
+        assert_eq!(result.collections.len(), 2);
+
+        // the re-keyed collection is indexed by its case-insensitive name key
+        let holder = crate::indexes::collections_store().idx.name_symbols
+        .item(deps.as_ref().storage, name_key("Dash-Collection", "DSHC")).expect("failed to unwrap!!");
+        assert!(holder.is_some());
+    }
+
+
+    // cargo test test_name_availability -- --show-output
+    #[test]
+    fn test_name_availability(){
+
+        let owner : &str = "archway14l92fdhae4htjtkyla73f262c39cngf2wc65ky";
+        let creator : &str = "archway1cz5a70ja86ak40de7r6vgm2lr9mtgvue5sj5kp";
+        let other : &str = "archway1upspu5660q39adv768z8ffk44ta6lzd4nfw2zw";
+
+        let (mut deps, _) = setup_test_collection(owner, "Taken Collection", "TKNC", 1);
+
+        let collection = |name : &str, symbol : &str| ExecuteMsg::CreateCollection { 
+            collection : CollectionMsg { name : name.to_string(), symbol : symbol.to_string(), 
+            ..Default::default() } };
+
+        let available = |deps : Deps, name : &str, symbol : &str, sender : Option<&str>| {
+            let res = query(deps, mock_env(), QueryMsg::IsNameAvailable { name : name.to_string(), 
+                symbol : symbol.to_string(), sender : sender.map(|s| s.to_string()) }).expect("failed to unwrap!!");
+            let result : NameAvailabilityResponse = from_binary(&res).unwrap();
+            result
+        };
+
+        let creator_info = mock_info(creator, &coins(134000, DEFAULT_PRICE_DENOM));
+        let other_info = mock_info(other, &coins(134000, DEFAULT_PRICE_DENOM));
+
+        // the name and symbol are taken by the owner
+        let result = available(deps.as_ref(), "Taken Collection", "TKNC", Some(creator));
+        assert!(!result.available && result.taken);
+
+        let res = execute(deps.as_mut(), mock_env(), creator_info.clone(), collection("Taken Collection", "TKNC"));
+        assert!(matches!(res, Err(crate::ContractError::NameNotAvailable { .. })));
+
+        // reserved by the creator
+        execute(deps.as_mut(), mock_env(), creator_info.clone(), ExecuteMsg::ReserveName { 
+            name : "Reserved Collection".to_string(), symbol : "RSVC".to_string() })
+        .expect("failed to reserve name!!");
+
+        assert!(!available(deps.as_ref(), "Reserved Collection", "RSVC", None).available);
+        assert!(available(deps.as_ref(), "Reserved Collection", "RSVC", Some(creator)).available);
+
+        let res = execute(deps.as_mut(), mock_env(), other_info.clone(), ExecuteMsg::ReserveName { 
+            name : "Reserved Collection".to_string(), symbol : "RSVC".to_string() });
+        assert!(matches!(res, Err(crate::ContractError::NameNotAvailable { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), other_info.clone(), collection("Reserved Collection", "RSVC"));
+        assert!(matches!(res, Err(crate::ContractError::NameNotAvailable { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), other_info.clone(), ExecuteMsg::ReleaseName { 
+            name : "Reserved Collection".to_string(), symbol : "RSVC".to_string() });
+        assert!(matches!(res, Err(crate::ContractError::Unauthorized {})));
+
+        // the reservation expires
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(NAME_RESERVATION_DURATION);
+        let res = query(deps.as_ref(), env, QueryMsg::IsNameAvailable { name : "Reserved Collection".to_string(), 
+            symbol : "RSVC".to_string(), sender : Some(other.to_string()) }).expect("failed to unwrap!!");
+        let result : NameAvailabilityResponse = from_binary(&res).unwrap();
+        assert!(result.available && result.reservation.is_none());
+
+        // the creator claims the reservation
+        execute(deps.as_mut(), mock_env(), creator_info.clone(), collection("Reserved Collection", "RSVC"))
+        .expect("failed to create collection!!");
+
+        let result = available(deps.as_ref(), "Reserved Collection", "RSVC", Some(creator));
+        assert!(!result.available && result.taken && result.reservation.is_none());
+
+        // the name and symbol are compared case-insensitively
+        let result = available(deps.as_ref(), "taken COLLECTION", "tknc", Some(creator));
+        assert!(!result.available && result.taken);
+
+        let res = execute(deps.as_mut(), mock_env(), other_info.clone(), ExecuteMsg::ReserveName { 
+            name : "reserved collection".to_string(), symbol : "rsvc".to_string() });
+        assert!(matches!(res, Err(crate::ContractError::NameNotAvailable { .. })));
+
+        let reserve = |name : &str, symbol : &str| ExecuteMsg::ReserveName { 
+            name : name.to_string(), symbol : symbol.to_string() };
+
+        // no back-to-back renewal by the same reserver, even after it expires
+        execute(deps.as_mut(), mock_env(), creator_info.clone(), reserve("Renewed Collection", "RNWC"))
+        .expect("failed to reserve name!!");
+
+        let res = execute(deps.as_mut(), mock_env(), creator_info.clone(), reserve("renewed collection", "RNWC"));
+        assert!(matches!(res, Err(crate::ContractError::NameNotAvailable { .. })));
+
+        let mut expired_env = mock_env();
+        expired_env.block.time = expired_env.block.time.plus_seconds(NAME_RESERVATION_DURATION);
+
+        let res = execute(deps.as_mut(), expired_env.clone(), creator_info.clone(), reserve("Renewed Collection", "RNWC"));
+        assert!(matches!(res, Err(crate::ContractError::NameNotAvailable { .. })));
+
+        execute(deps.as_mut(), expired_env.clone(), other_info.clone(), reserve("Renewed Collection", "RNWC"))
+        .expect("failed to reserve name!!");
+
+        // a released name is open to the others, but not to its reserver
+        execute(deps.as_mut(), mock_env(), creator_info.clone(), reserve("Released Collection", "RLSC"))
+        .expect("failed to reserve name!!");
+
+        execute(deps.as_mut(), mock_env(), creator_info.clone(), ExecuteMsg::ReleaseName { 
+            name : "Released Collection".to_string(), symbol : "RLSC".to_string() })
+        .expect("failed to release name!!");
+
+        let res = execute(deps.as_mut(), mock_env(), creator_info.clone(), reserve("Released Collection", "RLSC"));
+        assert!(matches!(res, Err(crate::ContractError::NameNotAvailable { .. })));
+
+        execute(deps.as_mut(), mock_env(), other_info.clone(), reserve("Released Collection", "RLSC"))
+        .expect("failed to reserve name!!");
+
+        // the creator holds none of the above now, and up to the cap at once
+        for symbol in ["CAPA", "CAPB", "CAPC"] {
+            execute(deps.as_mut(), mock_env(), creator_info.clone(), reserve("Capped Collection", symbol))
+            .expect("failed to reserve name!!");
+        }
+
+        let res = execute(deps.as_mut(), mock_env(), creator_info.clone(), reserve("Capped Collection", "CAPD"));
+        assert!(matches!(res, Err(crate::ContractError::NameReservationLimitReached { .. })));
+
+        // anyone prunes the reservations past their cooldown
+        let mut stale_env = mock_env();
+        stale_env.block.time = stale_env.block.time.plus_seconds(NAME_RESERVATION_DURATION * 2 + NAME_RESERVATION_COOLDOWN);
+
+        execute(deps.as_mut(), stale_env, mock_info(owner, &[]), ExecuteMsg::PruneNameReservations { 
+            start_after : None, limit : Some(MAX_LIMIT) })
+        .expect("failed to prune name reservations!!");
+
+        for (name, symbol) in [("Renewed Collection", "RNWC"), ("Released Collection", "RLSC"), ("Capped Collection", "CAPA")] {
+            let _key = name_key(name, symbol);
+            assert!(!crate::indexes::NAME_RESERVATIONS_STORE.has(deps.as_ref().storage, _key.clone()));
+            assert!(!crate::indexes::NAME_RESERVERS_STORE.has(deps.as_ref().storage, (Addr::unchecked(creator), _key.clone())));
+            assert!(!crate::indexes::NAME_RESERVERS_STORE.has(deps.as_ref().storage, (Addr::unchecked(other), _key)));
+        }
+
+        // the reservation is charged once the fee is set
+        execute(deps.as_mut(), mock_env(), mock_info(owner, &[]), ExecuteMsg::UpdateContractInfo { 
+            fees : Some(vec![
+                Fee {name : "CREATE_COLLECTION_FEE".to_string(),
+                value : Coin { amount : Uint128::from(1500u64), denom : "uconst".to_string()}},
+                Fee {name : "NAME_RESERVATION_FEE".to_string(),
+                value : Coin { amount : Uint128::from(800u64), denom : "uconst".to_string()}},
+            ]),
+            treasuries : None, contracts : None, log_last_payment : None,
+        }).expect("failed to update contract info!!");
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(other, &[]), reserve("Paid Collection", "PAID"));
+        assert!(matches!(res, Err(crate::ContractError::InsufficientFund { .. })));
+
+        let res = execute(deps.as_mut(), mock_env(), other_info.clone(), reserve("Paid Collection", "PAID"))
+        .expect("failed to reserve name!!");
+        assert!(!res.messages.is_empty());
+    }
+}
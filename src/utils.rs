@@ -1,5 +1,8 @@
 use std::hash::Hash;
+use cosmwasm_std::Uint128;
+use sha2::{Sha256, Digest};
 use pix0_market_handlers::utils::hash_to_hex;
+use crate::state::TREASURY_PERCENTAGE_TOTAL;
 
 
 pub fn nft_token_id<T:Hash>(t: &T) -> String {
@@ -21,3 +24,34 @@ pub fn str_to_u128(num_str : String, null_replace_by : u128) -> u128 {
     num_str.parse().unwrap_or(null_replace_by)
 }
 
+
+
+/*
+Split the total by the 2-decimal percentages, the rounding
+remainder is added to the amount at primary_index so that
+the amounts always sum up exactly to the total
+ */
+pub fn split_by_percentage(total : Uint128, percentages : &Vec<u16>, primary_index : usize) -> Vec<Uint128> {
+
+    let mut amounts : Vec<Uint128> = percentages.iter()
+    .map(|p| total.multiply_ratio(*p as u128, TREASURY_PERCENTAGE_TOTAL as u128))
+    .collect();
+
+    let paid = amounts.iter().fold(Uint128::zero(), |acc, a| acc + *a);
+
+    if let Some(primary) = amounts.get_mut(primary_index) {
+        *primary += total.checked_sub(paid).unwrap_or_default();
+    }
+
+    amounts
+}
+
+
+// hex encoded sha256 hash of the plaintext promo code
+pub fn promo_code_hash(code : &str) -> String {
+
+    Sha256::digest(code.as_bytes())
+    .iter()
+    .map(|b| format!("{:02x}", b))
+    .collect()
+}
@@ -0,0 +1,207 @@
+use cosmwasm_std::{Deps, DepsMut, Response, MessageInfo};
+use crate::state::{Collection, Item, Link, ValidationLimits, LINK_TYPE_IMAGE_URL, LINK_TYPE_EXTERNAL_LINK, 
+LINK_TYPE_VIDEO_URL, LINK_TYPE_ANIMATION_URL};
+use crate::indexes::VALIDATION_LIMITS_STORE;
+use crate::error::ContractError;
+use crate::ins::{common_response, STATUS_OK};
+use crate::query::internal_get_validation_limits;
+use crate::checks::check_if_contract_admin;
+
+// the characters allowed in the names besides the ASCII letters and digits
+const NAME_EXTRA_CHARS : &str = " -_.'&#!:()";
+
+// the characters allowed in the symbols besides the ASCII letters and digits,
+// as used by the symbols created before the validation
+const SYMBOL_EXTRA_CHARS : &str = "._";
+
+// the schemes allowed for the links of the items
+const LINK_SCHEMES : [&str; 3] = ["https://", "http://", "ipfs://"];
+
+// the link types holding a URL, only their values are checked against the schemes
+const URL_LINK_TYPES : [u8; 4] = [LINK_TYPE_IMAGE_URL, LINK_TYPE_EXTERNAL_LINK, LINK_TYPE_VIDEO_URL, LINK_TYPE_ANIMATION_URL];
+
+
+/*
+Sets the limits of the fields of collections and items,
+the records already stored are not affected
+ */
+pub fn set_validation_limits(deps : DepsMut, info : MessageInfo, limits : ValidationLimits)
+-> Result<Response, ContractError> {
+
+    check_if_contract_admin(deps.as_ref(), &info.sender)?;
+
+    if limits.max_name_length == 0 || limits.max_symbol_length == 0 ||
+    limits.max_description_length == 0 || limits.max_value_length == 0 ||
+    limits.max_links == 0 {
+        return Err(ContractError::InvalidInput { text :
+            "The max lengths and the max links must be above zero!".to_string()});
+    }
+
+    VALIDATION_LIMITS_STORE.save(deps.storage, &limits)?;
+
+    common_response("validation_limits", "set_validation_limits", STATUS_OK, None, None)
+}
+
+
+fn check_length(field : &str, value : &str, max : u32) -> Result<(), ContractError> {
+
+    if value.chars().count() > max as usize {
+        return Err(ContractError::InvalidInput { text :
+            format!("The {} is longer than {} characters!", field, max)});
+    }
+
+    Ok(())
+}
+
+
+fn check_count(field : &str, count : usize, max : u32) -> Result<(), ContractError> {
+
+    if count > max as usize {
+        return Err(ContractError::InvalidInput { text :
+            format!("The {} are more than {}!", field, max)});
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_name_valid(field : &str, name : &str, limits : &ValidationLimits)
+-> Result<(), ContractError> {
+
+    if name.trim().is_empty() || name.trim() != name {
+        return Err(ContractError::InvalidInput { text :
+            format!("The {} is empty or has leading or trailing spaces!", field)});
+    }
+
+    check_length(field, name, limits.max_name_length)?;
+
+    if name.chars().any(|c| !c.is_ascii_alphanumeric() && !NAME_EXTRA_CHARS.contains(c)) {
+        return Err(ContractError::InvalidInput { text :
+            format!("The {} has characters other than letters, digits and {}!", field, NAME_EXTRA_CHARS.trim())});
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_symbol_valid(symbol : &str, limits : &ValidationLimits) -> Result<(), ContractError> {
+
+    if symbol.is_empty() {
+        return Err(ContractError::InvalidInput { text : "The symbol is empty!".to_string()});
+    }
+
+    check_length("symbol", symbol, limits.max_symbol_length)?;
+
+    if symbol.chars().any(|c| !c.is_ascii_alphanumeric() && !SYMBOL_EXTRA_CHARS.contains(c)) {
+        return Err(ContractError::InvalidInput { text :
+            format!("The symbol has characters other than letters, digits and {}!", SYMBOL_EXTRA_CHARS)});
+    }
+
+    Ok(())
+}
+
+
+/*
+A color in hex of 6 digits, such as FFAA00, with or without a leading #
+ */
+pub (crate) fn check_if_hex_color_valid(color : &str) -> Result<(), ContractError> {
+
+    let hex = color.strip_prefix('#').unwrap_or(color);
+
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ContractError::InvalidInput { text :
+            format!("The background color {} is NOT a hex color!", color)});
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_link_valid(link : &Link, limits : &ValidationLimits) -> Result<(), ContractError> {
+
+    check_length("link", &link.value, limits.max_value_length)?;
+
+    if !URL_LINK_TYPES.contains(&link.link_type) {
+        return Ok(());
+    }
+
+    let link = &link.value;
+
+    let scheme = LINK_SCHEMES.iter().find(|s| link.to_lowercase().starts_with(*s));
+
+    if scheme.is_none() || link.len() == scheme.unwrap().len() ||
+    link.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(ContractError::InvalidInput { text :
+            format!("The link {} is NOT a valid {} link!", link, LINK_SCHEMES.join(", "))});
+    }
+
+    Ok(())
+}
+
+
+/*
+The name and symbol are only checked for the new collections,
+as they are part of the key of the collections already stored
+ */
+pub (crate) fn check_if_collection_fields_valid(deps : Deps, collection : &Collection, is_new : bool)
+-> Result<(), ContractError> {
+
+    let limits = internal_get_validation_limits(deps);
+
+    if is_new {
+        check_if_name_valid("name", &collection.name, &limits)?;
+        check_if_symbol_valid(&collection.symbol, &limits)?;
+    }
+
+    if collection.description.is_some() {
+        check_length("description", collection.description.as_ref().unwrap(), limits.max_description_length)?;
+    }
+
+    if collection.attributes.is_some() {
+
+        let attributes = collection.attributes.as_ref().unwrap();
+
+        check_count("attributes", attributes.len(), limits.max_attributes)?;
+
+        for a in attributes.iter() {
+            check_length("attribute name", &a.name, limits.max_name_length)?;
+            check_length("attribute value", &a.value, limits.max_value_length)?;
+        }
+    }
+
+    Ok(())
+}
+
+
+pub (crate) fn check_if_item_fields_valid(deps : Deps, item : &Item, is_new : bool)
+-> Result<(), ContractError> {
+
+    let limits = internal_get_validation_limits(deps);
+
+    if is_new {
+        check_if_name_valid("item name", &item.name, &limits)?;
+    }
+
+    if item.description.is_some() {
+        check_length("description", item.description.as_ref().unwrap(), limits.max_description_length)?;
+    }
+
+    check_count("links", item.links.len(), limits.max_links)?;
+
+    for l in item.links.iter() {
+        check_if_link_valid(l, &limits)?;
+    }
+
+    check_count("traits", item.traits.len(), limits.max_traits)?;
+
+    for t in item.traits.iter() {
+        check_length("trait type", &t.trait_type, limits.max_name_length)?;
+        check_length("trait value", &t.value, limits.max_value_length)?;
+    }
+
+    if item.background_color.is_some() {
+        check_if_hex_color_valid(item.background_color.as_ref().unwrap())?;
+    }
+
+    Ok(())
+}